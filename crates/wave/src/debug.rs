@@ -0,0 +1,831 @@
+//! `wave debug`, a debug adapter speaking the Debug Adapter Protocol over stdio.
+//!
+//! The adapter runs the script on the thread reading the requests, so requests are only
+//! answered before the launch, while the script is paused and once it ended. A `pause` or a
+//! breakpoint set while the script runs takes effect the next time it stops.
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::process;
+use std::rc::Rc;
+
+use serde_json::{json, Value};
+use wave_allocator::Allocator;
+use wave_interpreter::debugger::Debugger;
+use wave_interpreter::environment::Environment;
+use wave_interpreter::evaluator::Primitive;
+use wave_interpreter::Runtime;
+use wave_parser::Parser;
+use wave_span::Span;
+
+/// The only thread, scripts are single threaded.
+const THREAD_ID: u64 = 1;
+
+pub fn run(program: Option<String>) -> Result<(), String> {
+    let connection = Connection {
+        input: Box::new(BufReader::new(io::stdin())),
+        output: Box::new(io::stdout()),
+        sequence: 1,
+    };
+    serve(Rc::new(RefCell::new(connection)), program)
+}
+
+/// Answers the requests of `connection` until the client disconnects.
+fn serve(connection: Rc<RefCell<Connection>>, program: Option<String>) -> Result<(), String> {
+    let mut launch = Launch {
+        program,
+        stop_on_entry: false,
+        breakpoints: HashSet::new(),
+    };
+    let (mut launched, mut configured) = (false, false);
+    while !(launched && configured) {
+        let mut client = connection.borrow_mut();
+        let request = client.read_request().ok_or("the client disconnected")?;
+        match command(&request) {
+            "initialize" => {
+                client.respond(
+                    &request,
+                    json!({
+                        "supportsConfigurationDoneRequest": true,
+                        "supportsEvaluateForHovers": true,
+                    }),
+                );
+                client.send_event("initialized", json!({}));
+            }
+            "launch" => {
+                let arguments = &request["arguments"];
+                if let Some(program) = arguments["program"].as_str() {
+                    launch.program = Some(program.to_string());
+                }
+                launch.stop_on_entry = arguments["stopOnEntry"].as_bool().unwrap_or(false);
+                launched = true;
+                client.respond(&request, json!({}));
+            }
+            "setBreakpoints" => {
+                launch.breakpoints = client.set_breakpoints(&request);
+            }
+            "configurationDone" => {
+                configured = true;
+                client.respond(&request, json!({}));
+            }
+            _ => {
+                if !client.answer_idle(&request) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    let path = launch.program.take().ok_or("no program to debug")?;
+    let exit_code = match std::fs::read_to_string(&path) {
+        Ok(source_text) => debug(&connection, &path, &source_text, launch),
+        Err(error) => {
+            let output = format!("failed to read {path}: {error}\n");
+            connection.borrow_mut().send_output("stderr", &output);
+            1
+        }
+    };
+    let mut client = connection.borrow_mut();
+    client.send_event("exited", json!({ "exitCode": exit_code }));
+    client.send_event("terminated", json!({}));
+
+    // the client still asks for threads and disconnects
+    while let Some(request) = client.read_request() {
+        if !client.answer_idle(&request) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+struct Launch {
+    program: Option<String>,
+    stop_on_entry: bool,
+    breakpoints: HashSet<u32>,
+}
+
+/// Runs the script and returns its exit code.
+fn debug(
+    connection: &Rc<RefCell<Connection>>,
+    path: &str,
+    source_text: &str,
+    launch: Launch,
+) -> i64 {
+    let report = |errors: Vec<wave_diagnostics::Error>| {
+        for error in errors {
+            let error = error.with_source_code(source_text.to_string());
+            connection
+                .borrow_mut()
+                .send_output("stderr", &format!("{error:?}\n"));
+        }
+    };
+    // the arena of the program, and of the expressions evaluated while paused
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text).parse();
+    if !ret.errors.is_empty() {
+        report(ret.errors);
+        return 1;
+    }
+    let (errors, has_errors) = crate::check(&ret.program);
+    report(errors);
+    if has_errors {
+        return 1;
+    }
+
+    let session = Session {
+        connection: Rc::clone(connection),
+        allocator: &allocator,
+        path: path.to_string(),
+        source_text: source_text.to_string(),
+        line_starts: line_starts(source_text),
+        breakpoints: launch.breakpoints,
+        step: if launch.stop_on_entry {
+            Step::Entry
+        } else {
+            Step::Continue
+        },
+        resumed_at: None,
+        frames: vec![Frame {
+            name: "main".to_string(),
+            span: Span::default(),
+            environment: None,
+        }],
+        handles: vec![],
+    };
+    // `print` writes to stdout, where the protocol messages go, so it sends output events
+    let output = Rc::clone(connection);
+    let mut runtime = Runtime::new(ret.program)
+        .with_debugger(session)
+        .with_host_function("print", move |_, arguments, _| {
+            let text = format!("{:?}\n", arguments);
+            output.borrow_mut().send_output("stdout", &text);
+            Ok(Primitive::Null)
+        });
+    runtime
+        .inbuilt_functions
+        .retain(|function| function.name != "print");
+
+    let result = runtime.eval();
+    match result {
+        Ok(_) => 0,
+        Err(error) => {
+            report(vec![error]);
+            1
+        }
+    }
+}
+
+enum Step {
+    Continue,
+    Entry,
+    In,
+    /// Stop at the next statement of a frame at most this deep
+    Over(usize),
+    /// Stop at the next statement of a frame less deep than this
+    Out(usize),
+}
+
+struct Frame<'a> {
+    /// The callee when the frame is a call
+    name: String,
+    /// The statement being evaluated
+    span: Span,
+    environment: Option<Rc<RefCell<Environment<'a>>>>,
+}
+
+/// What a `variablesReference` stands for, valid until the script resumes.
+enum Handle<'a> {
+    Scope(Rc<RefCell<Environment<'a>>>, bool),
+    Value(Primitive<'a>),
+}
+
+struct Session<'a> {
+    connection: Rc<RefCell<Connection>>,
+    allocator: &'a Allocator,
+    path: String,
+    source_text: String,
+    line_starts: Vec<u32>,
+    breakpoints: HashSet<u32>,
+    step: Step,
+    /// The line and depth the script resumed at, a breakpoint there is only hit again
+    /// once another line ran
+    resumed_at: Option<(u32, usize)>,
+    frames: Vec<Frame<'a>>,
+    handles: Vec<Handle<'a>>,
+}
+
+impl<'a> Debugger<'a> for Session<'a> {
+    fn statement(
+        &mut self,
+        runtime: &Runtime<'a>,
+        span: Span,
+        environment: &Rc<RefCell<Environment<'a>>>,
+    ) {
+        let frame = self
+            .frames
+            .last_mut()
+            .expect("the main frame is never exited");
+        frame.span = span;
+        frame.environment = Some(Rc::clone(environment));
+
+        let (line, _) = self.position(span.start);
+        let depth = self.frames.len();
+        if self.resumed_at != Some((line, depth)) {
+            self.resumed_at = None;
+        }
+        let reason = match self.step {
+            Step::Entry => "entry",
+            Step::In => "step",
+            Step::Over(frame) if depth <= frame => "step",
+            Step::Out(frame) if depth < frame => "step",
+            _ if self.breakpoints.contains(&line) && self.resumed_at.is_none() => "breakpoint",
+            _ => return,
+        };
+        self.pause(runtime, reason);
+        self.resumed_at = Some((line, depth));
+    }
+
+    fn enter(&mut self, call_span: Span) {
+        let callee = &self.source_text[call_span.start as usize..call_span.end as usize];
+        let name = callee.split('(').next().unwrap_or(callee).trim();
+        self.frames.push(Frame {
+            name: name.to_string(),
+            span: call_span,
+            environment: None,
+        });
+    }
+
+    fn exit(&mut self) {
+        self.frames.pop();
+    }
+}
+
+impl<'a> Session<'a> {
+    /// Answers requests until one resumes the script.
+    ///
+    /// The script cannot be unwound from here, so the adapter exits when the client
+    /// disconnects while it is paused.
+    fn pause(&mut self, runtime: &Runtime<'a>, reason: &str) {
+        self.connection.borrow_mut().send_event(
+            "stopped",
+            json!({ "reason": reason, "threadId": THREAD_ID, "allThreadsStopped": true }),
+        );
+        loop {
+            let Some(request) = self.connection.borrow_mut().read_request() else {
+                process::exit(0);
+            };
+            let depth = self.frames.len();
+            let step = match command(&request) {
+                "continue" => Step::Continue,
+                "next" => Step::Over(depth),
+                "stepIn" => Step::In,
+                "stepOut" => Step::Out(depth),
+                "stackTrace" => {
+                    let body = self.stack_trace();
+                    self.connection.borrow_mut().respond(&request, body);
+                    continue;
+                }
+                "scopes" => {
+                    let body = self.scopes(&request["arguments"]);
+                    self.connection.borrow_mut().respond(&request, body);
+                    continue;
+                }
+                "variables" => {
+                    let body = self.variables(runtime, &request["arguments"]);
+                    self.connection.borrow_mut().respond(&request, body);
+                    continue;
+                }
+                "evaluate" => {
+                    self.evaluate(runtime, &request);
+                    continue;
+                }
+                "setBreakpoints" => {
+                    self.breakpoints = self.connection.borrow_mut().set_breakpoints(&request);
+                    continue;
+                }
+                "pause" => {
+                    self.connection.borrow_mut().respond(&request, json!({}));
+                    continue;
+                }
+                _ => {
+                    if !self.connection.borrow_mut().answer_idle(&request) {
+                        process::exit(0);
+                    }
+                    continue;
+                }
+            };
+            self.step = step;
+            self.handles.clear();
+            let body = json!({ "allThreadsContinued": true });
+            self.connection.borrow_mut().respond(&request, body);
+            return;
+        }
+    }
+
+    fn stack_trace(&self) -> Value {
+        let frames = self
+            .frames
+            .iter()
+            .enumerate()
+            .rev()
+            // frames of native calls have no statement of their own
+            .filter(|(_, frame)| frame.environment.is_some())
+            .map(|(id, frame)| {
+                let (line, column) = self.position(frame.span.start);
+                json!({
+                    "id": id,
+                    "name": frame.name,
+                    "line": line,
+                    "column": column,
+                    "source": { "path": self.path },
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({ "stackFrames": frames, "totalFrames": frames.len() })
+    }
+
+    /// Every environment of the chain of the frame is a scope, from the innermost.
+    fn scopes(&mut self, arguments: &Value) -> Value {
+        let Some(mut environment) = self.frame_environment(arguments) else {
+            return json!({ "scopes": [] });
+        };
+        let mut scopes = vec![];
+        loop {
+            let outer = environment.borrow().outer.clone();
+            let name = match (&outer, scopes.is_empty()) {
+                (None, _) => "Globals",
+                (Some(_), true) => "Locals",
+                (Some(_), false) => "Closure",
+            };
+            // blocks without declarations would only add noise
+            if scopes.is_empty() || outer.is_none() || !environment.borrow().values.is_empty() {
+                let reference =
+                    self.handle(Handle::Scope(Rc::clone(&environment), outer.is_none()));
+                scopes.push(json!({
+                    "name": name,
+                    "variablesReference": reference,
+                    "expensive": false,
+                }));
+            }
+            match outer {
+                Some(outer) => environment = outer,
+                None => break,
+            }
+        }
+        json!({ "scopes": scopes })
+    }
+
+    fn variables(&mut self, runtime: &Runtime<'a>, arguments: &Value) -> Value {
+        let reference = arguments["variablesReference"].as_u64().unwrap_or(0) as usize;
+        let children = match self.handles.get(reference.wrapping_sub(1)) {
+            Some(Handle::Scope(environment, global)) => {
+                members(&environment.borrow(), |value| {
+                    // the built-in globals, like `setTimeout`
+                    !(*global && matches!(value, Primitive::NativeFunction(_)))
+                })
+            }
+            Some(Handle::Value(value)) => children(value),
+            None => vec![],
+        };
+        let variables = children
+            .into_iter()
+            .map(|(name, value)| {
+                let mut variable = self.describe(runtime, value);
+                variable["name"] = json!(name);
+                variable
+            })
+            .collect::<Vec<_>>();
+        json!({ "variables": variables })
+    }
+
+    /// Watch expressions, and expressions typed in the debug console.
+    fn evaluate(&mut self, runtime: &Runtime<'a>, request: &Value) {
+        let arguments = &request["arguments"];
+        let Some(environment) = self.frame_environment(arguments) else {
+            let message = "no frame to evaluate in";
+            return self.connection.borrow_mut().respond_error(request, message);
+        };
+        let expression = arguments["expression"].as_str().unwrap_or_default();
+        let source = format!("{};", expression.trim().trim_end_matches(';'));
+        match runtime.evaluate(self.allocator, &source, environment) {
+            Ok(value) => {
+                let mut body = self.describe(runtime, value);
+                if let Some(fields) = body.as_object_mut() {
+                    let result = fields.remove("value").unwrap_or_default();
+                    fields.insert("result".to_string(), result);
+                }
+                self.connection.borrow_mut().respond(request, body);
+            }
+            Err(error) => {
+                let message = error.to_string();
+                self.connection
+                    .borrow_mut()
+                    .respond_error(request, &message);
+            }
+        }
+    }
+
+    /// The environment of the frame `frameId` names, the innermost frame without one.
+    fn frame_environment(&self, arguments: &Value) -> Option<Rc<RefCell<Environment<'a>>>> {
+        let frame = match arguments["frameId"].as_u64() {
+            Some(id) => self.frames.get(id as usize)?,
+            None => self
+                .frames
+                .iter()
+                .rev()
+                .find(|frame| frame.environment.is_some())?,
+        };
+        frame.environment.clone()
+    }
+
+    /// The `value`, `type` and `variablesReference` of a variable.
+    fn describe(&mut self, runtime: &Runtime<'a>, value: Primitive<'a>) -> Value {
+        let text = match &value {
+            Primitive::String(value) => format!("{:?}", value),
+            Primitive::Array(values) => format!("Array({})", values.len()),
+            Primitive::Map(map) => format!("Map({})", map.borrow().len()),
+            Primitive::Set(set) => format!("Set({})", set.borrow().len()),
+            // instances can reference themselves, they are only shown expanded
+            Primitive::Class(_) | Primitive::Instance(_) | Primitive::This(_) => {
+                runtime.type_of(&value).to_string()
+            }
+            value => format!("{:?}", value),
+        };
+        let kind = runtime.type_of(&value);
+        let reference = match value {
+            Primitive::Array(_)
+            | Primitive::Map(_)
+            | Primitive::Set(_)
+            | Primitive::Instance(_)
+            | Primitive::This(_) => self.handle(Handle::Value(value)),
+            _ => 0,
+        };
+        json!({ "value": text, "type": kind, "variablesReference": reference })
+    }
+
+    fn handle(&mut self, handle: Handle<'a>) -> usize {
+        self.handles.push(handle);
+        self.handles.len()
+    }
+
+    /// The 1-based line and column of an offset.
+    fn position(&self, offset: u32) -> (u32, u32) {
+        let line = self.line_starts.partition_point(|start| *start <= offset);
+        let column = offset - self.line_starts[line - 1] + 1;
+        (line as u32, column)
+    }
+}
+
+/// The named members of an environment, environments store fields as `this_<name>` and
+/// internal entries start with `#`.
+fn members<'a>(
+    environment: &Environment<'a>,
+    filter: impl Fn(&Primitive<'a>) -> bool,
+) -> Vec<(String, Primitive<'a>)> {
+    let mut members = environment
+        .values
+        .iter()
+        .filter(|(name, value)| !name.as_str().starts_with('#') && filter(value))
+        .map(|(name, value)| {
+            let name = name.as_str();
+            let name = name.strip_prefix("this_").unwrap_or(name);
+            // private names are followed by the class declaring them
+            let name = name.split_once('@').map_or(name, |(name, _)| name);
+            (name.to_string(), value.clone())
+        })
+        .collect::<Vec<_>>();
+    members.sort_by(|(a, _), (b, _)| a.cmp(b));
+    members
+}
+
+fn children<'a>(value: &Primitive<'a>) -> Vec<(String, Primitive<'a>)> {
+    match value {
+        Primitive::Array(values) => values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (index.to_string(), value.clone()))
+            .collect(),
+        Primitive::Map(map) => map
+            .borrow()
+            .entries()
+            .map(|(key, value)| (format!("{:?}", key), value.clone()))
+            .collect(),
+        Primitive::Set(set) => set
+            .borrow()
+            .entries()
+            .enumerate()
+            .map(|(index, (value, _))| (index.to_string(), value.clone()))
+            .collect(),
+        Primitive::Instance(environment) | Primitive::This(environment) => {
+            members(&environment.borrow(), |_| true)
+        }
+        _ => vec![],
+    }
+}
+
+fn line_starts(source_text: &str) -> Vec<u32> {
+    let lines = source_text
+        .match_indices('\n')
+        .map(|(index, _)| index as u32 + 1);
+    std::iter::once(0).chain(lines).collect()
+}
+
+fn command(request: &Value) -> &str {
+    request["command"].as_str().unwrap_or_default()
+}
+
+/// The stream of protocol messages with the client, stdin and stdout outside of tests.
+struct Connection {
+    input: Box<dyn BufRead>,
+    output: Box<dyn Write>,
+    /// The `seq` of the next message sent
+    sequence: u64,
+}
+
+impl Connection {
+    /// Reads the next request, `None` once the input is closed.
+    fn read_request(&mut self) -> Option<Value> {
+        let mut length = None;
+        loop {
+            let mut header = String::new();
+            if self.input.read_line(&mut header).ok()? == 0 {
+                return None;
+            }
+            let header = header.trim();
+            if header.is_empty() {
+                break;
+            }
+            if let Some(value) = header.strip_prefix("Content-Length:") {
+                length = value.trim().parse::<usize>().ok();
+            }
+        }
+        let mut body = vec![0; length?];
+        self.input.read_exact(&mut body).ok()?;
+        serde_json::from_slice(&body).ok()
+    }
+
+    fn send(&mut self, mut message: Value) {
+        message["seq"] = json!(self.sequence);
+        self.sequence += 1;
+        let body = message.to_string();
+        // nothing can be reported once the client is gone
+        let _ = write!(
+            self.output,
+            "Content-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let _ = self.output.flush();
+    }
+
+    fn respond(&mut self, request: &Value, body: Value) {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "command": request["command"],
+            "success": true,
+            "body": body,
+        }));
+    }
+
+    fn respond_error(&mut self, request: &Value, message: &str) {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "command": request["command"],
+            "success": false,
+            "message": message,
+        }));
+    }
+
+    fn send_event(&mut self, event: &str, body: Value) {
+        self.send(json!({ "type": "event", "event": event, "body": body }));
+    }
+
+    fn send_output(&mut self, category: &str, output: &str) {
+        self.send_event("output", json!({ "category": category, "output": output }));
+    }
+
+    /// Breakpoints are set by line, for the only source there is.
+    fn set_breakpoints(&mut self, request: &Value) -> HashSet<u32> {
+        let breakpoints = request["arguments"]["breakpoints"]
+            .as_array()
+            .into_iter()
+            .flatten();
+        let lines = breakpoints
+            .clone()
+            .filter_map(|breakpoint| breakpoint["line"].as_u64())
+            .map(|line| line as u32)
+            .collect::<HashSet<_>>();
+        let verified = breakpoints
+            .map(|breakpoint| json!({ "verified": true, "line": breakpoint["line"] }))
+            .collect::<Vec<_>>();
+        self.respond(request, json!({ "breakpoints": verified }));
+        lines
+    }
+
+    /// Answers requests that do not depend on the state of the script, `false` once the
+    /// client disconnected.
+    fn answer_idle(&mut self, request: &Value) -> bool {
+        match command(request) {
+            "threads" => self.respond(
+                request,
+                json!({ "threads": [{ "id": THREAD_ID, "name": "main" }] }),
+            ),
+            "setExceptionBreakpoints" => self.respond(request, json!({ "breakpoints": [] })),
+            "disconnect" | "terminate" => {
+                self.respond(request, json!({}));
+                return false;
+            }
+            command => self.respond_error(request, &format!("'{command}' is not supported")),
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::io::{self, Cursor, Write};
+    use std::rc::Rc;
+
+    use serde_json::{json, Value};
+
+    use super::{serve, Connection};
+
+    const SCRIPT: &str = "function double(x) {
+    let y = x * 2;
+    return y;
+}
+let a = double(1);
+let b = double(a);
+let c = b + 1; print(c);
+";
+
+    /// What the adapter sent, shared with the test once the session is over.
+    struct Output(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Output {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Frames the requests like a client does, with an extra header on the first.
+    fn frame(requests: &[(&str, Value)]) -> Vec<u8> {
+        let mut input = vec![];
+        for (seq, (command, arguments)) in requests.iter().enumerate() {
+            let body = json!({
+                "seq": seq + 1,
+                "type": "request",
+                "command": command,
+                "arguments": arguments,
+            })
+            .to_string();
+            write!(input, "Content-Length: {}\r\n", body.len()).unwrap();
+            if seq == 0 {
+                write!(input, "Content-Type: application/vscode-jsonrpc\r\n").unwrap();
+            }
+            write!(input, "\r\n{body}").unwrap();
+        }
+        input
+    }
+
+    fn messages(output: &[u8]) -> Vec<Value> {
+        let mut output = std::str::from_utf8(output).unwrap();
+        let mut messages = vec![];
+        while let Some(rest) = output.strip_prefix("Content-Length: ") {
+            let (length, rest) = rest.split_once("\r\n\r\n").unwrap();
+            let (body, rest) = rest.split_at(length.parse().unwrap());
+            messages.push(serde_json::from_str(body).unwrap());
+            output = rest;
+        }
+        assert!(output.is_empty());
+        messages
+    }
+
+    #[test]
+    fn scripted_session() {
+        let path = std::env::temp_dir().join("wave_scripted_session.wave");
+        std::fs::write(&path, SCRIPT).unwrap();
+        let program = path.to_string_lossy().into_owned();
+
+        let lines = |lines: &[u32]| {
+            let breakpoints = lines.iter().map(|line| json!({ "line": line }));
+            json!({ "source": { "path": program }, "breakpoints": breakpoints.collect::<Vec<_>>() })
+        };
+        let requests = [
+            ("initialize", json!({ "adapterID": "wave" })),
+            ("launch", json!({ "program": program, "stopOnEntry": true })),
+            ("setBreakpoints", lines(&[3, 7])),
+            ("configurationDone", json!({})),
+            // paused on entry, the next statement of the main frame is the first call
+            ("next", json!({})),
+            ("stepIn", json!({})),
+            ("stackTrace", json!({ "threadId": 1 })),
+            // the breakpoint is hit before the frame is left
+            ("stepOut", json!({})),
+            ("scopes", json!({ "frameId": 1 })),
+            ("variables", json!({ "variablesReference": 1 })),
+            ("evaluate", json!({ "expression": "x + y", "frameId": 1 })),
+            ("stepOut", json!({})),
+            ("stackTrace", json!({ "threadId": 1 })),
+            ("continue", json!({})),
+            // the handles of the previous pause are gone
+            ("variables", json!({ "variablesReference": 1 })),
+            ("continue", json!({})),
+            // the second statement of the line does not hit its breakpoint again
+            ("continue", json!({})),
+            ("threads", json!({})),
+            ("disconnect", json!({})),
+        ];
+
+        let output = Rc::new(RefCell::new(vec![]));
+        let connection = Connection {
+            input: Box::new(Cursor::new(frame(&requests))),
+            output: Box::new(Output(Rc::clone(&output))),
+            sequence: 1,
+        };
+        serve(Rc::new(RefCell::new(connection)), None).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        let messages = messages(&output.borrow());
+        for (seq, message) in messages.iter().enumerate() {
+            assert_eq!(message["seq"], json!(seq + 1));
+        }
+        let responses = messages
+            .iter()
+            .filter(|message| message["type"] == "response")
+            .map(|message| (message["request_seq"].as_u64().unwrap() as usize, message))
+            .collect::<HashMap<_, _>>();
+        let response = |seq: usize| {
+            let response = responses[&seq];
+            assert_eq!(response["command"], json!(requests[seq - 1].0));
+            response
+        };
+        // every request is answered
+        assert_eq!(responses.len(), requests.len());
+
+        let events = messages
+            .iter()
+            .filter(|message| message["type"] == "event")
+            .map(|message| match message["event"].as_str().unwrap() {
+                "stopped" => format!("stopped {}", message["body"]["reason"]),
+                "output" => format!("output {}", message["body"]["output"]),
+                "exited" => format!("exited {}", message["body"]["exitCode"]),
+                event => event.to_string(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            events,
+            [
+                "initialized",
+                "stopped \"entry\"",
+                "stopped \"step\"",
+                "stopped \"step\"",
+                "stopped \"breakpoint\"",
+                "stopped \"step\"",
+                "stopped \"breakpoint\"",
+                "stopped \"breakpoint\"",
+                "output \"[5]\\n\"",
+                "exited 0",
+                "terminated",
+            ]
+        );
+
+        let frames = |seq: usize| {
+            let frames = response(seq)["body"]["stackFrames"]
+                .as_array()
+                .unwrap()
+                .clone();
+            frames
+                .iter()
+                .map(|frame| format!("{} {}", frame["name"].as_str().unwrap(), frame["line"]))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(frames(7), ["double 2", "main 5"]);
+        assert_eq!(frames(13), ["main 6"]);
+
+        let scopes = response(9)["body"]["scopes"].as_array().unwrap();
+        assert_eq!(scopes[0]["name"], "Locals");
+        assert_eq!(scopes[0]["variablesReference"], 1);
+        assert_eq!(scopes.last().unwrap()["name"], "Globals");
+
+        let variables = &response(10)["body"]["variables"];
+        assert_eq!(variables[0]["name"], "x");
+        assert_eq!(variables[0]["value"], "1");
+        assert_eq!(variables[1]["name"], "y");
+        assert_eq!(variables[1]["value"], "2");
+
+        assert_eq!(response(11)["body"]["result"], "3");
+        assert_eq!(response(15)["body"]["variables"], json!([]));
+        assert_eq!(response(18)["body"]["threads"][0]["id"], 1);
+    }
+}
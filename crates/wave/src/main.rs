@@ -1,9 +1,18 @@
+mod debug;
+
 use std::env;
 use wave_allocator::Allocator;
+use wave_ast::ast::Program;
+use wave_diagnostics::miette::Severity;
 use wave_interpreter::Runtime;
 use wave_parser::Parser;
+use wave_semantic::SemanticBuilder;
 
 fn main() -> Result<(), String>{
+    let args: Vec<String> = env::args().collect();
+    if args.get(1).is_some_and(|command| command == "debug") {
+        return debug::run(args.get(2).cloned());
+    }
     let source = check_args()?;
 
     let path = env::current_dir()
@@ -16,6 +25,14 @@ fn main() -> Result<(), String>{
 
     if ret.errors.is_empty() {
         let program = ret.program;
+        let (errors, has_errors) = check(&program);
+        for error in errors {
+            let error = error.with_source_code(source_text.clone());
+            println!("{error:?}");
+        }
+        if has_errors {
+            return Ok(());
+        }
         let runtime = Runtime::new(program);
         let result = Runtime::eval(&runtime);
         match result {
@@ -34,10 +51,21 @@ fn main() -> Result<(), String>{
     Ok(())
 }
 
+/// Runs the semantic checks, and tells whether any of them must stop the program from running.
+fn check(program: &Program) -> (Vec<wave_diagnostics::Error>, bool) {
+    let semantic = SemanticBuilder::new().build(program);
+    let has_errors = semantic.errors.iter().any(|error| {
+        error
+            .severity()
+            .is_none_or(|severity| severity == Severity::Error)
+    });
+    (semantic.errors, has_errors)
+}
+
 fn check_args() -> Result<String, String> {
     let args: Vec<String> = env::args().collect();
     if args.len() < 2 {
-        return Err("Usage: wave <filename>".to_string());
+        return Err("Usage: wave <filename>\n       wave debug [<filename>]".to_string());
     }
     Ok(args[1].clone())
 }
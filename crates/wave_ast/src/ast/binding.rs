@@ -3,10 +3,12 @@ use std::hash::Hash;
 
 #[cfg(feature = "serde")]
 use serde::Serialize;
-use wave_allocator::Box;
+use wave_allocator::{Box, Vec};
 use wave_span::{Atom, Span};
 use wave_syntax::symbol::SymbolId;
 
+use crate::ast::{Expression, PropertyKey};
+
 #[derive(Debug, Hash)]
 #[cfg_attr(
     feature = "serde",
@@ -22,6 +24,71 @@ pub struct BindingPattern<'a> {
 pub enum BindingPatternKind<'a> {
     /// `const a = 1`
     BindingIdentifier(Box<'a, BindingIdentifier>),
+    /// `const { a } = 1`
+    ObjectPattern(Box<'a, ObjectPattern<'a>>),
+    /// `const [ a ] = 1`
+    ArrayPattern(Box<'a, ArrayPattern<'a>>),
+    /// A defaulted binding inside a pattern, `const [a = 1] = []`
+    AssignmentPattern(Box<'a, AssignmentPattern<'a>>),
+}
+
+impl<'a> BindingPattern<'a> {
+    pub fn is_destructuring_pattern(&self) -> bool {
+        matches!(
+            self.kind,
+            BindingPatternKind::ObjectPattern(_) | BindingPatternKind::ArrayPattern(_)
+        )
+    }
+
+    pub fn has_default(&self) -> bool {
+        matches!(self.kind, BindingPatternKind::AssignmentPattern(_))
+    }
+}
+
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct AssignmentPattern<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub left: BindingPattern<'a>,
+    pub right: Expression<'a>,
+}
+
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct ObjectPattern<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub properties: Vec<'a, BindingProperty<'a>>,
+    pub rest: Option<Box<'a, RestElement<'a>>>,
+}
+
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct BindingProperty<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub key: PropertyKey<'a>,
+    pub value: BindingPattern<'a>,
+    pub shorthand: bool,
+}
+
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct ArrayPattern<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    /// `None` for elisions, `const [, b] = pair`
+    pub elements: Vec<'a, Option<BindingPattern<'a>>>,
+    pub rest: Option<Box<'a, RestElement<'a>>>,
+}
+
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct RestElement<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub argument: BindingPattern<'a>,
 }
 
 #[derive(Debug, Clone)]
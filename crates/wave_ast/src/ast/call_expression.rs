@@ -18,6 +18,7 @@ pub struct CallExpression<'a> {
     pub span: Span,
     pub callee: Expression<'a>,
     pub arguments: Vec<'a, Argument<'a>>,
+    pub optional: bool, // for optional chaining
 }
 
 impl<'a> CallExpression<'a> {}
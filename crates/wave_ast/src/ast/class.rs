@@ -3,7 +3,7 @@ use wave_allocator::{Box, Vec};
 
 #[cfg(feature = "serde")]
 use serde::Serialize;
-use wave_span::Span;
+use wave_span::{Atom, Span};
 
 /// Class Definitions
 #[derive(Debug, Hash)]
@@ -57,6 +57,7 @@ pub struct MethodDefinition<'a> {
     pub key: PropertyKey<'a>,
     pub value: Box<'a, Function<'a>>, // FunctionExpression
     pub kind: MethodDefinitionKind,
+    pub r#static: bool,
 }
 
 #[derive(Debug, Hash)]
@@ -70,6 +71,7 @@ pub struct PropertyDefinition<'a> {
     pub span: Span,
     pub key: PropertyKey<'a>,
     pub value: Option<Expression<'a>>,
+    pub r#static: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -77,6 +79,8 @@ pub struct PropertyDefinition<'a> {
 pub enum MethodDefinitionKind {
     Constructor,
     Method,
+    Get,
+    Set,
 }
 
 impl MethodDefinitionKind {
@@ -86,11 +90,38 @@ impl MethodDefinitionKind {
     pub fn is_method(&self) -> bool {
         matches!(self, Self::Method)
     }
+    pub fn is_accessor(&self) -> bool {
+        matches!(self, Self::Get | Self::Set)
+    }
 }
 
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum PropertyKey<'a> {
     Identifier(Box<'a, IdentifierName>),
+    PrivateIdentifier(Box<'a, PrivateIdentifier>),
     Expression(Expression<'a>),
 }
+
+impl<'a> PropertyKey<'a> {
+    pub fn is_private_identifier(&self) -> bool {
+        matches!(self, Self::PrivateIdentifier(_))
+    }
+
+    pub fn private_name(&self) -> Option<&Atom> {
+        match self {
+            Self::PrivateIdentifier(ident) => Some(&ident.name),
+            _ => None,
+        }
+    }
+}
+
+/// Private Identifier
+#[derive(Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct PrivateIdentifier {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    /// The name without the leading `#`
+    pub name: Atom,
+}
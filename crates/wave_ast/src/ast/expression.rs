@@ -2,15 +2,18 @@
 use serde::Serialize;
 
 use crate::ast::{
-    AssignmentExpression, BinaryExpression, IdentifierReference, ParenthesizedExpression,
-    SequenceExpression,
+    AssignmentExpression, BinaryExpression, ChainExpression, ConditionalExpression,
+    IdentifierReference, MatchExpression, ParenthesizedExpression, SequenceExpression,
+    TemplateLiteral,
 };
-use crate::{BooleanLiteral, NullLiteral, NumberLiteral, StringLiteral};
+use crate::{BigIntLiteral, BooleanLiteral, NullLiteral, NumberLiteral, StringLiteral};
+use num_bigint::BigInt;
 use wave_allocator::Box;
 
 use super::{
-    ArrayExpression, CallExpression, Function, LogicalExpression, MemberExpression, NewExpression,
-    Super, ThisExpression, UnaryExpression, UpdateExpression,
+    ArrayExpression, AwaitExpression, CallExpression, Function, LogicalExpression,
+    MemberExpression, NewExpression, Super, ThisExpression, UnaryExpression, UpdateExpression,
+    YieldExpression,
 };
 
 #[derive(Debug, Hash)]
@@ -20,7 +23,9 @@ pub enum Expression<'a> {
     BooleanLiteral(Box<'a, BooleanLiteral>),
     NullLiteral(Box<'a, NullLiteral>),
     NumberLiteral(Box<'a, NumberLiteral<'a>>),
+    BigIntLiteral(Box<'a, BigIntLiteral<'a>>),
     StringLiteral(Box<'a, StringLiteral>),
+    TemplateLiteral(Box<'a, TemplateLiteral<'a>>),
     Identifier(Box<'a, IdentifierReference>),
     BinaryExpression(Box<'a, BinaryExpression<'a>>),
     SequenceExpression(Box<'a, SequenceExpression<'a>>),
@@ -30,12 +35,17 @@ pub enum Expression<'a> {
     UnaryExpression(Box<'a, UnaryExpression<'a>>),
     UpdateExpression(Box<'a, UpdateExpression<'a>>),
     LogicalExpression(Box<'a, LogicalExpression<'a>>),
+    ConditionalExpression(Box<'a, ConditionalExpression<'a>>),
     FunctionExpression(Box<'a, Function<'a>>),
+    MatchExpression(Box<'a, MatchExpression<'a>>),
 
     MemberExpression(Box<'a, MemberExpression<'a>>),
+    ChainExpression(Box<'a, ChainExpression<'a>>),
     ThisExpression(Box<'a, ThisExpression>),
     Super(Box<'a, Super>),
     NewExpression(Box<'a, NewExpression<'a>>),
+    YieldExpression(Box<'a, YieldExpression<'a>>),
+    AwaitExpression(Box<'a, AwaitExpression<'a>>),
 }
 
 impl<'a> Expression<'a> {
@@ -47,6 +57,7 @@ impl<'a> Expression<'a> {
                     | Self::ThisExpression(_)
                     | Self::ParenthesizedExpression(_)
                     | Self::ArrayExpression(_)
+                    | Self::TemplateLiteral(_)
             )
     }
 
@@ -61,6 +72,7 @@ impl<'a> Expression<'a> {
             }
             Self::NullLiteral(_) => "null",
             Self::NumberLiteral(lit) => lit.raw,
+            Self::BigIntLiteral(lit) => lit.raw,
             Self::StringLiteral(lit) => &lit.value,
             Self::Identifier(ident) => &ident.name,
             _ => "",
@@ -73,6 +85,7 @@ impl<'a> Expression<'a> {
             Self::BooleanLiteral(_)
                 | Self::NullLiteral(_)
                 | Self::NumberLiteral(_)
+                | Self::BigIntLiteral(_)
                 | Self::StringLiteral(_)
         )
     }
@@ -169,6 +182,7 @@ impl<'a> Expression<'a> {
             Self::BooleanLiteral(lit) => Some(lit.value),
             Self::NullLiteral(_) => Some(false),
             Self::NumberLiteral(lit) => Some(lit.value != 0.0),
+            Self::BigIntLiteral(lit) => Some(lit.value != BigInt::default()),
             Self::StringLiteral(lit) => Some(!lit.value.is_empty()),
             _ => None,
         }
@@ -186,6 +200,7 @@ impl<'a> Expression<'a> {
             Self::BooleanLiteral(_)
             | Self::NullLiteral(_)
             | Self::NumberLiteral(_)
+            | Self::BigIntLiteral(_)
             | Self::StringLiteral(_) => true,
             Self::UnaryExpression(unary_expr) => unary_expr.argument.is_immutable_value(),
             Self::Identifier(ident) => {
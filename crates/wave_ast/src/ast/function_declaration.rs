@@ -1,5 +1,5 @@
 use crate::ast::Span;
-use crate::ast::{BindingIdentifier, BindingPattern, Statement};
+use crate::ast::{BindingIdentifier, BindingPattern, Expression, RestElement, Statement};
 use wave_allocator::{Box, Vec};
 
 #[cfg(feature = "serde")]
@@ -19,6 +19,7 @@ pub struct FormalParameters<'a> {
     pub span: Span,
     pub kind: FormalParameterKind,
     pub items: Vec<'a, FormalParameter<'a>>,
+    pub rest: Option<Box<'a, RestElement<'a>>>,
 }
 
 #[derive(Debug, Hash)]
@@ -37,7 +38,16 @@ pub enum FormalParameterKind {
 
 impl<'a> FormalParameters<'a> {
     pub fn is_empty(&self) -> bool {
-        self.items.is_empty()
+        self.items.is_empty() && self.rest.is_none()
+    }
+
+    /// Number of arguments a call has to pass, parameters after the last one
+    /// without a default value are optional.
+    pub fn required_count(&self) -> usize {
+        self.items
+            .iter()
+            .rposition(|param| !param.pattern.has_default())
+            .map_or(0, |position| position + 1)
     }
 }
 
@@ -48,6 +58,10 @@ pub struct Function<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
     pub id: Option<BindingIdentifier>,
+    /// `function*`, calling it returns a generator instead of running the body
+    pub generator: bool,
+    /// `async function`, calling it returns a promise of the value it returns
+    pub r#async: bool,
     pub params: Box<'a, FormalParameters<'a>>,
     pub body: Option<Box<'a, FunctionBody<'a>>>,
 }
@@ -65,3 +79,27 @@ impl<'a> FunctionBody<'a> {
         self.statements.is_empty()
     }
 }
+
+/// Yield Expression
+///
+/// `yield value` suspends the enclosing generator, `yield* iterable` yields every value of
+/// `iterable` in turn.
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct YieldExpression<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub delegate: bool,
+    pub argument: Option<Expression<'a>>,
+}
+
+/// Await Expression
+///
+/// `await value` suspends the enclosing async function until the promise `value` settles.
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct AwaitExpression<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub argument: Expression<'a>,
+}
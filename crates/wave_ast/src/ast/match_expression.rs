@@ -0,0 +1,83 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use wave_allocator::{Box, Vec};
+use wave_span::Span;
+
+use super::{BindingIdentifier, Expression, IdentifierName, IdentifierReference};
+
+/// Match Expression
+///
+/// ```text
+/// match (value) {
+///     0 => "zero",
+///     [first, ...rest] if first > 1 => rest,
+///     Point { x, y: 0 } => x,
+///     other => other,
+/// }
+/// ```
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct MatchExpression<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub discriminant: Expression<'a>,
+    pub arms: Vec<'a, MatchArm<'a>>,
+}
+
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct MatchArm<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub pattern: MatchPattern<'a>,
+    pub guard: Option<Expression<'a>>,
+    pub body: Expression<'a>,
+}
+
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
+pub enum MatchPattern<'a> {
+    /// `1`, `"a"`, `-2`, `null`
+    Literal(Expression<'a>),
+    /// `value`, binds anything
+    Identifier(Box<'a, BindingIdentifier>),
+    /// `[a, 1, ...rest]`
+    Array(Box<'a, MatchArrayPattern<'a>>),
+    /// `Point { x, y: 0 }`
+    Instance(Box<'a, MatchInstancePattern<'a>>),
+}
+
+impl<'a> MatchPattern<'a> {
+    /// Whether the pattern matches every value.
+    pub fn is_irrefutable(&self) -> bool {
+        matches!(self, Self::Identifier(_))
+    }
+}
+
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct MatchArrayPattern<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub elements: Vec<'a, MatchPattern<'a>>,
+    pub rest: Option<Box<'a, BindingIdentifier>>,
+}
+
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct MatchInstancePattern<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub class: IdentifierReference,
+    pub properties: Vec<'a, MatchProperty<'a>>,
+}
+
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct MatchProperty<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub key: IdentifierName,
+    pub value: MatchPattern<'a>,
+    pub shorthand: bool,
+}
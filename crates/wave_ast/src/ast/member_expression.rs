@@ -1,7 +1,7 @@
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
-use crate::ast::{Expression, IdentifierName};
+use crate::ast::{Expression, IdentifierName, PrivateIdentifier};
 use wave_span::Span;
 
 /// Member Expression
@@ -10,6 +10,7 @@ use wave_span::Span;
 pub enum MemberExpression<'a> {
     ComputedMemberExpression(ComputedMemberExpression<'a>),
     StaticMemberExpression(StaticMemberExpression<'a>),
+    PrivateFieldExpression(PrivateFieldExpression<'a>),
 }
 
 #[derive(Debug, Hash)]
@@ -19,6 +20,7 @@ pub struct ComputedMemberExpression<'a> {
     pub span: Span,
     pub object: Expression<'a>,
     pub expression: Expression<'a>,
+    pub optional: bool, // for optional chaining
 }
 
 #[derive(Debug, Hash)]
@@ -28,6 +30,18 @@ pub struct StaticMemberExpression<'a> {
     pub span: Span,
     pub object: Expression<'a>,
     pub property: IdentifierName,
+    pub optional: bool, // for optional chaining
+}
+
+/// `object.#field`
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct PrivateFieldExpression<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub object: Expression<'a>,
+    pub field: PrivateIdentifier,
+    pub optional: bool, // for optional chaining
 }
 
 impl<'a> MemberExpression<'a> {
@@ -35,10 +49,19 @@ impl<'a> MemberExpression<'a> {
         matches!(self, MemberExpression::ComputedMemberExpression(_))
     }
 
+    pub fn optional(&self) -> bool {
+        match self {
+            MemberExpression::ComputedMemberExpression(expr) => expr.optional,
+            MemberExpression::StaticMemberExpression(expr) => expr.optional,
+            MemberExpression::PrivateFieldExpression(expr) => expr.optional,
+        }
+    }
+
     pub fn object(&self) -> &Expression<'a> {
         match self {
             MemberExpression::ComputedMemberExpression(expr) => &expr.object,
             MemberExpression::StaticMemberExpression(expr) => &expr.object,
+            MemberExpression::PrivateFieldExpression(expr) => &expr.object,
         }
     }
 
@@ -49,6 +72,7 @@ impl<'a> MemberExpression<'a> {
                 _ => None,
             },
             MemberExpression::StaticMemberExpression(expr) => Some(expr.property.name.as_str()),
+            MemberExpression::PrivateFieldExpression(_) => None,
         }
     }
 
@@ -61,6 +85,7 @@ impl<'a> MemberExpression<'a> {
             MemberExpression::StaticMemberExpression(expr) => {
                 Some((expr.property.span, &expr.property.name))
             }
+            MemberExpression::PrivateFieldExpression(_) => None,
         }
     }
 
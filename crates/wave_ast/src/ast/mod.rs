@@ -5,26 +5,36 @@ mod class;
 mod expression;
 mod function_declaration;
 mod identifier;
+mod match_expression;
 mod member_expression;
 mod module;
 mod variable_declaration;
 
 pub use crate::ast::function_declaration::{
-    FormalParameter, FormalParameterKind, FormalParameters, Function, FunctionBody, FunctionType,
+    AwaitExpression, FormalParameter, FormalParameterKind, FormalParameters, Function,
+    FunctionBody, FunctionType, YieldExpression,
 };
 pub use crate::ast::variable_declaration::{
     VariableDeclaration, VariableDeclarationKind, VariableDeclarator,
 };
 pub use assignment_expression::{AssignmentExpression, AssignmentTarget, SimpleAssignmentTarget};
-pub use binding::{BindingIdentifier, BindingPattern, BindingPatternKind};
+pub use binding::{
+    ArrayPattern, AssignmentPattern, BindingIdentifier, BindingPattern, BindingPatternKind,
+    BindingProperty, ObjectPattern, RestElement,
+};
 pub use call_expression::CallExpression;
 pub use class::{
     Class, ClassBody, ClassElement, ClassType, MethodDefinition, MethodDefinitionKind,
-    PropertyDefinition, PropertyKey,
+    PrivateIdentifier, PropertyDefinition, PropertyKey,
 };
 pub use expression::Expression;
 pub use identifier::IdentifierReference;
-pub use member_expression::{ComputedMemberExpression, MemberExpression, StaticMemberExpression};
+pub use match_expression::{
+    MatchArm, MatchArrayPattern, MatchExpression, MatchInstancePattern, MatchPattern, MatchProperty,
+};
+pub use member_expression::{
+    ComputedMemberExpression, MemberExpression, PrivateFieldExpression, StaticMemberExpression,
+};
 pub use module::{
     ImportDeclaration, ImportDeclarationSpecifier, ImportSpecifier, ModuleDeclaration,
     ModuleExportName,
@@ -65,9 +75,14 @@ pub enum Statement<'a> {
     BlockStatement(Box<'a, BlockStatement<'a>>),
     ReturnStatement(Box<'a, ReturnStatement<'a>>),
     WhileStatement(Box<'a, WhileStatement<'a>>),
+    ForOfStatement(Box<'a, ForOfStatement<'a>>),
+    SwitchStatement(Box<'a, SwitchStatement<'a>>),
+    LabeledStatement(Box<'a, LabeledStatement<'a>>),
     BreakStatement(Box<'a, BreakStatement>),
     ContinueStatement(Box<'a, ContinueStatement>),
     ModuleDeclaration(Box<'a, ModuleDeclaration<'a>>),
+    /// A statement that failed to parse, kept so the rest of the program can still be inspected
+    InvalidStatement(Box<'a, InvalidStatement>),
 }
 
 #[derive(Debug, Hash)]
@@ -155,13 +170,86 @@ pub struct ArrayExpression<'a> {
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ArrayExpressionElement<'a> {
+    SpreadElement(Box<'a, SpreadElement<'a>>),
     Expression(Expression<'a>),
 }
 
+/// Spread Element, `...array` in array literals and call arguments
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct SpreadElement<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub argument: Expression<'a>,
+}
+
+/// Conditional Expression
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct ConditionalExpression<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub test: Expression<'a>,
+    pub consequent: Expression<'a>,
+    pub alternate: Expression<'a>,
+}
+
+/// Chain Expression, wraps a member access or call containing `?.`
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct ChainExpression<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub expression: ChainElement<'a>,
+}
+
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
+pub enum ChainElement<'a> {
+    CallExpression(Box<'a, CallExpression<'a>>),
+    MemberExpression(Box<'a, MemberExpression<'a>>),
+}
+
+/// Template Literal
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct TemplateLiteral<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub quasis: Vec<'a, TemplateElement>,
+    pub expressions: Vec<'a, Expression<'a>>,
+}
+
+impl<'a> TemplateLiteral<'a> {
+    pub fn is_no_substitution_template(&self) -> bool {
+        self.expressions.is_empty()
+    }
+}
+
+/// Template Element, the text between two substitutions
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct TemplateElement {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub tail: bool,
+    pub value: TemplateElementValue,
+}
+
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct TemplateElementValue {
+    /// The text as written in the source, escapes included
+    pub raw: Atom,
+    /// The text with escapes decoded
+    pub cooked: Atom,
+}
+
 /// Argument
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum Argument<'a> {
+    SpreadElement(Box<'a, SpreadElement<'a>>),
     Expression(Expression<'a>),
 }
 
@@ -207,12 +295,72 @@ pub struct WhileStatement<'a> {
     pub body: Statement<'a>,
 }
 
+/// For-Of Statement
+///
+/// `for (let x of items) body`, iterating any value that implements the iterator protocol.
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct ForOfStatement<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub left: Box<'a, VariableDeclaration<'a>>,
+    pub right: Expression<'a>,
+    pub body: Statement<'a>,
+}
+
+/// Switch Statement
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct SwitchStatement<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub discriminant: Expression<'a>,
+    pub cases: Vec<'a, SwitchCase<'a>>,
+}
+
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct SwitchCase<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    /// `None` for the `default` case
+    pub test: Option<Expression<'a>>,
+    pub consequent: Vec<'a, Statement<'a>>,
+}
+
+impl<'a> SwitchCase<'a> {
+    pub fn is_default_case(&self) -> bool {
+        self.test.is_none()
+    }
+}
+
+/// Invalid Statement
+///
+/// Covers the source text skipped while recovering from a syntax error.
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct InvalidStatement {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+}
+
+/// Labeled Statement
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct LabeledStatement<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub label: LabelIdentifier,
+    pub body: Statement<'a>,
+}
+
 /// Continue Statement
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ContinueStatement {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    pub label: Option<LabelIdentifier>,
 }
 
 /// Break Statement
@@ -221,6 +369,16 @@ pub struct ContinueStatement {
 pub struct BreakStatement {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    pub label: Option<LabelIdentifier>,
+}
+
+/// Label Identifier
+#[derive(Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct LabelIdentifier {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub name: Atom,
 }
 
 /// Identifier Name
@@ -1,7 +1,7 @@
 use crate::ast::{
-    Argument, ArrayExpression, ArrayExpressionElement, AssignmentExpression, AssignmentTarget, BinaryExpression, BindingIdentifier, BindingPattern, BindingPatternKind, BlockStatement, BreakStatement, CallExpression, Class, ClassBody, ClassElement, ClassType, ComputedMemberExpression, ContinueStatement, Declaration, Expression, ExpressionStatement, FormalParameter, FormalParameterKind, FormalParameters, Function, FunctionBody, FunctionType, IdentifierName, IdentifierReference, IfStatement, ImportDeclaration, ImportDeclarationSpecifier, LogicalExpression, MemberExpression, ModuleDeclaration, NewExpression, ParenthesizedExpression, Program, PropertyDefinition, PropertyKey, ReturnStatement, SequenceExpression, SimpleAssignmentTarget, Statement, StaticMemberExpression, Super, ThisExpression, UnaryExpression, UpdateExpression, VariableDeclaration, VariableDeclarationKind, VariableDeclarator, WhileStatement
+    Argument, ArrayExpression, ArrayExpressionElement, ArrayPattern, AssignmentExpression, AssignmentPattern, AssignmentTarget, AwaitExpression, BinaryExpression, BindingIdentifier, BindingPattern, BindingPatternKind, BindingProperty, BlockStatement, BreakStatement, CallExpression, ChainElement, ChainExpression, Class, ClassBody, ClassElement, ClassType, ComputedMemberExpression, ConditionalExpression, ContinueStatement, Declaration, Expression, ExpressionStatement, FormalParameter, FormalParameterKind, FormalParameters, ForOfStatement, Function, FunctionBody, FunctionType, IdentifierName, IdentifierReference, IfStatement, ImportDeclaration, ImportDeclarationSpecifier, InvalidStatement, LabelIdentifier, LabeledStatement, LogicalExpression, MatchArm, MatchArrayPattern, MatchExpression, MatchInstancePattern, MatchPattern, MatchProperty, MemberExpression, ModuleDeclaration, NewExpression, ObjectPattern, ParenthesizedExpression, PrivateFieldExpression, PrivateIdentifier, Program, PropertyDefinition, PropertyKey, RestElement, ReturnStatement, SequenceExpression, SimpleAssignmentTarget, SpreadElement, Statement, StaticMemberExpression, Super, SwitchCase, SwitchStatement, TemplateElement, TemplateLiteral, ThisExpression, UnaryExpression, UpdateExpression, VariableDeclaration, VariableDeclarationKind, VariableDeclarator, WhileStatement, YieldExpression
 };
-use crate::literal::{BooleanLiteral, NullLiteral, NumberLiteral, StringLiteral};
+use crate::literal::{BigIntLiteral, BooleanLiteral, NullLiteral, NumberLiteral, StringLiteral};
 use wave_allocator::{Allocator, Box, Vec};
 use wave_span::Span;
 use wave_syntax::operator::{
@@ -55,6 +55,23 @@ impl<'a> AstBuilder<'a> {
         Expression::NumberLiteral(self.alloc(literal))
     }
 
+    pub fn literal_bigint_expression(&self, literal: BigIntLiteral<'a>) -> Expression<'a> {
+        Expression::BigIntLiteral(self.alloc(literal))
+    }
+
+    pub fn template_literal(
+        &self,
+        span: Span,
+        quasis: Vec<'a, TemplateElement>,
+        expressions: Vec<'a, Expression<'a>>,
+    ) -> Expression<'a> {
+        Expression::TemplateLiteral(self.alloc(TemplateLiteral {
+            span,
+            quasis,
+            expressions,
+        }))
+    }
+
     pub fn variable_declaration(
         &self,
         span: Span,
@@ -96,6 +113,65 @@ impl<'a> AstBuilder<'a> {
         BindingPatternKind::BindingIdentifier(self.alloc(identifier))
     }
 
+    pub fn object_pattern(
+        &self,
+        span: Span,
+        properties: Vec<'a, BindingProperty<'a>>,
+        rest: Option<Box<'a, RestElement<'a>>>,
+    ) -> BindingPatternKind<'a> {
+        BindingPatternKind::ObjectPattern(self.alloc(ObjectPattern {
+            span,
+            properties,
+            rest,
+        }))
+    }
+
+    pub fn binding_property(
+        &self,
+        span: Span,
+        key: PropertyKey<'a>,
+        value: BindingPattern<'a>,
+        shorthand: bool,
+    ) -> BindingProperty<'a> {
+        BindingProperty {
+            span,
+            key,
+            value,
+            shorthand,
+        }
+    }
+
+    pub fn array_pattern(
+        &self,
+        span: Span,
+        elements: Vec<'a, Option<BindingPattern<'a>>>,
+        rest: Option<Box<'a, RestElement<'a>>>,
+    ) -> BindingPatternKind<'a> {
+        BindingPatternKind::ArrayPattern(self.alloc(ArrayPattern {
+            span,
+            elements,
+            rest,
+        }))
+    }
+
+    pub fn assignment_pattern(
+        &self,
+        span: Span,
+        left: BindingPattern<'a>,
+        right: Expression<'a>,
+    ) -> BindingPattern<'a> {
+        let pattern = self.alloc(AssignmentPattern { span, left, right });
+        self.binding_pattern(BindingPatternKind::AssignmentPattern(pattern))
+    }
+
+    pub fn rest_element(
+        &self,
+        span: Span,
+        argument: BindingPattern<'a>,
+    ) -> Box<'a, RestElement<'a>> {
+        self.alloc(RestElement { span, argument })
+    }
+
     pub fn identifier_reference_expression(&self, ident: IdentifierReference) -> Expression<'a> {
         Expression::Identifier(self.alloc(ident))
     }
@@ -173,15 +249,32 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         kind: FormalParameterKind,
         items: Vec<'a, FormalParameter<'a>>,
+        rest: Option<Box<'a, RestElement<'a>>>,
     ) -> Box<'a, FormalParameters<'a>> {
-        self.alloc(FormalParameters { span, kind, items })
+        self.alloc(FormalParameters {
+            span,
+            kind,
+            items,
+            rest,
+        })
+    }
+
+    pub fn spread_element(
+        &self,
+        span: Span,
+        argument: Expression<'a>,
+    ) -> Box<'a, SpreadElement<'a>> {
+        self.alloc(SpreadElement { span, argument })
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn function(
         &self,
         r#type: FunctionType,
         span: Span,
         id: Option<BindingIdentifier>,
+        generator: bool,
+        r#async: bool,
         params: Box<'a, FormalParameters<'a>>,
         body: Option<Box<'a, FunctionBody<'a>>>,
     ) -> Box<'a, Function<'a>> {
@@ -189,11 +282,30 @@ impl<'a> AstBuilder<'a> {
             r#type,
             span,
             id,
+            generator,
+            r#async,
             params,
             body,
         })
     }
 
+    pub fn yield_expression(
+        &self,
+        span: Span,
+        delegate: bool,
+        argument: Option<Expression<'a>>,
+    ) -> Expression<'a> {
+        Expression::YieldExpression(self.alloc(YieldExpression {
+            span,
+            delegate,
+            argument,
+        }))
+    }
+
+    pub fn await_expression(&self, span: Span, argument: Expression<'a>) -> Expression<'a> {
+        Expression::AwaitExpression(self.alloc(AwaitExpression { span, argument }))
+    }
+
     pub fn function_body(
         &self,
         span: Span,
@@ -242,14 +354,74 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         callee: Expression<'a>,
         arguments: Vec<'a, Argument<'a>>,
+        optional: bool,
     ) -> Expression<'a> {
         Expression::CallExpression(self.alloc(CallExpression {
             span,
             callee,
             arguments,
+            optional,
         }))
     }
 
+    pub fn conditional_expression(
+        &self,
+        span: Span,
+        test: Expression<'a>,
+        consequent: Expression<'a>,
+        alternate: Expression<'a>,
+    ) -> Expression<'a> {
+        Expression::ConditionalExpression(self.alloc(ConditionalExpression {
+            span,
+            test,
+            consequent,
+            alternate,
+        }))
+    }
+
+    pub fn match_expression(
+        &self,
+        span: Span,
+        discriminant: Expression<'a>,
+        arms: Vec<'a, MatchArm<'a>>,
+    ) -> Expression<'a> {
+        Expression::MatchExpression(self.alloc(MatchExpression {
+            span,
+            discriminant,
+            arms,
+        }))
+    }
+
+    pub fn match_array_pattern(
+        &self,
+        span: Span,
+        elements: Vec<'a, MatchPattern<'a>>,
+        rest: Option<Box<'a, BindingIdentifier>>,
+    ) -> MatchPattern<'a> {
+        MatchPattern::Array(self.alloc(MatchArrayPattern {
+            span,
+            elements,
+            rest,
+        }))
+    }
+
+    pub fn match_instance_pattern(
+        &self,
+        span: Span,
+        class: IdentifierReference,
+        properties: Vec<'a, MatchProperty<'a>>,
+    ) -> MatchPattern<'a> {
+        MatchPattern::Instance(self.alloc(MatchInstancePattern {
+            span,
+            class,
+            properties,
+        }))
+    }
+
+    pub fn chain_expression(&self, span: Span, expression: ChainElement<'a>) -> Expression<'a> {
+        Expression::ChainExpression(self.alloc(ChainExpression { span, expression }))
+    }
+
     pub fn unary_expression(
         &self,
         span: Span,
@@ -302,12 +474,66 @@ impl<'a> AstBuilder<'a> {
         Statement::WhileStatement(self.alloc(WhileStatement { span, test, body }))
     }
 
-    pub fn break_statement(&self, span: Span) -> Statement<'a> {
-        Statement::BreakStatement(self.alloc(BreakStatement { span }))
+    pub fn for_of_statement(
+        &self,
+        span: Span,
+        left: Box<'a, VariableDeclaration<'a>>,
+        right: Expression<'a>,
+        body: Statement<'a>,
+    ) -> Statement<'a> {
+        Statement::ForOfStatement(self.alloc(ForOfStatement {
+            span,
+            left,
+            right,
+            body,
+        }))
     }
 
-    pub fn continue_statement(&self, span: Span) -> Statement<'a> {
-        Statement::ContinueStatement(self.alloc(ContinueStatement { span }))
+    pub fn switch_statement(
+        &self,
+        span: Span,
+        discriminant: Expression<'a>,
+        cases: Vec<'a, SwitchCase<'a>>,
+    ) -> Statement<'a> {
+        Statement::SwitchStatement(self.alloc(SwitchStatement {
+            span,
+            discriminant,
+            cases,
+        }))
+    }
+
+    pub fn switch_case(
+        &self,
+        span: Span,
+        test: Option<Expression<'a>>,
+        consequent: Vec<'a, Statement<'a>>,
+    ) -> SwitchCase<'a> {
+        SwitchCase {
+            span,
+            test,
+            consequent,
+        }
+    }
+
+    pub fn invalid_statement(&self, span: Span) -> Statement<'a> {
+        Statement::InvalidStatement(self.alloc(InvalidStatement { span }))
+    }
+
+    pub fn labeled_statement(
+        &self,
+        span: Span,
+        label: LabelIdentifier,
+        body: Statement<'a>,
+    ) -> Statement<'a> {
+        Statement::LabeledStatement(self.alloc(LabeledStatement { span, label, body }))
+    }
+
+    pub fn break_statement(&self, span: Span, label: Option<LabelIdentifier>) -> Statement<'a> {
+        Statement::BreakStatement(self.alloc(BreakStatement { span, label }))
+    }
+
+    pub fn continue_statement(&self, span: Span, label: Option<LabelIdentifier>) -> Statement<'a> {
+        Statement::ContinueStatement(self.alloc(ContinueStatement { span, label }))
     }
 
     pub fn static_member_expression(
@@ -315,8 +541,9 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         object: Expression<'a>,
         property: IdentifierName,
+        optional: bool,
     ) -> Expression<'a> {
-        self.member_expression(self.static_member(span, object, property))
+        self.member_expression(self.static_member(span, object, property, optional))
     }
 
     pub fn computed_member_expression(
@@ -324,8 +551,26 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         object: Expression<'a>,
         expression: Expression<'a>,
+        optional: bool,
     ) -> Expression<'a> {
-        self.member_expression(self.computed_member(span, object, expression))
+        self.member_expression(self.computed_member(span, object, expression, optional))
+    }
+
+    pub fn private_field_expression(
+        &self,
+        span: Span,
+        object: Expression<'a>,
+        field: PrivateIdentifier,
+        optional: bool,
+    ) -> Expression<'a> {
+        self.member_expression(MemberExpression::PrivateFieldExpression(
+            PrivateFieldExpression {
+                span,
+                object,
+                field,
+                optional,
+            },
+        ))
     }
 
     pub fn member_expression(&self, expr: MemberExpression<'a>) -> Expression<'a> {
@@ -337,11 +582,13 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         object: Expression<'a>,
         property: IdentifierName,
+        optional: bool,
     ) -> MemberExpression<'a> {
         let static_member_expression = StaticMemberExpression {
             span,
             object,
             property,
+            optional,
         };
         MemberExpression::StaticMemberExpression(static_member_expression)
     }
@@ -351,11 +598,13 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         object: Expression<'a>,
         expression: Expression<'a>,
+        optional: bool,
     ) -> MemberExpression<'a> {
         MemberExpression::ComputedMemberExpression(ComputedMemberExpression {
             span,
             object,
             expression,
+            optional,
         })
     }
     pub fn this_expression(&self, span: Span) -> Expression<'a> {
@@ -413,8 +662,14 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         key: PropertyKey<'a>,
         value: Option<Expression<'a>>,
+        r#static: bool,
     ) -> ClassElement<'a> {
-        ClassElement::PropertyDefinition(self.alloc(PropertyDefinition { span, key, value }))
+        ClassElement::PropertyDefinition(self.alloc(PropertyDefinition {
+            span,
+            key,
+            value,
+            r#static,
+        }))
     }
 
     pub fn function_expression(&self, function: Box<'a, Function<'a>>) -> Expression<'a> {
@@ -6,8 +6,12 @@ pub mod span;
 
 #[cfg(feature = "serde")]
 mod serialize;
+#[cfg(feature = "serde")]
+pub use crate::serialize::EcmaFormatter;
 
 mod trivia;
 
-pub use crate::literal::{BooleanLiteral, NullLiteral, NumberLiteral, StringLiteral};
+pub use crate::literal::{
+    BigIntLiteral, BooleanLiteral, NullLiteral, NumberLiteral, StringLiteral,
+};
 pub use crate::trivia::{Comment, CommentKind, Trivias, TriviasMap};
@@ -1,5 +1,6 @@
 use std::hash::{Hash, Hasher};
 
+use num_bigint::BigInt;
 use wave_span::{Atom, Span};
 
 #[cfg(feature = "serde")]
@@ -68,6 +69,38 @@ impl<'a> Hash for NumberLiteral<'a> {
     }
 }
 
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct BigIntLiteral<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_bigint"))]
+    pub value: BigInt,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub raw: &'a str,
+}
+
+impl<'a> BigIntLiteral<'a> {
+    pub fn new(span: Span, value: BigInt, raw: &'a str) -> Self {
+        Self { span, value, raw }
+    }
+}
+
+impl<'a> Hash for BigIntLiteral<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+/// BigInts are written as decimal strings, JSON numbers would lose precision
+#[cfg(feature = "serde")]
+fn serialize_bigint<S: serde::Serializer>(
+    value: &BigInt,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
 #[derive(Debug, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct StringLiteral {
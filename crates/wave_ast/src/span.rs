@@ -1,6 +1,9 @@
 use wave_span::{GetSpan, Span};
 
-use crate::ast::{Declaration, Expression, MemberExpression, ModuleDeclaration, Statement};
+use crate::ast::{
+    BindingPattern, BindingPatternKind, Declaration, Expression, MatchPattern, MemberExpression,
+    ModuleDeclaration, PropertyKey, Statement,
+};
 
 impl<'a> GetSpan for Statement<'a> {
     fn span(&self) -> Span {
@@ -12,6 +15,10 @@ impl<'a> GetSpan for Statement<'a> {
             Self::IfStatement(stmt) => stmt.span,
             Self::ReturnStatement(stmt) => stmt.span,
             Self::WhileStatement(stmt) => stmt.span,
+            Self::ForOfStatement(stmt) => stmt.span,
+            Self::SwitchStatement(stmt) => stmt.span,
+            Self::LabeledStatement(stmt) => stmt.span,
+            Self::InvalidStatement(stmt) => stmt.span,
             Self::Declaration(decl) => decl.span(),
             Self::ModuleDeclaration(decl) => decl.span(),
         }
@@ -24,6 +31,8 @@ impl<'a> GetSpan for Expression<'a> {
             Self::BooleanLiteral(e) => e.span,
             Self::NullLiteral(e) => e.span,
             Self::NumberLiteral(e) => e.span,
+            Self::TemplateLiteral(e) => e.span,
+            Self::BigIntLiteral(e) => e.span,
             Self::StringLiteral(e) => e.span,
             Self::Identifier(e) => e.span,
             Self::AssignmentExpression(e) => e.span,
@@ -35,11 +44,16 @@ impl<'a> GetSpan for Expression<'a> {
             Self::UnaryExpression(e) => e.span,
             Self::UpdateExpression(e) => e.span,
             Self::LogicalExpression(e) => e.span,
+            Self::ConditionalExpression(e) => e.span,
+            Self::ChainExpression(e) => e.span,
             Self::ThisExpression(e) => e.span,
             Self::Super(e) => e.span,
+            Self::YieldExpression(e) => e.span,
+            Self::AwaitExpression(e) => e.span,
             Self::NewExpression(e) => e.span,
             Self::MemberExpression(e) => e.span(),
             Self::FunctionExpression(e) => e.span,
+            Self::MatchExpression(e) => e.span,
         }
     }
 }
@@ -59,6 +73,29 @@ impl<'a> GetSpan for MemberExpression<'a> {
         match self {
             Self::ComputedMemberExpression(expr) => expr.span,
             Self::StaticMemberExpression(expr) => expr.span,
+            Self::PrivateFieldExpression(expr) => expr.span,
+        }
+    }
+}
+
+impl<'a> GetSpan for BindingPattern<'a> {
+    fn span(&self) -> Span {
+        match &self.kind {
+            BindingPatternKind::BindingIdentifier(ident) => ident.span,
+            BindingPatternKind::ObjectPattern(pat) => pat.span,
+            BindingPatternKind::ArrayPattern(pat) => pat.span,
+            BindingPatternKind::AssignmentPattern(pat) => pat.span,
+        }
+    }
+}
+
+impl<'a> GetSpan for MatchPattern<'a> {
+    fn span(&self) -> Span {
+        match self {
+            Self::Literal(expr) => expr.span(),
+            Self::Identifier(ident) => ident.span,
+            Self::Array(pat) => pat.span,
+            Self::Instance(pat) => pat.span,
         }
     }
 }
@@ -70,3 +107,13 @@ impl<'a> GetSpan for ModuleDeclaration<'a> {
         }
     }
 }
+
+impl<'a> GetSpan for PropertyKey<'a> {
+    fn span(&self) -> Span {
+        match self {
+            Self::Identifier(ident) => ident.span,
+            Self::PrivateIdentifier(ident) => ident.span,
+            Self::Expression(expr) => expr.span(),
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::environment::Environment;
+use crate::evaluator::Primitive;
+use crate::Runtime;
+use wave_allocator::Allocator;
+use wave_ast::ast::Statement;
+use wave_diagnostics::Result;
+use wave_parser::Parser;
+use wave_span::{GetSpan, Span};
+
+/// Follows the evaluation to pause it, like at breakpoints or after a step.
+///
+/// The runtime calls the debugger before every statement and around every call. Pausing is
+/// blocking in [`Debugger::statement`] until the evaluation should go on. While it runs, the
+/// debugger is detached from the runtime, so expressions it evaluates are not reported to it.
+pub trait Debugger<'a> {
+    /// Called before a statement is evaluated, blocks are not reported but their statements are.
+    fn statement(
+        &mut self,
+        runtime: &Runtime<'a>,
+        span: Span,
+        environment: &Rc<RefCell<Environment<'a>>>,
+    );
+
+    /// Called before a function is called by the expression at `call_span`.
+    fn enter(&mut self, _call_span: Span) {}
+
+    /// Called once the last function entered returned or failed.
+    fn exit(&mut self) {}
+}
+
+impl<'a> Runtime<'a> {
+    pub(crate) fn debug_statement(
+        &self,
+        statement: &Statement<'a>,
+        environment: &Rc<RefCell<Environment<'a>>>,
+    ) {
+        if matches!(statement, Statement::BlockStatement(_)) {
+            return;
+        }
+        self.notify_debugger(|runtime, debugger| {
+            debugger.statement(runtime, statement.span(), environment);
+        });
+    }
+
+    pub(crate) fn debug_enter(&self, call_span: Span) {
+        self.notify_debugger(|_, debugger| debugger.enter(call_span));
+    }
+
+    pub(crate) fn debug_exit(&self) {
+        self.notify_debugger(|_, debugger| debugger.exit());
+    }
+
+    /// Detaches the debugger while it runs, so it can evaluate code itself.
+    fn notify_debugger(&self, f: impl FnOnce(&Self, &mut dyn Debugger<'a>)) {
+        let Some(mut debugger) = self.debugger.borrow_mut().take() else {
+            return;
+        };
+        f(self, debugger.as_mut());
+        *self.debugger.borrow_mut() = Some(debugger);
+    }
+
+    /// Evaluates `source` in `environment`, like the watch expressions of a debugger.
+    ///
+    /// Values the source creates, like functions, can be stored in the environment and outlive
+    /// this call, so the source and its syntax tree are allocated in `allocator`, an arena
+    /// living as long as the runtime like the one of the program.
+    pub fn evaluate(
+        &self,
+        allocator: &'a Allocator,
+        source: &str,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        let source = allocator.alloc_str(source);
+        let mut ret = Parser::new(allocator, source).parse();
+        if !ret.errors.is_empty() {
+            return Err(ret.errors.remove(0));
+        }
+        let program = allocator.alloc(ret.program);
+        let mut result = Primitive::Null;
+        for statement in &program.body {
+            result = self.eval_statement(statement, Rc::clone(&environment))?;
+        }
+        self.unwrap_return_value(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::environment::Environment;
+    use crate::Runtime;
+    use wave_allocator::Allocator;
+    use wave_parser::Parser;
+    use wave_span::Span;
+
+    use super::Debugger;
+
+    /// Records the line and call depth of every statement, and the value of `x` on line 2.
+    struct Recorder<'a> {
+        allocator: &'a Allocator,
+        source: &'a str,
+        depth: usize,
+        trace: Rc<RefCell<Vec<(usize, usize)>>>,
+        watched: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl<'a> Debugger<'a> for Recorder<'a> {
+        fn statement(
+            &mut self,
+            runtime: &Runtime<'a>,
+            span: Span,
+            environment: &Rc<RefCell<Environment<'a>>>,
+        ) {
+            let line = self.source[..span.start as usize].matches('\n').count() + 1;
+            self.trace.borrow_mut().push((line, self.depth));
+            if line == 2 {
+                let value = runtime.evaluate(self.allocator, "x * 2;", Rc::clone(environment));
+                self.watched.borrow_mut().push(match value {
+                    Ok(value) => format!("{:?}", value),
+                    Err(error) => error.to_string(),
+                });
+            }
+        }
+
+        fn enter(&mut self, _call_span: Span) {
+            self.depth += 1;
+        }
+
+        fn exit(&mut self) {
+            self.depth -= 1;
+        }
+    }
+
+    #[test]
+    fn debugger_hook() {
+        let source = "function double(x) {
+            return x * 2;
+        }
+        let a = double(1);
+        double(a);";
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source).parse().program;
+        let trace = Rc::default();
+        let watched = Rc::default();
+        let recorder = Recorder {
+            allocator: &allocator,
+            source,
+            depth: 0,
+            trace: Rc::clone(&trace),
+            watched: Rc::clone(&watched),
+        };
+
+        Runtime::new(program)
+            .with_debugger(recorder)
+            .eval()
+            .unwrap();
+
+        assert_eq!(*trace.borrow(), [(1, 0), (4, 0), (2, 1), (5, 0), (2, 1)]);
+        assert_eq!(*watched.borrow(), ["2", "4"]);
+    }
+
+    #[test]
+    fn evaluate() {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, "let x = 1;").parse().program;
+        let runtime = Runtime::new(program);
+        let environment = runtime.eval_environment().unwrap();
+        let evaluate = |source| runtime.evaluate(&allocator, source, Rc::clone(&environment));
+
+        // functions created by an evaluation outlive it
+        evaluate("x = x + 1; function f() { return x; }").unwrap();
+        assert_eq!(
+            format!("{:?}", evaluate("[f(), typeof x];").unwrap()),
+            "[2, number]"
+        );
+        assert_eq!(
+            evaluate("y;").unwrap_err().to_string(),
+            "Variable not found."
+        );
+        assert!(evaluate("x +;").is_err());
+    }
+}
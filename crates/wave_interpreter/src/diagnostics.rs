@@ -15,23 +15,36 @@ pub struct InvalidNumber(#[label] pub Span);
 #[diagnostic(help("Will be implemented in the future."))]
 pub struct OperatorNotImplemented(pub &'static str, #[label] pub Span);
 
-#[derive(Debug, Error, Diagnostic)]
-#[error("Not a boolean.")]
-#[diagnostic(help("This operation can only be performed on booleans."))]
-pub struct InvalidBoolean(#[label] pub Span);
-
 #[derive(Debug, Error, Diagnostic)]
 #[error("Type mismatch.")]
 #[diagnostic(help("This operation can only be performed on expressions with same type."))]
 pub struct TypeMismatch(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Cannot mix BigInt and Number in arithmetic.")]
+#[diagnostic(help("Convert explicitly with 'BigInt(value)' or 'Number(value)'."))]
+pub struct MixedBigIntArithmetic(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Division by zero.")]
+pub struct DivisionByZero(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("BigInt exponent must be a non-negative integer that fits in 32 bits.")]
+pub struct InvalidBigIntExponent(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Cannot convert {0} to a BigInt.")]
+#[diagnostic(help("Only integers and strings of decimal digits can be converted."))]
+pub struct InvalidBigIntConversion(pub String);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Variable not found.")]
 pub struct VariableNotFound(#[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("Invalid number of arguments.")]
-pub struct InvalidNumberOfArguments(#[label] pub Span);
+#[error("Expected {0}, but got {1}.")]
+pub struct InvalidNumberOfArguments(pub String, pub usize, #[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("Cannot redeclare inbuilt function.")]
@@ -42,10 +55,31 @@ pub struct CannotRedeclareInbuiltFunction(#[label] pub Span);
 #[error("Cannot instantiate non-class declaration.")]
 pub struct CannotInstantiateNonClass(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("'super' used in a class without a parent class.")]
+pub struct SuperWithoutParentClass(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Right-hand side of 'instanceof' is not a class.")]
+pub struct InvalidInstanceofTarget(#[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Cannot access property.")]
 pub struct CannotAccessProperty(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Cannot assign to a property with only a getter.")]
+#[diagnostic(help("Define a setter for the property with `set`."))]
+pub struct AssignToGetterOnly(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Cannot access private member '#{0}' of an object whose class did not declare it.")]
+pub struct InvalidPrivateAccess(pub String, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Class members can only be named by identifiers, strings or numbers.")]
+pub struct UnsupportedPropertyKey(#[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Cannot call non-function expressions.")]
 pub struct CannotCallNonFunction(#[label] pub Span);
@@ -65,3 +99,113 @@ pub struct NotAnArray();
 #[derive(Debug, Error, Diagnostic)]
 #[error("Failed to import file.")]
 pub struct ImportFailure();
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unknown native module 'std:{0}'.")]
+#[diagnostic(help("Available native modules are: {1}."))]
+pub struct UnknownNativeModule(pub String, pub &'static str, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Module 'std:{0}' has no export named '{1}'.")]
+pub struct NativeExportNotFound(pub &'static str, pub String, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("I/O is disabled for this runtime.")]
+#[diagnostic(help("The host has turned off access to 'std:io'."))]
+pub struct IoDisabled(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to {0} {1:?} with error \"{2}\"")]
+pub struct IoFailure(pub &'static str, pub String, pub std::io::Error);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Argument {1} of '{0}' must be a {2}.")]
+pub struct InvalidNativeArgument(pub &'static str, pub usize, pub &'static str);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("'{0}' expects {1}.")]
+pub struct InvalidReflectionTarget(pub &'static str, pub &'static str, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid JSON at line {1}, column {2}: {0}.")]
+pub struct InvalidJson(pub String, pub usize, pub usize);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("{0} cannot be converted to JSON.")]
+#[diagnostic(help(
+    "JSON can hold null, booleans, numbers, strings, arrays, instances and Maps with string keys."
+))]
+pub struct InvalidJsonValue(pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Cannot destructure a non-iterable value with an array pattern.")]
+pub struct InvalidArrayDestructuring(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Cannot destructure a non-instance value with an object pattern.")]
+pub struct InvalidObjectDestructuring(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("No element at index {0} to destructure.")]
+#[diagnostic(help("Give the binding a default value, e.g. `[a = null]`."))]
+pub struct MissingDestructuredElement(pub usize, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("No property '{0}' to destructure.")]
+#[diagnostic(help("Give the binding a default value, e.g. `{{ a = null }}`."))]
+pub struct MissingDestructuredProperty(pub String, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Only iterable values can be spread.")]
+pub struct InvalidSpread(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Value is not iterable.")]
+#[diagnostic(help(
+    "Arrays, strings, generators and instances with an `iterator()` method can be iterated."
+))]
+pub struct NotIterable(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The result of an iterator's `next()` must have a boolean `done` property.")]
+pub struct InvalidIteratorResult(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("No match arm matches the value {0}.")]
+#[diagnostic(help("Add a catch-all arm, e.g. `other => null`."))]
+pub struct NoMatchingArm(pub String, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Instance patterns must name a class.")]
+pub struct InvalidMatchClass(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Cannot evaluate a statement with syntax errors.")]
+pub struct InvalidStatement(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Generator is already running.")]
+#[diagnostic(help("A generator cannot resume itself from its own body."))]
+pub struct GeneratorAlreadyRunning(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("'yield' cannot suspend the generator from here.")]
+pub struct UnsupportedYield(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("'await' cannot suspend the async function from here.")]
+pub struct UnsupportedAwait(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Uncaught promise rejection: {0}")]
+#[diagnostic(help("Handle the rejection with `catch(handler)` or a second handler to `then`."))]
+pub struct UnhandledRejection(pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Value cannot be used as a key.")]
+#[diagnostic(help("Keys can be numbers, strings, booleans, BigInts, null or instances."))]
+pub struct InvalidCollectionKey(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The entries of a Map must be `[key, value]` arrays.")]
+pub struct InvalidMapEntry(#[label] pub Span);
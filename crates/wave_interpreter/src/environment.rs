@@ -2,6 +2,7 @@ use std::{cell::RefCell, rc::Rc};
 
 use crate::{diagnostics, evaluator::Primitive};
 use rustc_hash::FxHashMap;
+use wave_ast::ast::PropertyDefinition;
 use wave_diagnostics::Result;
 use wave_span::{Atom, Span};
 
@@ -12,6 +13,9 @@ pub struct Environment<'a> {
     // REFACTOR : Repalce this rc to environment with scope tree from the sematic analysis
     // I can probably just slug the variable name and use the scope tree to find the variable
     pub outer: Option<Rc<RefCell<Environment<'a>>>>,
+    /// Instance field initializers when this is the environment of a class,
+    /// evaluated again for every new instance.
+    pub fields: Vec<&'a PropertyDefinition<'a>>,
 }
 
 impl<'a> Environment<'a> {
@@ -38,6 +42,20 @@ impl<'a> Environment<'a> {
         }
     }
 
+    /// Assigns the nearest binding of `name`, defining it here when there is none.
+    pub fn assign(&mut self, name: Atom, value: Primitive<'a>) {
+        if !self.values.contains_key(&name) {
+            if let Some(outer) = &self.outer {
+                if outer.borrow().get(name.clone(), Span::default()).is_ok() {
+                    // the binding exists, so this cannot fail
+                    let _ = outer.borrow_mut().mutate(name, value);
+                    return;
+                }
+            }
+        }
+        self.values.insert(name, value);
+    }
+
     pub fn define(&mut self, name: Atom, value: Primitive<'a>) {
         self.values.insert(name, value);
     }
@@ -46,6 +64,7 @@ impl<'a> Environment<'a> {
         Environment {
             values: FxHashMap::default(),
             outer: Some(outer),
+            fields: Vec::new(),
         }
     }
 }
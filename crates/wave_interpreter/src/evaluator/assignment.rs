@@ -38,7 +38,9 @@ impl<'a> Runtime<'a> {
                 self.eval_bitwise_assignment(expression, environment)
             }
 
-            AssignmentOperator::LogicalOr | AssignmentOperator::LogicalAnd => {
+            AssignmentOperator::LogicalOr
+            | AssignmentOperator::LogicalAnd
+            | AssignmentOperator::LogicalNullish => {
                 self.eval_logical_assignment(expression, environment)
             }
         }
@@ -56,23 +58,31 @@ impl<'a> Runtime<'a> {
                 SimpleAssignmentTarget::AssignmentTargetIdentifier(identifier) => {
                     environment
                         .borrow_mut()
-                        .define(identifier.name.to_owned(), right_eval);
+                        .assign(identifier.name.to_owned(), right_eval);
                 }
                 SimpleAssignmentTarget::MemberAssignmentTarget(member_expression) => unsafe {
                     let member_expression = ptr::read(member_expression).unbox();
                     match member_expression {
                         MemberExpression::StaticMemberExpression(static_member) => {
-                            let env = self
+                            let object = self
                                 .eval_expression(&static_member.object, Rc::clone(&environment))?;
-
-                            let property_name = self.bind_this(static_member.property.name);
-
-                            match env {
-                                Primitive::This(this_env) => {
-                                    this_env.borrow_mut().define(property_name, right_eval);
-                                }
-                                _ => todo!(),
-                            }
+                            self.assign_property(
+                                object,
+                                static_member.property.name,
+                                right_eval,
+                                static_member.span,
+                            )?;
+                        }
+                        MemberExpression::PrivateFieldExpression(private_field) => {
+                            let object = self
+                                .eval_expression(&private_field.object, Rc::clone(&environment))?;
+                            let name = self.get_private_key(
+                                &object,
+                                &private_field.field.name,
+                                &environment,
+                                private_field.span,
+                            )?;
+                            self.assign_property(object, name, right_eval, private_field.span)?;
                         }
                         // TODO : Refactor this so that you do not have to clone the array on every assignment
                         MemberExpression::ComputedMemberExpression(static_member) => {
@@ -105,7 +115,7 @@ impl<'a> Runtime<'a> {
                                     array[index] = right_eval;
                                     environment
                                         .borrow_mut()
-                                        .define(identifier, Primitive::Array(array));
+                                        .assign(identifier, Primitive::Array(array));
                                 }
                                 _ => todo!(),
                             }
@@ -133,49 +143,12 @@ impl<'a> Runtime<'a> {
             .borrow()
             .get(left_identifier.name.to_owned(), left_identifier.span)?;
         let right_eval = self.eval_expression(&expression.right, Rc::clone(&environment))?;
-        match (left_current, right_eval) {
-            (Primitive::Number(l), Primitive::Number(r)) => match &expression.operator {
-                AssignmentOperator::Addition => {
-                    environment
-                        .borrow_mut()
-                        .define(left_identifier.name.to_owned(), Primitive::Number(l + r));
-                    Ok(Primitive::Null)
-                }
-                AssignmentOperator::Subtraction => {
-                    environment
-                        .borrow_mut()
-                        .define(left_identifier.name.to_owned(), Primitive::Number(l - r));
-                    Ok(Primitive::Null)
-                }
-                AssignmentOperator::Multiplication => {
-                    environment
-                        .borrow_mut()
-                        .define(left_identifier.name.to_owned(), Primitive::Number(l * r));
-                    Ok(Primitive::Null)
-                }
-                AssignmentOperator::Division => {
-                    environment
-                        .borrow_mut()
-                        .define(left_identifier.name.to_owned(), Primitive::Number(l / r));
-                    Ok(Primitive::Null)
-                }
-                AssignmentOperator::Remainder => {
-                    environment
-                        .borrow_mut()
-                        .define(left_identifier.name.to_owned(), Primitive::Number(l % r));
-                    Ok(Primitive::Null)
-                }
-                AssignmentOperator::Exponential => {
-                    environment.borrow_mut().define(
-                        left_identifier.name.to_owned(),
-                        Primitive::Number(l.powf(r)),
-                    );
-                    Ok(Primitive::Null)
-                }
-                _ => unreachable!(),
-            },
-            _ => Err(diagnostics::InvalidNumber(expression.span).into()),
-        }
+        let operator = expression.operator.to_binary_operator().unwrap();
+        let value = self.apply_arithmetic(&operator, left_current, right_eval, expression.span)?;
+        environment
+            .borrow_mut()
+            .assign(left_identifier.name.to_owned(), value);
+        Ok(Primitive::Null)
     }
 
     pub fn eval_bitwise_assignment(
@@ -193,35 +166,15 @@ impl<'a> Runtime<'a> {
             .borrow()
             .get(left_identifier.name.to_owned(), left_identifier.span)?;
         let right_eval = self.eval_expression(&expression.right, Rc::clone(&environment))?;
-        match (left_current, right_eval) {
-            (Primitive::Number(l), Primitive::Number(r)) => match &expression.operator {
-                AssignmentOperator::BitwiseOR => {
-                    environment.borrow_mut().define(
-                        left_identifier.name.to_owned(),
-                        Primitive::Number((l as i64 | r as i64) as f64),
-                    );
-                    Ok(Primitive::Null)
-                }
-                AssignmentOperator::BitwiseAnd => {
-                    environment.borrow_mut().define(
-                        left_identifier.name.to_owned(),
-                        Primitive::Number((l as i64 & r as i64) as f64),
-                    );
-                    Ok(Primitive::Null)
-                }
-                AssignmentOperator::BitwiseXOR => {
-                    environment.borrow_mut().define(
-                        left_identifier.name.to_owned(),
-                        Primitive::Number((l as i64 ^ r as i64) as f64),
-                    );
-                    Ok(Primitive::Null)
-                }
-                _ => unreachable!(),
-            },
-            _ => Err(diagnostics::InvalidNumber(expression.span).into()),
-        }
+        let operator = expression.operator.to_binary_operator().unwrap();
+        let value = self.apply_bitwise(&operator, left_current, right_eval, expression.span)?;
+        environment
+            .borrow_mut()
+            .assign(left_identifier.name.to_owned(), value);
+        Ok(Primitive::Null)
     }
 
+    /// Like the logical operators, the right side is only evaluated when it is assigned.
     pub fn eval_logical_assignment(
         &self,
         expression: &Box<'_, AssignmentExpression<'a>>,
@@ -238,24 +191,20 @@ impl<'a> Runtime<'a> {
             .borrow()
             .get(left_identifier.name.to_owned(), left_identifier.span)?;
 
-        let right_eval = self.eval_expression(&expression.right, Rc::clone(&environment))?;
-        match (left_current, right_eval) {
-            (Primitive::Boolean(l), Primitive::Boolean(r)) => match &expression.operator {
-                AssignmentOperator::LogicalOr => {
-                    environment
-                        .borrow_mut()
-                        .define(left_identifier.name.to_owned(), Primitive::Boolean(l || r));
-                    Ok(Primitive::Null)
-                }
-                AssignmentOperator::LogicalAnd => {
-                    environment
-                        .borrow_mut()
-                        .define(left_identifier.name.to_owned(), Primitive::Boolean(l && r));
-                    Ok(Primitive::Null)
-                }
-                _ => unreachable!(),
-            },
-            _ => Err(diagnostics::InvalidBoolean(expression.span).into()),
+        let assign = match expression.operator {
+            AssignmentOperator::LogicalNullish => matches!(left_current, Primitive::Null),
+            AssignmentOperator::LogicalOr => !left_current.is_truthy(),
+            AssignmentOperator::LogicalAnd => left_current.is_truthy(),
+            _ => unreachable!(),
+        };
+        if !assign {
+            return Ok(Primitive::Null);
         }
+
+        let right_eval = self.eval_expression(&expression.right, Rc::clone(&environment))?;
+        environment
+            .borrow_mut()
+            .assign(left_identifier.name.to_owned(), right_eval);
+        Ok(Primitive::Null)
     }
 }
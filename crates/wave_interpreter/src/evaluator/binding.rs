@@ -0,0 +1,204 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::environment::Environment;
+use crate::evaluator::Primitive;
+use crate::{diagnostics, Runtime};
+use rustc_hash::FxHashMap;
+use wave_ast::ast::{
+    ArrayPattern, BindingPattern, BindingPatternKind, Expression, ObjectPattern, PropertyKey,
+};
+use wave_diagnostics::Result;
+use wave_span::{Atom, GetSpan};
+
+impl<'a> Runtime<'a> {
+    /// Binds every identifier of `pattern` in `environment`, destructuring `value` as needed.
+    pub fn bind_pattern(
+        &self,
+        pattern: &BindingPattern<'a>,
+        value: Primitive<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<()> {
+        match &pattern.kind {
+            BindingPatternKind::BindingIdentifier(identifier) => {
+                environment
+                    .borrow_mut()
+                    .define(identifier.name.to_owned(), value);
+                Ok(())
+            }
+            BindingPatternKind::AssignmentPattern(pattern) => {
+                let value = match value {
+                    Primitive::Null => {
+                        self.eval_expression(&pattern.right, Rc::clone(&environment))?
+                    }
+                    value => value,
+                };
+                self.bind_pattern(&pattern.left, value, environment)
+            }
+            BindingPatternKind::ArrayPattern(pattern) => {
+                self.bind_array_pattern(pattern, value, environment)
+            }
+            BindingPatternKind::ObjectPattern(pattern) => {
+                self.bind_object_pattern(pattern, value, environment)
+            }
+        }
+    }
+
+    /// Missing values are only allowed for bindings with a default.
+    fn bind_missing(
+        &self,
+        pattern: &BindingPattern<'a>,
+        value: Option<Primitive<'a>>,
+        missing: impl FnOnce() -> wave_diagnostics::Error,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<()> {
+        let value = match (value, &pattern.kind) {
+            (Some(value), _) => value,
+            (None, BindingPatternKind::AssignmentPattern(_)) => Primitive::Null,
+            (None, _) => return Err(missing()),
+        };
+        self.bind_pattern(pattern, value, environment)
+    }
+
+    fn bind_array_pattern(
+        &self,
+        pattern: &ArrayPattern<'a>,
+        value: Primitive<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<()> {
+        let mut array = match value {
+            Primitive::Array(array) => array,
+            value => {
+                let Some(mut iterator) = self.get_iterator(value, pattern.span)? else {
+                    return Err(diagnostics::InvalidArrayDestructuring(pattern.span).into());
+                };
+                // Without a rest element only the destructured values are taken
+                let mut values = vec![];
+                while pattern.rest.is_some() || values.len() < pattern.elements.len() {
+                    match self.iterator_next(&mut iterator, pattern.span)? {
+                        Some(value) => values.push(value),
+                        None => break,
+                    }
+                }
+                values
+            }
+        };
+
+        for (index, element) in pattern.elements.iter().enumerate() {
+            let Some(element) = element else {
+                continue;
+            };
+            self.bind_missing(
+                element,
+                array.get(index).cloned(),
+                || diagnostics::MissingDestructuredElement(index, element.span()).into(),
+                Rc::clone(&environment),
+            )?;
+        }
+
+        if let Some(rest) = &pattern.rest {
+            let rest_values = array.split_off(pattern.elements.len().min(array.len()));
+            self.bind_pattern(&rest.argument, Primitive::Array(rest_values), environment)?;
+        }
+        Ok(())
+    }
+
+    fn bind_object_pattern(
+        &self,
+        pattern: &ObjectPattern<'a>,
+        value: Primitive<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<()> {
+        let (Primitive::Instance(instance) | Primitive::This(instance)) = value else {
+            return Err(diagnostics::InvalidObjectDestructuring(pattern.span).into());
+        };
+
+        let mut bound = vec![];
+        for property in &pattern.properties {
+            let name: Atom = match &property.key {
+                PropertyKey::Identifier(identifier) => identifier.name.to_owned(),
+                PropertyKey::Expression(Expression::StringLiteral(literal)) => {
+                    literal.value.to_owned()
+                }
+                PropertyKey::PrivateIdentifier(identifier) => {
+                    return Err(diagnostics::InvalidObjectDestructuring(identifier.span).into())
+                }
+                PropertyKey::Expression(expression) => {
+                    return Err(diagnostics::InvalidObjectDestructuring(expression.span()).into())
+                }
+            };
+            // only a missing property falls back to the default, a failing getter is an error
+            let value = if self.has_property(&instance, &name) {
+                Some(self.get_property(Rc::clone(&instance), name.clone(), property.span)?)
+            } else {
+                None
+            };
+            self.bind_missing(
+                &property.value,
+                value,
+                || diagnostics::MissingDestructuredProperty(name.to_string(), property.span).into(),
+                Rc::clone(&environment),
+            )?;
+            bound.push(self.bind_this(name));
+        }
+
+        // the rest instance only carries the remaining own fields
+        if let Some(rest) = &pattern.rest {
+            let values = instance
+                .borrow()
+                .values
+                .iter()
+                .filter(|(name, _)| {
+                    name.starts_with("this_")
+                        && !name.starts_with("this_#")
+                        && !bound.contains(name)
+                })
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect::<FxHashMap<_, _>>();
+            let rest_instance = Environment {
+                values,
+                ..Environment::default()
+            };
+            let rest_value = Primitive::Instance(self.alloc_environment(rest_instance));
+            self.bind_pattern(&rest.argument, rest_value, environment)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::eval;
+
+    #[test]
+    fn array_patterns() {
+        assert_eq!(eval("let [a, , b] = [1, 2, 3]; [a, b];").unwrap(), "[1, 3]");
+        assert_eq!(eval("let [a, ...b] = [1, 2, 3]; b;").unwrap(), "[2, 3]");
+        assert_eq!(eval("let [a, b = a + 1] = [1]; b;").unwrap(), "2");
+        assert!(eval("let [a, b] = [1];").is_err());
+        assert!(eval("let [a] = 1;").is_err());
+    }
+
+    #[test]
+    fn object_patterns() {
+        let class = "class P { constructor() { this.x = 1; this.y = 2; } }";
+        let eval_with_class = |source: &str| eval(&format!("{class} {source}"));
+        assert_eq!(
+            eval_with_class("let { x, z: [a] = [3] } = new P(); [x, a];").unwrap(),
+            "[1, 3]"
+        );
+        assert_eq!(
+            eval_with_class("let { x, ...rest } = new P(); rest.y;").unwrap(),
+            "2"
+        );
+        assert_eq!(
+            eval_with_class("function f({ y }) { return y; } f(new P());").unwrap(),
+            "2"
+        );
+        assert!(eval_with_class("let { z } = new P();").is_err());
+
+        let class = "class Q { get broken() { return missing; } }";
+        let error = eval(&format!("{class} let {{ broken = 1 }} = new Q();")).unwrap_err();
+        assert_eq!(error.to_string(), "Variable not found.");
+    }
+}
@@ -1,19 +1,24 @@
 use std::cell::RefCell;
-use std::ptr;
 use std::rc::Rc;
 
 use crate::evaluator::Primitive;
 use crate::Runtime;
 use crate::{diagnostics, environment::Environment};
-use std::vec::Vec as StdVec;
 use wave_allocator::Box;
 use wave_ast::ast::{
-    Argument, Class, ClassElement, Expression, NewExpression, PropertyKey, Super, ThisExpression,
+    Class, ClassElement, Expression, MethodDefinitionKind, NewExpression, PropertyDefinition,
+    PropertyKey, Super, ThisExpression,
 };
 use wave_diagnostics::Result;
-use wave_span::{Atom, Span};
+use wave_span::{Atom, GetSpan, Span};
 
 const CONSTRUCTOR: &str = "this_constructor";
+/// Key of the environment holding the static members of a class
+const STATIC: &str = "#static";
+/// Key of the name of the parent class in the environment of a class
+const SUPER: &str = "super";
+/// Key of the class a bound method was found in, which `super` is resolved from
+const HOME: &str = "#home";
 
 impl<'a> Runtime<'a> {
     pub fn eval_class_declaration(
@@ -21,53 +26,127 @@ impl<'a> Runtime<'a> {
         declaration: &Box<'_, Class<'a>>,
         environment: Rc<RefCell<Environment<'a>>>,
     ) -> Result<Primitive<'a>> {
-        unsafe {
-            let class = ptr::read(declaration).unbox();
-            let class_elements = class.body.unbox().body;
-
-            let env = Rc::new(RefCell::new(Environment::extend(Rc::clone(&environment))));
-
-            if let Some(identifier) = &class.id {
-                for element in class_elements {
-                    match element {
-                        ClassElement::PropertyDefinition(definition) => {
-                            let property_name = match &definition.key {
-                                PropertyKey::Identifier(identifier) => identifier.name.to_owned(),
-                                _ => unreachable!(),
-                            };
-                            let property_name = self.bind_this(property_name);
-                            let expr_value = if let Some(expr) = &definition.value {
-                                self.eval_expression(expr, Rc::clone(&env))?
-                            } else {
-                                Primitive::Null
-                            };
-                            env.borrow_mut().define(property_name, expr_value);
-                        }
-                        ClassElement::MethodDefinition(definition) => {
-                            let method_name = match &definition.key {
-                                PropertyKey::Identifier(identifier) => identifier.name.to_owned(),
-                                _ => unreachable!(),
-                            };
-                            let method_name = self.bind_this(method_name);
-                            let function =
-                                self.eval_function(&definition.value, Rc::clone(&env))?;
-                            env.borrow_mut().define(method_name, function);
+        let Some(identifier) = &declaration.id else {
+            return Ok(Primitive::Null);
+        };
+
+        let env = self.alloc_environment(Environment::extend(Rc::clone(&environment)));
+        // Static members live apart from the instance members, so instances can't see them
+        let static_env = self.alloc_environment(Environment::extend(Rc::clone(&environment)));
+        env.borrow_mut()
+            .define(STATIC.into(), Primitive::Instance(Rc::clone(&static_env)));
+        // Static initializers and methods resolve `super` and private names from the class
+        static_env
+            .borrow_mut()
+            .define(HOME.into(), Primitive::Class(Rc::clone(&env)));
+
+        if let Some(Expression::Identifier(ident)) = &declaration.super_class {
+            env.borrow_mut()
+                .define(SUPER.into(), Primitive::String(ident.name.to_string()));
+        };
+
+        // Defined before the members so static initializers can refer to the class
+        environment.borrow_mut().define(
+            identifier.name.to_owned(),
+            Primitive::Class(Rc::clone(&env)),
+        );
+
+        for element in &declaration.body.body {
+            match element {
+                ClassElement::PropertyDefinition(definition) if definition.r#static => {
+                    let property_name =
+                        self.bind_this(self.get_property_key(&definition.key, &env)?);
+                    let value = if let Some(expr) = &definition.value {
+                        self.eval_expression(expr, Rc::clone(&static_env))?
+                    } else {
+                        Primitive::Null
+                    };
+                    static_env.borrow_mut().define(property_name, value);
+                }
+                ClassElement::PropertyDefinition(definition) => {
+                    // SAFETY: the definition is allocated in the arena of the program, which
+                    // outlives every environment created while evaluating it.
+                    let definition: &'a PropertyDefinition<'a> =
+                        unsafe { &*(&**definition as *const PropertyDefinition<'a>) };
+                    env.borrow_mut().fields.push(definition);
+                }
+                ClassElement::MethodDefinition(definition) => {
+                    let name = self.get_property_key(&definition.key, &env)?;
+                    let method_name = match definition.kind {
+                        MethodDefinitionKind::Get => self.bind_getter(name),
+                        MethodDefinitionKind::Set => self.bind_setter(name),
+                        MethodDefinitionKind::Constructor | MethodDefinitionKind::Method => {
+                            self.bind_this(name)
                         }
-                    }
+                    };
+                    let target = if definition.r#static {
+                        &static_env
+                    } else {
+                        &env
+                    };
+                    let function = self.eval_function(&definition.value, Rc::clone(target))?;
+                    target.borrow_mut().define(method_name, function);
                 }
+            }
+        }
 
-                if let Some(Expression::Identifier(ident)) = &declaration.super_class {
-                    env.borrow_mut()
-                        .define("super".into(), Primitive::String(ident.name.to_string()));
-                };
+        Ok(Primitive::Null)
+    }
 
-                environment
-                    .borrow_mut()
-                    .define(identifier.name.to_owned(), Primitive::Class(env));
+    /// The name a member of the class `class_env` is stored under, string and number literal
+    /// keys name the member like an identifier with their value.
+    fn get_property_key(
+        &self,
+        key: &PropertyKey<'a>,
+        class_env: &Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Atom> {
+        match key {
+            PropertyKey::Identifier(identifier) => Ok(identifier.name.to_owned()),
+            PropertyKey::PrivateIdentifier(identifier) => {
+                Ok(self.bind_private(class_env, identifier.name.clone()))
+            }
+            PropertyKey::Expression(Expression::StringLiteral(literal)) => {
+                Ok(literal.value.to_owned())
             }
+            PropertyKey::Expression(Expression::NumberLiteral(literal)) => {
+                Ok(format!("{:?}", Primitive::Number(literal.value)).into())
+            }
+            PropertyKey::Expression(Expression::BigIntLiteral(literal)) => {
+                Ok(literal.value.to_string().into())
+            }
+            PropertyKey::Expression(expression) => {
+                Err(diagnostics::UnsupportedPropertyKey(expression.span()).into())
+            }
+        }
+    }
+
+    /// Evaluates the field initializers of `class_env` and its parent classes for a new instance.
+    fn init_fields(
+        &self,
+        class_env: &Rc<RefCell<Environment<'a>>>,
+        instance_env: &Rc<RefCell<Environment<'a>>>,
+    ) -> Result<()> {
+        if let Some(parent_env) = self.get_superclass(class_env) {
+            self.init_fields(&parent_env, instance_env)?;
+        }
 
-            Ok(Primitive::Null)
+        let fields = class_env.borrow().fields.clone();
+        if fields.is_empty() {
+            return Ok(());
+        }
+        // Initializers run in a scope of the instance that knows the class declaring them
+        let field_env = self.alloc_environment(Environment::extend(Rc::clone(instance_env)));
+        self.bind_home(&field_env, class_env);
+        for definition in fields {
+            let property_name = self.bind_this(self.get_property_key(&definition.key, class_env)?);
+            let value = if let Some(expr) = &definition.value {
+                self.eval_expression(expr, Rc::clone(&field_env))?
+            } else {
+                Primitive::Null
+            };
+            instance_env.borrow_mut().define(property_name, value);
         }
+        Ok(())
     }
 
     pub fn eval_new_expression(
@@ -82,37 +161,34 @@ impl<'a> Runtime<'a> {
 
                 match class {
                     Primitive::Class(class_env) => {
-                        let class_env = Rc::new(RefCell::new(Environment::extend(class_env)));
-
-                        let constuctor = class_env
-                            .borrow()
-                            .get(CONSTRUCTOR.into(), declaration.span)?;
-
-                        let mut arguments = vec![];
-                        for arg in &declaration.arguments {
-                            match arg {
-                                Argument::Expression(expression) => {
-                                    arguments.push(
-                                        self.eval_expression(expression, Rc::clone(&class_env))?,
-                                    );
-                                }
-                            }
-                        }
+                        let instance_env =
+                            self.alloc_environment(Environment::extend(Rc::clone(&class_env)));
+                        self.init_fields(&class_env, &instance_env)?;
 
-                        let Primitive::Class(instance_env) = self.apply_constructor(
-                            constuctor,
-                            arguments,
-                            declaration.span,
-                            class_env,
-                        )?
-                        else {
-                            return Err(
-                                diagnostics::CannotInstantiateNonClass(identifier.span).into()
-                            );
-                        };
+                        let arguments =
+                            self.eval_arguments(&declaration.arguments, Rc::clone(&environment))?;
+
+                        // A class without a constructor in its chain only initializes its fields
+                        if let Some((constructor, home)) =
+                            self.find_member(&class_env, &CONSTRUCTOR.into())
+                        {
+                            let constructor = self.bind_method(
+                                constructor,
+                                &instance_env,
+                                &home,
+                                declaration.span,
+                            )?;
+                            self.apply_function(constructor, arguments, declaration.span)?;
+                        }
 
                         Ok(Primitive::Instance(instance_env))
                     }
+                    // built-in constructors like `Promise`
+                    Primitive::NativeFunction(function) => {
+                        let arguments =
+                            self.eval_arguments(&declaration.arguments, Rc::clone(&environment))?;
+                        (function.function)(self, &function.receiver, arguments, declaration.span)
+                    }
                     _ => Err(diagnostics::CannotInstantiateNonClass(identifier.span).into()),
                 }
             }
@@ -120,76 +196,99 @@ impl<'a> Runtime<'a> {
         }
     }
 
-    pub fn apply_constructor(
+    /// The environment of the class `class_env` extends, if any.
+    pub fn get_superclass(
         &self,
-        function: Primitive<'a>,
-        arguments: StdVec<Primitive<'a>>,
-        callee_span: Span,
-        env: Rc<RefCell<Environment<'a>>>,
-    ) -> Result<Primitive<'a>> {
-        match function {
-            Primitive::Function(params, body, _) => {
-                match params {
-                    Some(params) => {
-                        if params.len() != arguments.len() {
-                            return Err(diagnostics::InvalidNumberOfArguments(callee_span).into());
-                        }
-
-                        for (param, arg) in params.iter().zip(arguments) {
-                            let param_name = self.get_atom_formal_parameters(param);
-                            env.borrow_mut().define(param_name, arg);
-                        }
-                    }
-                    None => {
-                        if !arguments.is_empty() {
-                            return Err(diagnostics::InvalidNumberOfArguments(callee_span).into());
-                        }
-                    }
-                }
-
-                match body {
-                    Some(body) => {
-                        let eval = self.eval_block(&body, Rc::clone(&env))?;
-                        self.unwrap_return_value(eval)?;
-                        Ok(Primitive::Class(env))
-                    }
-                    None => Ok(Primitive::Null),
-                }
-            }
-            _ => Err(diagnostics::CannotCallNonFunction(callee_span).into()),
+        class_env: &Rc<RefCell<Environment<'a>>>,
+    ) -> Option<Rc<RefCell<Environment<'a>>>> {
+        let parent = class_env.borrow().values.get(&Atom::from(SUPER)).cloned();
+        let Some(Primitive::String(parent)) = parent else {
+            return None;
+        };
+        match class_env.borrow().get(parent.into(), Span::default()) {
+            Ok(Primitive::Class(parent_env)) => Some(parent_env),
+            _ => None,
         }
     }
 
+    /// The class `super` refers to from `environment`: the parent of the class the running
+    /// method was found in, or of the class whose initializers are being evaluated.
     pub fn get_parent_class(
         &self,
         span: Span,
         environment: Rc<RefCell<Environment<'a>>>,
-    ) -> Result<Primitive<'a>> {
-        let parent_class = environment.borrow().get("super".into(), span)?;
+    ) -> Result<Rc<RefCell<Environment<'a>>>> {
+        self.get_enclosing_class(&environment)
+            .and_then(|class_env| self.get_superclass(&class_env))
+            .ok_or_else(|| diagnostics::SuperWithoutParentClass(span).into())
+    }
 
-        let Primitive::String(parent_class_name) = parent_class else {
-            unreachable!()
-        };
+    /// The class whose body the code running in `environment` was declared in.
+    pub fn get_enclosing_class(
+        &self,
+        environment: &Rc<RefCell<Environment<'a>>>,
+    ) -> Option<Rc<RefCell<Environment<'a>>>> {
+        let mut current = Some(Rc::clone(environment));
+        while let Some(env) = current {
+            if let Some(Primitive::Class(home)) = env.borrow().values.get(&Atom::from(HOME)) {
+                return Some(Rc::clone(home));
+            }
+            if self.is_class_env(&env) {
+                return Some(env);
+            }
+            current = env.borrow().outer.clone();
+        }
+        None
+    }
 
-        environment.borrow().get(parent_class_name.into(), span)
+    /// The key of the private `name` of `object`, as declared by the class enclosing
+    /// `environment`. Only instances of that class, or of classes extending it, have it.
+    pub fn get_private_key(
+        &self,
+        object: &Primitive<'a>,
+        name: &Atom,
+        environment: &Rc<RefCell<Environment<'a>>>,
+        span: Span,
+    ) -> Result<Atom> {
+        let error = || diagnostics::InvalidPrivateAccess(name.to_string(), span).into();
+        let class_env = self.get_enclosing_class(environment).ok_or_else(error)?;
+        let key = self.bind_private(&class_env, name.clone());
+        let target = match object {
+            Primitive::Instance(env) | Primitive::This(env) => Some(Rc::clone(env)),
+            Primitive::Class(env) => self.get_static_env(env),
+            _ => None,
+        };
+        let has_member = target.is_some_and(|target| {
+            [
+                self.bind_this(key.clone()),
+                self.bind_getter(key.clone()),
+                self.bind_setter(key.clone()),
+            ]
+            .iter()
+            .any(|member| self.find_member(&target, member).is_some())
+        });
+        if has_member {
+            Ok(key)
+        } else {
+            Err(error())
+        }
     }
 
+    /// The constructor `super(...)` calls, bound to the instance being constructed.
+    /// `null` when no parent class in the chain declares a constructor.
     pub fn eval_super_expression(
         &self,
         super_call: &Box<'_, Super>,
         environment: Rc<RefCell<Environment<'a>>>,
     ) -> Result<Primitive<'a>> {
-        let Primitive::Class(parent_class) =
-            self.get_parent_class(super_call.span, Rc::clone(&environment))?
-        else {
-            unreachable!()
-        };
+        let parent_class = self.get_parent_class(super_call.span, Rc::clone(&environment))?;
 
-        let super_call = parent_class
-            .borrow()
-            .get(CONSTRUCTOR.into(), super_call.span)?;
-
-        Ok(super_call)
+        match self.find_member(&parent_class, &CONSTRUCTOR.into()) {
+            Some((constructor, home)) => {
+                self.bind_method(constructor, &environment, &home, super_call.span)
+            }
+            None => Ok(Primitive::Null),
+        }
     }
 
     pub fn eval_this_expression(
@@ -200,7 +299,273 @@ impl<'a> Runtime<'a> {
         Ok(Primitive::This(environment))
     }
 
+    /// Whether `instance` was created from `class` or from a class extending it.
+    pub fn is_instance_of(
+        &self,
+        instance: &Rc<RefCell<Environment<'a>>>,
+        class: &Rc<RefCell<Environment<'a>>>,
+    ) -> bool {
+        let mut current = self.get_receiver(instance).borrow().outer.clone();
+        while let Some(class_env) = current {
+            if Rc::ptr_eq(&class_env, class) {
+                return true;
+            }
+            current = self.get_superclass(&class_env);
+        }
+        false
+    }
+
+    /// Whether `env` holds the members of a class.
+    pub fn is_class_env(&self, env: &Rc<RefCell<Environment<'a>>>) -> bool {
+        env.borrow().values.contains_key(&Atom::from(STATIC))
+    }
+
+    /// A bound method remembers the class it was found in, so `super` inside it
+    /// resolves from that class rather than from the class of `this`.
+    pub fn bind_home(
+        &self,
+        method_env: &Rc<RefCell<Environment<'a>>>,
+        home: &Rc<RefCell<Environment<'a>>>,
+    ) {
+        if self.is_class_env(home) {
+            method_env
+                .borrow_mut()
+                .define(HOME.into(), Primitive::Class(Rc::clone(home)));
+        }
+    }
+
+    /// Whether two function environments are the same, or bind a method of the same class to
+    /// the same instance, since every read of a method binds it anew.
+    pub fn is_same_binding(
+        &self,
+        a: &Rc<RefCell<Environment<'a>>>,
+        b: &Rc<RefCell<Environment<'a>>>,
+    ) -> bool {
+        if Rc::ptr_eq(a, b) {
+            return true;
+        }
+        let (a, b) = (a.borrow(), b.borrow());
+        let home = Atom::from(HOME);
+        match (a.values.get(&home), b.values.get(&home), &a.outer, &b.outer) {
+            (
+                Some(Primitive::Class(a_home)),
+                Some(Primitive::Class(b_home)),
+                Some(a_outer),
+                Some(b_outer),
+            ) => {
+                Rc::ptr_eq(a_home, b_home)
+                    && Rc::ptr_eq(&self.get_receiver(a_outer), &self.get_receiver(b_outer))
+            }
+            _ => false,
+        }
+    }
+
     pub fn bind_this(&self, atom: Atom) -> Atom {
         ("this_".to_string() + atom.as_ref()).into()
     }
+
+    /// Private names keep their `#`, which no public property name can contain, and are
+    /// followed by the class declaring them after `@`, so a subclass declaring the same name
+    /// gets a slot of its own.
+    pub fn bind_private(&self, class_env: &Rc<RefCell<Environment<'a>>>, atom: Atom) -> Atom {
+        format!("#{}@{:p}", atom, Rc::as_ptr(class_env)).into()
+    }
+
+    /// The constructor is stored like a method, but is not a property of instances.
+    pub fn is_internal_property(&self, name: &Atom) -> bool {
+        name.as_str() == "constructor"
+    }
+
+    pub fn bind_getter(&self, atom: Atom) -> Atom {
+        ("get_this_".to_string() + atom.as_ref()).into()
+    }
+
+    pub fn bind_setter(&self, atom: Atom) -> Atom {
+        ("set_this_".to_string() + atom.as_ref()).into()
+    }
+
+    /// The environment holding the static members of a class.
+    pub fn get_static_env(
+        &self,
+        class_env: &Rc<RefCell<Environment<'a>>>,
+    ) -> Option<Rc<RefCell<Environment<'a>>>> {
+        match class_env.borrow().values.get(&Atom::from(STATIC)) {
+            Some(Primitive::Instance(static_env)) => Some(Rc::clone(static_env)),
+            _ => None,
+        }
+    }
+
+    /// The instance environment that `this` refers to from `env`, for example from the
+    /// scope of a method call.
+    pub fn get_receiver(&self, env: &Rc<RefCell<Environment<'a>>>) -> Rc<RefCell<Environment<'a>>> {
+        let mut current = Rc::clone(env);
+        loop {
+            let outer = current.borrow().outer.clone();
+            match outer {
+                Some(outer) if self.is_class_env(&outer) => return current,
+                Some(outer) => current = outer,
+                None => return Rc::clone(env),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::eval;
+
+    #[test]
+    fn accessors_and_static_members() {
+        let class = "class Temperature {
+                celsius = 0;
+                static created = 0;
+                static of(celsius) {
+                    Temperature.created = Temperature.created + 1;
+                    let t = new Temperature();
+                    t.celsius = celsius;
+                    return t;
+                }
+                get fahrenheit() { return this.celsius * 9 / 5 + 32; }
+                set fahrenheit(value) { this.celsius = (value - 32) * 5 / 9; }
+            }";
+        let run = |code: &str| eval(&format!("{class} {code}")).unwrap();
+        assert_eq!(run("Temperature.of(100).fahrenheit;"), "212");
+        assert_eq!(
+            run("let t = new Temperature(); t.fahrenheit = 50; t.celsius;"),
+            "10"
+        );
+        assert_eq!(
+            run("Temperature.of(1); Temperature.of(2); Temperature.created;"),
+            "2"
+        );
+    }
+
+    #[test]
+    fn getter_only_accessors_are_read_only() {
+        let class =
+            "class Circle { radius = 1; get area() { return 3 * this.radius * this.radius; } }";
+        let error = eval(&format!("{class} let c = new Circle(); c.area = 5;")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Cannot assign to a property with only a getter."
+        );
+        assert_eq!(
+            eval(&format!(
+                "{class} let c = new Circle(); c.radius = 2; c.area;"
+            ))
+            .unwrap(),
+            "12"
+        );
+    }
+
+    #[test]
+    fn fields_are_initialized_per_instance() {
+        let class = "class Counter { count = 0; inc() { this.count = this.count + 1; } }";
+        assert_eq!(
+            eval(&format!(
+                "{class} let a = new Counter(); let b = new Counter(); a.inc(); a.inc(); b.inc(); [a.count, b.count];"
+            ))
+            .unwrap(),
+            "[2, 1]"
+        );
+    }
+
+    #[test]
+    fn private_members() {
+        let class = "class Account {
+                #balance = 0;
+                constructor(balance) { this.#balance = balance; }
+                #fee() { return 1; }
+                withdraw(amount) { this.#balance = this.#balance - amount - this.#fee(); }
+                get balance() { return this.#balance; }
+            }";
+        let run = |code: &str| eval(&format!("{class} {code}"));
+        assert_eq!(
+            run("let a = new Account(10); a.withdraw(4); a.balance;").unwrap(),
+            "5"
+        );
+
+        let error = run("new Account(1).constructor;").unwrap_err().to_string();
+        assert_eq!(error, "Cannot access property.");
+        let rest = run("let { ...rest } = new Account(1); rest;").unwrap();
+        assert_eq!(rest, "{}");
+    }
+
+    #[test]
+    fn private_names_belong_to_their_class() {
+        let classes = "class A {
+                #x = 1;
+                static #count = 0;
+                getA() { return this.#x; }
+                static count() { A.#count = A.#count + 1; return A.#count; }
+                read(other) { return other.#x; }
+            }
+            class B extends A {
+                #x = 2;
+                getB() { return this.#x; }
+            }
+            class C { #x = 3; }";
+        let run = |code: &str| eval(&format!("{classes} {code}"));
+        assert_eq!(
+            run("let b = new B(); [b.getA(), b.getB(), A.count(), A.count()];").unwrap(),
+            "[1, 2, 1, 2]"
+        );
+        assert_eq!(run("new A().read(new B());").unwrap(), "1");
+        let error = run("new A().read(new C());").unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "Cannot access private member '#x' of an object whose class did not declare it."
+        );
+    }
+
+    #[test]
+    fn literal_member_names() {
+        let class = "class A {
+                \"x\"() { return 1; }
+                static \"y\" = 2;
+                get \"z\"() { return 3; }
+                1() { return 4; }
+            }";
+        assert_eq!(
+            eval(&format!("{class} let a = new A(); [a.x(), A.y, a.z];")).unwrap(),
+            "[1, 2, 3]"
+        );
+    }
+
+    #[test]
+    fn multi_level_inheritance() {
+        let classes = "class Shape {
+                constructor(size) { this.size = size; }
+                area() { return this.size; }
+                sides() { return 0; }
+            }
+            class Rect extends Shape {
+                constructor(size) { super(size * 2); this.kind = 4; }
+                area() { return super.area() + 1; }
+                sides() { return this.kind; }
+            }
+            class Square extends Rect {
+                area() { return super.area() * 10; }
+            }";
+        let run = |code: &str| eval(&format!("{classes} {code}"));
+        // Square has no constructor, so Rect's runs and its `super` still reaches Shape
+        assert_eq!(
+            run("let s = new Square(3); [s.size, s.kind, s.area(), s.sides()];").unwrap(),
+            "[6, 4, 70, 4]"
+        );
+        assert_eq!(
+            run("let s = new Square(1); [s instanceof Shape, s instanceof Rect, s instanceof Square];")
+                .unwrap(),
+            "[true, true, true]"
+        );
+        assert_eq!(
+            run("[new Rect(1) instanceof Square, 1 instanceof Shape];").unwrap(),
+            "[false, false]"
+        );
+
+        let error = run("class Lone { m() { return super.m(); } } new Lone().m();")
+            .unwrap_err()
+            .to_string();
+        assert_eq!(error, "'super' used in a class without a parent class.");
+    }
 }
@@ -0,0 +1,323 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::vec::Vec as StdVec;
+
+use crate::evaluator::function::NativeFunction;
+use crate::evaluator::Primitive;
+use crate::{diagnostics, Runtime};
+use num_bigint::BigInt;
+use rustc_hash::FxHashMap;
+use wave_diagnostics::Result;
+use wave_span::{Atom, Span};
+
+/// The hashable identity of a key, numbers and strings compare by value, instances and other
+/// reference values by identity.
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum CollectionKey {
+    Null,
+    Boolean(bool),
+    /// The bits of the number, every `NaN` is the same key
+    Number(u64),
+    BigInt(BigInt),
+    String(String),
+    Reference(usize),
+}
+
+/// The entries of a `Map` or a `Set` in insertion order, a `Set` stores `null` values.
+#[derive(Default)]
+pub struct Collection<'a> {
+    /// Deleted entries leave a hole until the next compaction, so the index stays valid
+    entries: StdVec<Option<(Primitive<'a>, Primitive<'a>)>>,
+    index: FxHashMap<CollectionKey, usize>,
+}
+
+impl<'a> Collection<'a> {
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &(Primitive<'a>, Primitive<'a>)> {
+        self.entries.iter().flatten()
+    }
+
+    fn get(&self, key: &CollectionKey) -> Option<&Primitive<'a>> {
+        let index = self.index.get(key)?;
+        self.entries[*index].as_ref().map(|(_, value)| value)
+    }
+
+    /// Replacing the value of a key keeps its position.
+    fn insert(&mut self, key: CollectionKey, key_value: Primitive<'a>, value: Primitive<'a>) {
+        match self.index.get(&key) {
+            Some(index) => self.entries[*index] = Some((key_value, value)),
+            None => {
+                self.index.insert(key, self.entries.len());
+                self.entries.push(Some((key_value, value)));
+            }
+        }
+    }
+
+    fn remove(&mut self, key: &CollectionKey) -> bool {
+        let Some(index) = self.index.remove(key) else {
+            return false;
+        };
+        self.entries[index] = None;
+        if self.entries.len() > 2 * self.index.len() + 8 {
+            self.compact();
+        }
+        true
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.index.clear();
+    }
+
+    fn compact(&mut self) {
+        self.entries.retain(Option::is_some);
+        for (position, entry) in self.entries.iter().enumerate() {
+            if let Some((key, _)) = entry {
+                let key = collection_key(key).expect("stored keys are hashable");
+                self.index.insert(key, position);
+            }
+        }
+    }
+}
+
+/// A method of a `Map` or a `Set`, called with the collection it was read from.
+type CollectionMethod<'a> = fn(
+    &Runtime<'a>,
+    &Rc<RefCell<Collection<'a>>>,
+    StdVec<Primitive<'a>>,
+    Span,
+) -> Result<Primitive<'a>>;
+
+/// The key of `value`, `None` when it cannot be hashed, like arrays and functions.
+fn collection_key(value: &Primitive<'_>) -> Option<CollectionKey> {
+    Some(match value {
+        Primitive::Null => CollectionKey::Null,
+        Primitive::Boolean(value) => CollectionKey::Boolean(*value),
+        Primitive::Number(value) if value.is_nan() => CollectionKey::Number(f64::NAN.to_bits()),
+        Primitive::Number(value) => CollectionKey::Number(value.to_bits()),
+        Primitive::BigInt(value) => CollectionKey::BigInt(value.clone()),
+        Primitive::String(value) => CollectionKey::String(value.clone()),
+        Primitive::Instance(env) | Primitive::Class(env) => {
+            CollectionKey::Reference(Rc::as_ptr(env) as usize)
+        }
+        Primitive::Generator(generator) => CollectionKey::Reference(Rc::as_ptr(generator) as usize),
+        Primitive::Promise(promise) => CollectionKey::Reference(Rc::as_ptr(promise) as usize),
+        Primitive::Map(collection) | Primitive::Set(collection) => {
+            CollectionKey::Reference(Rc::as_ptr(collection) as usize)
+        }
+        _ => return None,
+    })
+}
+
+impl<'a> Runtime<'a> {
+    /// `new Map(entries)` and `new Set(values)`, both optionally filled from an iterable.
+    pub fn construct_collection(
+        &self,
+        set: bool,
+        arguments: StdVec<Primitive<'a>>,
+        span: Span,
+    ) -> Result<Primitive<'a>> {
+        let collection = Rc::new(RefCell::new(Collection::default()));
+        let iterable = arguments.into_iter().next().unwrap_or(Primitive::Null);
+        let values = match iterable {
+            Primitive::Null => vec![],
+            iterable => self
+                .collect_iterable(iterable, span)?
+                .ok_or(diagnostics::NotIterable(span))?,
+        };
+        for value in values {
+            let (key, value) = if set {
+                (value, Primitive::Null)
+            } else {
+                match value {
+                    Primitive::Array(entry) if entry.len() == 2 => {
+                        let mut entry = entry.into_iter();
+                        (entry.next().unwrap(), entry.next().unwrap())
+                    }
+                    _ => return Err(diagnostics::InvalidMapEntry(span).into()),
+                }
+            };
+            self.insert_entry(&collection, key, value, span)?;
+        }
+
+        if set {
+            Ok(Primitive::Set(collection))
+        } else {
+            Ok(Primitive::Map(collection))
+        }
+    }
+
+    fn insert_entry(
+        &self,
+        collection: &Rc<RefCell<Collection<'a>>>,
+        key: Primitive<'a>,
+        value: Primitive<'a>,
+        span: Span,
+    ) -> Result<()> {
+        let key = self.normalize_key(key);
+        let hashed = collection_key(&key).ok_or(diagnostics::InvalidCollectionKey(span))?;
+        collection.borrow_mut().insert(hashed, key, value);
+        Ok(())
+    }
+
+    /// `this` is stored as the instance it refers to and `-0` as `0`.
+    fn normalize_key(&self, key: Primitive<'a>) -> Primitive<'a> {
+        match key {
+            Primitive::This(env) => Primitive::Instance(self.get_receiver(&env)),
+            // the pattern matches `-0` too
+            Primitive::Number(0.0) => Primitive::Number(0.0),
+            key => key,
+        }
+    }
+
+    fn argument_key(&self, arguments: StdVec<Primitive<'a>>, span: Span) -> Result<CollectionKey> {
+        let key = self.normalize_key(arguments.into_iter().next().unwrap_or(Primitive::Null));
+        collection_key(&key).ok_or_else(|| diagnostics::InvalidCollectionKey(span).into())
+    }
+
+    /// `size` and the methods of a `Map` or a `Set`.
+    pub fn get_collection_member(
+        &self,
+        object: Primitive<'a>,
+        name: Atom,
+        span: Span,
+    ) -> Result<Primitive<'a>> {
+        let (Primitive::Map(collection) | Primitive::Set(collection)) = &object else {
+            unreachable!()
+        };
+        if name == "size" {
+            return Ok(Primitive::Number(collection.borrow().len() as f64));
+        }
+        let set = matches!(object, Primitive::Set(_));
+        let method: CollectionMethod<'a> = match (name.as_str(), set) {
+            ("get", false) => |runtime, collection, arguments, span| {
+                let key = runtime.argument_key(arguments, span)?;
+                Ok(collection
+                    .borrow()
+                    .get(&key)
+                    .cloned()
+                    .unwrap_or(Primitive::Null))
+            },
+            ("set", false) => |runtime, collection, arguments, span| {
+                let mut arguments = arguments.into_iter();
+                let key = arguments.next().unwrap_or(Primitive::Null);
+                let value = arguments.next().unwrap_or(Primitive::Null);
+                runtime.insert_entry(collection, key, value, span)?;
+                Ok(Primitive::Map(Rc::clone(collection)))
+            },
+            ("add", true) => |runtime, collection, arguments, span| {
+                let value = arguments.into_iter().next().unwrap_or(Primitive::Null);
+                runtime.insert_entry(collection, value, Primitive::Null, span)?;
+                Ok(Primitive::Set(Rc::clone(collection)))
+            },
+            ("has", _) => |runtime, collection, arguments, span| {
+                let key = runtime.argument_key(arguments, span)?;
+                Ok(Primitive::Boolean(collection.borrow().get(&key).is_some()))
+            },
+            ("delete", _) => |runtime, collection, arguments, span| {
+                let key = runtime.argument_key(arguments, span)?;
+                Ok(Primitive::Boolean(collection.borrow_mut().remove(&key)))
+            },
+            ("clear", _) => |_, collection, _, _| {
+                collection.borrow_mut().clear();
+                Ok(Primitive::Null)
+            },
+            ("keys", _) => |_, collection, _, _| {
+                let keys = collection
+                    .borrow()
+                    .entries()
+                    .map(|(key, _)| key.clone())
+                    .collect();
+                Ok(Primitive::Array(keys))
+            },
+            ("values", false) => |_, collection, _, _| {
+                let values = collection
+                    .borrow()
+                    .entries()
+                    .map(|(_, value)| value.clone())
+                    .collect();
+                Ok(Primitive::Array(values))
+            },
+            ("values", true) => |_, collection, _, _| {
+                let values = collection
+                    .borrow()
+                    .entries()
+                    .map(|(value, _)| value.clone())
+                    .collect();
+                Ok(Primitive::Array(values))
+            },
+            ("entries", false) => {
+                |_, collection, _, _| Ok(Primitive::Array(map_entries(&collection.borrow())))
+            }
+            _ => return Err(diagnostics::CannotAccessProperty(span).into()),
+        };
+
+        let receiver = object.clone();
+        Ok(Primitive::NativeFunction(NativeFunction::method(
+            name,
+            receiver,
+            move |runtime, receiver, arguments, span| {
+                let (Primitive::Map(collection) | Primitive::Set(collection)) = receiver else {
+                    unreachable!()
+                };
+                method(runtime, collection, arguments, span)
+            },
+        )))
+    }
+}
+
+/// The entries of a `Map` as `[key, value]` arrays, the values `for-of` iterates.
+pub fn map_entries<'a>(collection: &Collection<'a>) -> StdVec<Primitive<'a>> {
+    collection
+        .entries()
+        .map(|(key, value)| Primitive::Array(vec![key.clone(), value.clone()]))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::eval;
+
+    #[test]
+    fn maps() {
+        let source = "class Key { constructor(id) { this.id = id; } }
+            let a = new Key(1);
+            let m = new Map([[1, \"one\"], [\"1\", \"string\"]]);
+            m.set(a, \"a\").set(new Key(1), \"other\").set(-0, \"zero\");
+            [m.get(1), m.get(\"1\"), m.get(a), m.get(0), m.get(2), m.size];";
+        assert_eq!(eval(source).unwrap(), "[one, string, a, zero, Null, 5]");
+
+        let order = "let m = new Map();
+            m.set(\"b\", 1).set(\"a\", 2).set(\"b\", 3);
+            m.delete(\"a\");
+            m.set(\"c\", 4);
+            let out = [];
+            for (const [key, value] of m) { out = [...out, key, value]; }
+            [out, m.has(\"a\"), m.delete(\"a\"), m.keys()];";
+        assert_eq!(eval(order).unwrap(), "[[b, 3, c, 4], false, false, [b, c]]");
+        assert_eq!(
+            eval("new Map().set([1], 1);").unwrap_err().to_string(),
+            "Value cannot be used as a key."
+        );
+    }
+
+    #[test]
+    fn sets() {
+        let source = "let s = new Set([3, 1, 3, 2, 1]);
+            s.add(1).add(4);
+            s.delete(3);
+            [s.size, s.has(2), s.has(3), [...s]];";
+        assert_eq!(eval(source).unwrap(), "[3, true, false, [1, 2, 4]]");
+        assert_eq!(
+            eval("let s = new Set(\"hello\"); s.values();").unwrap(),
+            "[h, e, l, o]"
+        );
+    }
+}
@@ -5,7 +5,7 @@ use crate::environment::Environment;
 use crate::evaluator::Primitive;
 use crate::Runtime;
 use wave_allocator::{Box, Vec};
-use wave_ast::ast::{BindingPatternKind, Declaration, VariableDeclaration, VariableDeclarator};
+use wave_ast::ast::{Declaration, VariableDeclaration, VariableDeclarator};
 use wave_diagnostics::Result;
 
 impl<'a> Runtime<'a> {
@@ -44,17 +44,9 @@ impl<'a> Runtime<'a> {
             if declarator.init.is_none() {
                 continue;
             }
-            match &declarator.id.kind {
-                BindingPatternKind::BindingIdentifier(identifier) => {
-                    let value = self.eval_expression(
-                        declarator.init.as_ref().unwrap(),
-                        Rc::clone(&environment),
-                    )?;
-                    environment
-                        .borrow_mut()
-                        .define(identifier.name.to_owned(), value);
-                }
-            }
+            let value =
+                self.eval_expression(declarator.init.as_ref().unwrap(), Rc::clone(&environment))?;
+            self.bind_pattern(&declarator.id, value, Rc::clone(&environment))?;
         }
         Ok(Primitive::Null)
     }
@@ -2,6 +2,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::environment::Environment;
+use crate::evaluator::generator::address;
 use crate::evaluator::Primitive;
 use crate::Runtime;
 use std::vec::Vec as StdVec;
@@ -17,10 +18,34 @@ impl<'a> Runtime<'a> {
         &self,
         expression: &Expression<'a>,
         environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        // a statement that suspended runs again, without evaluating what it evaluated before
+        if !self.replays.borrow().is_empty() {
+            let node = address(expression);
+            if self.keeps(node) {
+                if let Some(value) = self.replayed_value(node) {
+                    return Ok(value);
+                }
+                let value = self.eval_expression_kind(expression, environment)?;
+                self.keep_value(node, &value);
+                return Ok(value);
+            }
+        }
+        self.eval_expression_kind(expression, environment)
+    }
+
+    fn eval_expression_kind(
+        &self,
+        expression: &Expression<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
     ) -> Result<Primitive<'a>> {
         match expression {
             Expression::BooleanLiteral(expression) => self.eval_boolean_literal(expression),
             Expression::NumberLiteral(expression) => self.eval_number_literal(expression),
+            Expression::BigIntLiteral(expression) => self.eval_bigint_literal(expression),
+            Expression::TemplateLiteral(expression) => {
+                self.eval_template_literal(expression, environment)
+            }
             Expression::StringLiteral(expression) => self.eval_string_literal(expression),
             Expression::Identifier(expression) => self.eval_identifier(expression, environment),
             Expression::ArrayExpression(expression) => {
@@ -63,7 +88,22 @@ impl<'a> Runtime<'a> {
             Expression::MemberExpression(expression) => {
                 self.eval_member_expression(expression, environment)
             }
+            Expression::ChainExpression(expression) => {
+                self.eval_chain_expression(expression, environment)
+            }
+            Expression::MatchExpression(expression) => {
+                self.eval_match_expression(expression, environment)
+            }
+            Expression::ConditionalExpression(expression) => {
+                self.eval_conditional_expression(expression, environment)
+            }
             Expression::Super(expression) => self.eval_super_expression(expression, environment),
+            Expression::YieldExpression(expression) => {
+                self.eval_yield_expression(expression, environment)
+            }
+            Expression::AwaitExpression(expression) => {
+                self.eval_await_expression(expression, environment)
+            }
         }
     }
 
@@ -75,7 +115,6 @@ impl<'a> Runtime<'a> {
         environment
             .borrow()
             .get(expression.name.to_owned(), expression.span)
-            .map(|v| v.clone())
     }
 
     fn eval_array_expression(
@@ -85,22 +124,16 @@ impl<'a> Runtime<'a> {
     ) -> Result<Primitive<'a>> {
         let mut result = StdVec::new();
         for element in &expression.elements {
-            let value = self.eval_array_expression_element(element, Rc::clone(&environment))?;
-            result.push(value);
-        }
-        Ok(Primitive::Array(result))
-    }
-
-    fn eval_array_expression_element(
-        &self,
-        expression: &ArrayExpressionElement<'a>,
-        environment: Rc<RefCell<Environment<'a>>>,
-    ) -> Result<Primitive<'a>> {
-        match expression {
-            ArrayExpressionElement::Expression(expression) => {
-                self.eval_expression(expression, Rc::clone(&environment))
+            match element {
+                ArrayExpressionElement::Expression(expression) => {
+                    result.push(self.eval_expression(expression, Rc::clone(&environment))?);
+                }
+                ArrayExpressionElement::SpreadElement(spread) => {
+                    result.extend(self.eval_spread_element(spread, Rc::clone(&environment))?);
+                }
             }
         }
+        Ok(Primitive::Array(result))
     }
 
     fn eval_sequence_expression(
@@ -2,12 +2,13 @@ use std::rc::Rc;
 use std::{cell::RefCell, ptr};
 
 use crate::environment::Environment;
+use crate::evaluator::generator::Generator;
 use crate::evaluator::Primitive;
 use crate::{diagnostics, Runtime};
 use std::vec::Vec as StdVec;
-use wave_allocator::Box;
+use wave_allocator::{Box, Vec};
 use wave_ast::ast::{
-    Argument, BindingPatternKind, CallExpression, Expression, FormalParameter, Function,
+    Argument, CallExpression, Expression, FormalParameters, Function, SpreadElement,
 };
 use wave_diagnostics::Result;
 use wave_span::{Atom, Span};
@@ -15,7 +16,47 @@ use wave_span::{Atom, Span};
 #[derive(Clone)]
 pub struct InbuiltFunction {
     pub name: Atom,
-    pub function: fn(&[Primitive<'_>]) -> Result<Primitive<'static>>,
+    pub function: for<'a> fn(&Runtime<'a>, &[Primitive<'a>]) -> Result<Primitive<'a>>,
+}
+
+/// The Rust implementation of a [`NativeFunction`], called with its receiver and arguments.
+pub type NativeCallback<'a> = Rc<
+    dyn Fn(&Runtime<'a>, &Primitive<'a>, StdVec<Primitive<'a>>, Span) -> Result<Primitive<'a>> + 'a,
+>;
+
+/// A function implemented in Rust, like the exports of native modules, the functions registered
+/// by the host or the `next()` of a generator. Methods are bound to the value they were read from.
+#[derive(Clone)]
+pub struct NativeFunction<'a> {
+    pub name: Atom,
+    pub receiver: Rc<Primitive<'a>>,
+    pub function: NativeCallback<'a>,
+}
+
+impl<'a> NativeFunction<'a> {
+    pub fn new(
+        name: &str,
+        function: impl Fn(&Runtime<'a>, StdVec<Primitive<'a>>, Span) -> Result<Primitive<'a>> + 'a,
+    ) -> Self {
+        Self::method(
+            name.into(),
+            Primitive::Null,
+            move |runtime, _, arguments, span| function(runtime, arguments, span),
+        )
+    }
+
+    pub fn method(
+        name: Atom,
+        receiver: Primitive<'a>,
+        function: impl Fn(&Runtime<'a>, &Primitive<'a>, StdVec<Primitive<'a>>, Span) -> Result<Primitive<'a>>
+            + 'a,
+    ) -> Self {
+        Self {
+            name,
+            receiver: Rc::new(receiver),
+            function: Rc::new(function),
+        }
+    }
 }
 
 impl<'a> Runtime<'a> {
@@ -24,9 +65,12 @@ impl<'a> Runtime<'a> {
         expression: &Box<'_, Function<'a>>,
         environment: Rc<RefCell<Environment<'a>>>,
     ) -> Result<Primitive<'a>> {
+        if expression.generator || expression.r#async {
+            return self.eval_resumable_function(expression, environment);
+        }
         unsafe {
             let function = ptr::read(expression).unbox();
-            let params = function.params.unbox().items;
+            let params = function.params.unbox();
 
             match (function.id, function.body) {
                 (Some(id), Some(body)) => {
@@ -78,25 +122,12 @@ impl<'a> Runtime<'a> {
             Expression::Identifier(identifier) => {
                 let function_name = identifier.name.to_owned();
 
-                let mut arguments = vec![];
-
-                for arg in &expression.arguments {
-                    match arg {
-                        Argument::Expression(expression) => {
-                            arguments
-                                .push(self.eval_expression(expression, Rc::clone(&environment))?);
-                        }
-                    }
-                }
+                let arguments =
+                    self.eval_arguments(&expression.arguments, Rc::clone(&environment))?;
 
                 if self.is_inbuilt_function(&function_name) {
                     let in_built = self.get_in_built_function(&function_name);
-                    let function = in_built.function;
-                    unsafe {
-                        Ok(std::mem::transmute::<Primitive<'_>, Primitive<'_>>(
-                            function(&arguments)?,
-                        ))
-                    }
+                    self.call_inbuilt_function(&in_built, &arguments)
                 } else {
                     let function = environment.borrow().get(function_name, identifier.span)?;
                     self.apply_function(function, arguments, expression.span)
@@ -105,30 +136,16 @@ impl<'a> Runtime<'a> {
             Expression::MemberExpression(_) => {
                 let function = self.eval_expression(&expression.callee, Rc::clone(&environment))?;
 
-                let mut arguments = vec![];
-                for arg in &expression.arguments {
-                    match arg {
-                        Argument::Expression(expression) => {
-                            arguments
-                                .push(self.eval_expression(expression, Rc::clone(&environment))?);
-                        }
-                    }
-                }
+                let arguments =
+                    self.eval_arguments(&expression.arguments, Rc::clone(&environment))?;
 
                 self.apply_function(function, arguments, expression.span)
             }
             Expression::FunctionExpression(function) => {
                 let function = self.eval_function(function, Rc::clone(&environment))?;
 
-                let mut arguments = vec![];
-                for arg in &expression.arguments {
-                    match arg {
-                        Argument::Expression(expression) => {
-                            arguments
-                                .push(self.eval_expression(expression, Rc::clone(&environment))?);
-                        }
-                    }
-                }
+                let arguments =
+                    self.eval_arguments(&expression.arguments, Rc::clone(&environment))?;
 
                 self.apply_function(function, arguments, expression.span)
             }
@@ -136,22 +153,15 @@ impl<'a> Runtime<'a> {
                 let function =
                     self.eval_super_expression(super_expression, Rc::clone(&environment))?;
 
-                let mut arguments = vec![];
-                for arg in &expression.arguments {
-                    match arg {
-                        Argument::Expression(expression) => {
-                            arguments
-                                .push(self.eval_expression(expression, Rc::clone(&environment))?);
-                        }
-                    }
-                }
+                let arguments =
+                    self.eval_arguments(&expression.arguments, Rc::clone(&environment))?;
 
-                self.apply_constructor(
-                    function,
-                    arguments,
-                    expression.span,
-                    Rc::clone(&environment),
-                )
+                // without a constructor in the parent chain there is nothing to call
+                if let Primitive::Null = function {
+                    return Ok(Primitive::Null);
+                }
+                self.apply_function(function, arguments, expression.span)?;
+                Ok(Primitive::Null)
             }
             _ => unreachable!(),
         }
@@ -162,28 +172,23 @@ impl<'a> Runtime<'a> {
         function: Primitive<'a>,
         arguments: StdVec<Primitive<'a>>,
         callee_span: Span,
+    ) -> Result<Primitive<'a>> {
+        self.debug_enter(callee_span);
+        let result = self.call_function(function, arguments, callee_span);
+        self.debug_exit();
+        result
+    }
+
+    fn call_function(
+        &self,
+        function: Primitive<'a>,
+        arguments: StdVec<Primitive<'a>>,
+        callee_span: Span,
     ) -> Result<Primitive<'a>> {
         match function {
             Primitive::Function(params, body, env) => {
-                let env = Rc::new(RefCell::new(Environment::extend(env)));
-
-                match params {
-                    Some(params) => {
-                        if params.len() != arguments.len() {
-                            return Err(diagnostics::InvalidNumberOfArguments(callee_span).into());
-                        }
-
-                        for (param, arg) in params.iter().zip(arguments) {
-                            let param_name = self.get_atom_formal_parameters(param);
-                            env.borrow_mut().define(param_name, arg);
-                        }
-                    }
-                    None => {
-                        if !arguments.is_empty() {
-                            return Err(diagnostics::InvalidNumberOfArguments(callee_span).into());
-                        }
-                    }
-                }
+                let env = self.alloc_environment(Environment::extend(env));
+                self.bind_arguments(params.as_ref(), arguments, callee_span, Rc::clone(&env))?;
 
                 match body {
                     Some(body) => {
@@ -193,14 +198,128 @@ impl<'a> Runtime<'a> {
                     None => Ok(Primitive::Null),
                 }
             }
+            Primitive::GeneratorFunction(params, body, env) => {
+                let env = self.alloc_environment(Environment::extend(env));
+                self.bind_arguments(Some(params), arguments, callee_span, Rc::clone(&env))?;
+                Ok(Primitive::Generator(Rc::new(RefCell::new(Generator::new(
+                    body, env,
+                )))))
+            }
+            Primitive::AsyncFunction(params, body, env) => {
+                self.call_async_function(params, body, env, arguments, callee_span)
+            }
+            Primitive::NativeFunction(function) => {
+                (function.function)(self, &function.receiver, arguments, callee_span)
+            }
             _ => Err(diagnostics::CannotCallNonFunction(callee_span).into()),
         }
     }
 
-    pub fn get_atom_formal_parameters(&self, param: &FormalParameter) -> Atom {
-        match &param.pattern.kind {
-            BindingPatternKind::BindingIdentifier(identifier) => identifier.name.to_owned(),
+    pub fn call_inbuilt_function(
+        &self,
+        in_built: &InbuiltFunction,
+        arguments: &[Primitive<'a>],
+    ) -> Result<Primitive<'a>> {
+        (in_built.function)(self, arguments)
+    }
+
+    pub fn eval_arguments(
+        &self,
+        arguments: &Vec<'_, Argument<'a>>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<StdVec<Primitive<'a>>> {
+        let mut values = vec![];
+        for argument in arguments {
+            match argument {
+                Argument::Expression(expression) => {
+                    values.push(self.eval_expression(expression, Rc::clone(&environment))?);
+                }
+                Argument::SpreadElement(spread) => {
+                    values.extend(self.eval_spread_element(spread, Rc::clone(&environment))?);
+                }
+            }
         }
+        Ok(values)
+    }
+
+    pub fn eval_spread_element(
+        &self,
+        spread: &SpreadElement<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<StdVec<Primitive<'a>>> {
+        let value = self.eval_expression(&spread.argument, environment)?;
+        self.collect_iterable(value, spread.span)?
+            .ok_or_else(|| diagnostics::InvalidSpread(spread.span).into())
+    }
+
+    /// Binds call arguments to the parameters, missing optional arguments take their default.
+    pub fn bind_arguments(
+        &self,
+        params: Option<&FormalParameters<'a>>,
+        mut arguments: StdVec<Primitive<'a>>,
+        callee_span: Span,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<()> {
+        let Some(params) = params else {
+            if !arguments.is_empty() {
+                return Err(diagnostics::InvalidNumberOfArguments(
+                    "0 arguments".to_string(),
+                    arguments.len(),
+                    callee_span,
+                )
+                .into());
+            }
+            return Ok(());
+        };
+
+        let required = params.required_count();
+        let total = params.items.len();
+        let noun = |count: usize| if count == 1 { "argument" } else { "arguments" };
+        let expected = match (params.rest.is_some(), required == total) {
+            (true, _) => format!("at least {required} {}", noun(required)),
+            (false, true) => format!("{required} {}", noun(required)),
+            (false, false) => format!("{required} to {total} arguments"),
+        };
+        if arguments.len() < required || (params.rest.is_none() && arguments.len() > total) {
+            return Err(diagnostics::InvalidNumberOfArguments(
+                expected,
+                arguments.len(),
+                callee_span,
+            )
+            .into());
+        }
+
+        let rest = arguments.split_off(total.min(arguments.len()));
+        let mut arguments = arguments.into_iter();
+        for param in &params.items {
+            let argument = arguments.next().unwrap_or(Primitive::Null);
+            self.bind_pattern(&param.pattern, argument, Rc::clone(&environment))?;
+        }
+        if let Some(rest_param) = &params.rest {
+            self.bind_pattern(&rest_param.argument, Primitive::Array(rest), environment)?;
+        }
+        Ok(())
+    }
+
+    /// Built-in callers pass callbacks every argument they may use, a callback declaring fewer
+    /// parameters, like a `Promise` executor taking only `resolve`, receives the leading ones.
+    pub fn leading_arguments(
+        &self,
+        callback: &Primitive<'a>,
+        mut arguments: StdVec<Primitive<'a>>,
+    ) -> StdVec<Primitive<'a>> {
+        let params = match callback {
+            Primitive::Function(params, _, _) => params.as_ref(),
+            Primitive::GeneratorFunction(params, _, _) | Primitive::AsyncFunction(params, _, _) => {
+                Some(*params)
+            }
+            _ => return arguments,
+        };
+        let accepted = params.map_or(0, |params| params.items.len());
+        if params.is_none_or(|params| params.rest.is_none()) {
+            arguments.truncate(accepted);
+        }
+        arguments
     }
 
     pub fn unwrap_return_value(&self, primitive: Primitive<'a>) -> Result<Primitive<'a>> {
@@ -226,3 +345,30 @@ impl<'a> Runtime<'a> {
             .clone()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::eval;
+
+    #[test]
+    fn default_and_rest_parameters() {
+        let function = "function f(a, b = a + 1, ...rest) { return [a, b, rest]; }";
+        let call = |args: &str| eval(&format!("{function} f({args});"));
+        assert_eq!(call("1").unwrap(), "[1, 2, []]");
+        assert_eq!(call("1, 5, 6, 7").unwrap(), "[1, 5, [6, 7]]");
+
+        let error = call("").unwrap_err().to_string();
+        assert_eq!(error, "Expected at least 1 argument, but got 0.");
+    }
+
+    #[test]
+    fn spread_arguments_and_elements() {
+        let function = "function f(a, b, c) { return a + b + c; }";
+        assert_eq!(eval(&format!("{function} f(...[1, 2], 3);")).unwrap(), "6");
+        assert_eq!(
+            eval("let a = [2, 3]; [1, ...a, 4];").unwrap(),
+            "[1, 2, 3, 4]"
+        );
+        assert!(eval("[...1];").is_err());
+    }
+}
@@ -0,0 +1,1028 @@
+use std::cell::RefCell;
+use std::ops::ControlFlow;
+use std::rc::Rc;
+use std::vec::Vec as StdVec;
+
+use crate::evaluator::function::NativeFunction;
+use crate::evaluator::iterator::ValueIterator;
+use crate::evaluator::Primitive;
+use crate::{diagnostics, environment::Environment, Runtime};
+use rustc_hash::{FxHashMap, FxHashSet};
+use wave_ast::ast::{
+    Argument, ArrayExpressionElement, AssignmentTarget, AwaitExpression, ChainElement, Declaration,
+    Expression, ForOfStatement, Function, MatchPattern, MemberExpression, SimpleAssignmentTarget,
+    Statement, SwitchCase, WhileStatement, YieldExpression,
+};
+use wave_diagnostics::Result;
+use wave_span::{Atom, GetSpan, Span};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneratorState {
+    Suspended,
+    /// Resuming a generator from its own body is an error
+    Running,
+    Completed,
+}
+
+/// The body of a `function*`, run as an explicit stack of frames so it can be suspended at
+/// a `yield` and resumed later from the same point. Async functions run the same way, each
+/// `await` suspends them until the awaited promise settles.
+pub struct Generator<'a> {
+    pub state: GeneratorState,
+    frames: StdVec<Frame<'a>>,
+    /// The expressions the statement it is suspended in already evaluated
+    replay: Replay<'a>,
+}
+
+/// A `yield` or `await` suspends its function in the middle of a statement, which runs again
+/// from its start once resumed. The values of the expressions it already evaluated are kept by
+/// the address of their node, so running it again does not repeat their side effects, and the
+/// `yield` or `await` it suspended at evaluates to the value it was resumed with.
+#[derive(Default)]
+pub struct Replay<'a> {
+    /// The expressions of the statement whose value is kept, those that may suspend and their
+    /// operands
+    kept: FxHashSet<usize>,
+    values: FxHashMap<usize, Primitive<'a>>,
+    /// The iterators of the `yield*` in progress
+    delegates: FxHashMap<usize, ValueIterator<'a>>,
+    /// The `yield` or `await` the value of the next resumption goes to
+    waiting: Option<usize>,
+    /// The value the `yield*` in progress passes on to its iterator
+    sent: Option<Primitive<'a>>,
+    /// What the statement yielded or awaited, once it suspended
+    suspended: Option<Primitive<'a>>,
+}
+
+/// One level of the suspended statements of a generator, the innermost is last.
+enum Frame<'a> {
+    Block {
+        statements: &'a [Statement<'a>],
+        index: usize,
+        environment: Rc<RefCell<Environment<'a>>>,
+    },
+    While {
+        statement: &'a WhileStatement<'a>,
+        labels: StdVec<Atom>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    },
+    ForOf {
+        statement: &'a ForOfStatement<'a>,
+        iterator: ValueIterator<'a>,
+        labels: StdVec<Atom>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    },
+    /// The cases of a `switch` still to fall through
+    Switch {
+        cases: &'a [SwitchCase<'a>],
+        next: usize,
+        labels: StdVec<Atom>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    },
+    /// A labeled statement that is not a loop, only a `break` may target it
+    Label { labels: StdVec<Atom> },
+}
+
+/// What resuming a generator produced.
+pub enum GeneratorStep<'a> {
+    Yield(Primitive<'a>),
+    /// The generator finished, with the value of its `return`
+    Return(Primitive<'a>),
+}
+
+/// How running a statement of a generator continues.
+enum Flow<'a> {
+    Next,
+    Suspend(Primitive<'a>),
+    /// A `break`, `continue` or `return`
+    Jump(Primitive<'a>),
+}
+
+impl<'a> Generator<'a> {
+    pub fn new(body: &'a [Statement<'a>], environment: Rc<RefCell<Environment<'a>>>) -> Self {
+        Self {
+            state: GeneratorState::Suspended,
+            frames: vec![Frame::Block {
+                statements: body,
+                index: 0,
+                environment,
+            }],
+            replay: Replay::default(),
+        }
+    }
+
+    pub fn complete(&mut self) {
+        self.state = GeneratorState::Completed;
+        self.frames.clear();
+        self.replay = Replay::default();
+    }
+}
+
+impl<'a> Frame<'a> {
+    fn body(statement: &'a Statement<'a>, environment: Rc<RefCell<Environment<'a>>>) -> Self {
+        Frame::Block {
+            statements: std::slice::from_ref(statement),
+            index: 0,
+            environment,
+        }
+    }
+
+    fn labels(&self) -> &[Atom] {
+        match self {
+            Frame::While { labels, .. }
+            | Frame::ForOf { labels, .. }
+            | Frame::Switch { labels, .. }
+            | Frame::Label { labels } => labels,
+            Frame::Block { .. } => &[],
+        }
+    }
+}
+
+impl<'a> Replay<'a> {
+    fn keep(&mut self, expression: &Expression<'a>) {
+        self.kept.insert(address(expression));
+        if suspends(expression) {
+            for_each_operand(expression, &mut |operand| self.keep(operand));
+        }
+    }
+}
+
+/// Identifies an expression by the address of its node, which stays the same while the
+/// program runs.
+pub(crate) fn address(expression: &Expression<'_>) -> usize {
+    fn node<T>(node: &T) -> usize {
+        node as *const T as usize
+    }
+    match expression {
+        Expression::AssignmentExpression(expression) => node(&**expression),
+        Expression::BooleanLiteral(expression) => node(&**expression),
+        Expression::NullLiteral(expression) => node(&**expression),
+        Expression::NumberLiteral(expression) => node(&**expression),
+        Expression::BigIntLiteral(expression) => node(&**expression),
+        Expression::StringLiteral(expression) => node(&**expression),
+        Expression::TemplateLiteral(expression) => node(&**expression),
+        Expression::Identifier(expression) => node(&**expression),
+        Expression::BinaryExpression(expression) => node(&**expression),
+        Expression::SequenceExpression(expression) => node(&**expression),
+        Expression::ParenthesizedExpression(expression) => node(&**expression),
+        Expression::ArrayExpression(expression) => node(&**expression),
+        Expression::CallExpression(expression) => node(&**expression),
+        Expression::UnaryExpression(expression) => node(&**expression),
+        Expression::UpdateExpression(expression) => node(&**expression),
+        Expression::LogicalExpression(expression) => node(&**expression),
+        Expression::ConditionalExpression(expression) => node(&**expression),
+        Expression::FunctionExpression(expression) => node(&**expression),
+        Expression::MatchExpression(expression) => node(&**expression),
+        Expression::MemberExpression(expression) => node(&**expression),
+        Expression::ChainExpression(expression) => node(&**expression),
+        Expression::ThisExpression(expression) => node(&**expression),
+        Expression::Super(expression) => node(&**expression),
+        Expression::NewExpression(expression) => node(&**expression),
+        Expression::YieldExpression(expression) => node(&**expression),
+        Expression::AwaitExpression(expression) => node(&**expression),
+    }
+}
+
+/// Whether evaluating `expression` may suspend its function, the functions it creates
+/// suspend on their own.
+fn suspends(expression: &Expression<'_>) -> bool {
+    if matches!(
+        expression,
+        Expression::YieldExpression(_) | Expression::AwaitExpression(_)
+    ) {
+        return true;
+    }
+    let mut found = false;
+    for_each_operand(expression, &mut |operand| {
+        found = found || suspends(operand)
+    });
+    found
+}
+
+/// Calls `f` with the expressions `expression` evaluates directly.
+fn for_each_operand<'a, 'e>(
+    expression: &'e Expression<'a>,
+    f: &mut impl FnMut(&'e Expression<'a>),
+) {
+    fn arguments<'a, 'e>(arguments: &'e [Argument<'a>], f: &mut impl FnMut(&'e Expression<'a>)) {
+        for argument in arguments {
+            match argument {
+                Argument::Expression(expression) => f(expression),
+                Argument::SpreadElement(spread) => f(&spread.argument),
+            }
+        }
+    }
+    fn member<'a, 'e>(member: &'e MemberExpression<'a>, f: &mut impl FnMut(&'e Expression<'a>)) {
+        f(member.object());
+        if let MemberExpression::ComputedMemberExpression(member) = member {
+            f(&member.expression);
+        }
+    }
+    fn match_pattern<'a, 'e>(
+        pattern: &'e MatchPattern<'a>,
+        f: &mut impl FnMut(&'e Expression<'a>),
+    ) {
+        match pattern {
+            MatchPattern::Literal(expression) => f(expression),
+            MatchPattern::Array(array) => {
+                for element in &array.elements {
+                    match_pattern(element, f);
+                }
+            }
+            MatchPattern::Instance(instance) => {
+                for property in &instance.properties {
+                    match_pattern(&property.value, f);
+                }
+            }
+            MatchPattern::Identifier(_) => {}
+        }
+    }
+
+    match expression {
+        Expression::AssignmentExpression(expression) => {
+            let AssignmentTarget::SimpleAssignmentTarget(target) = &expression.left;
+            if let SimpleAssignmentTarget::MemberAssignmentTarget(target) = target {
+                member(target, f);
+            }
+            f(&expression.right);
+        }
+        Expression::TemplateLiteral(expression) => expression.expressions.iter().for_each(f),
+        Expression::BinaryExpression(expression) => {
+            f(&expression.left);
+            f(&expression.right);
+        }
+        Expression::LogicalExpression(expression) => {
+            f(&expression.left);
+            f(&expression.right);
+        }
+        Expression::SequenceExpression(expression) => expression.expressions.iter().for_each(f),
+        Expression::ParenthesizedExpression(expression) => f(&expression.expression),
+        Expression::ArrayExpression(expression) => {
+            for element in &expression.elements {
+                match element {
+                    ArrayExpressionElement::Expression(expression) => f(expression),
+                    ArrayExpressionElement::SpreadElement(spread) => f(&spread.argument),
+                }
+            }
+        }
+        Expression::CallExpression(expression) => {
+            f(&expression.callee);
+            arguments(&expression.arguments, f);
+        }
+        Expression::NewExpression(expression) => {
+            f(&expression.callee);
+            arguments(&expression.arguments, f);
+        }
+        Expression::UnaryExpression(expression) => f(&expression.argument),
+        Expression::UpdateExpression(expression) => {
+            if let SimpleAssignmentTarget::MemberAssignmentTarget(target) = &expression.argument {
+                member(target, f);
+            }
+        }
+        Expression::ConditionalExpression(expression) => {
+            f(&expression.test);
+            f(&expression.consequent);
+            f(&expression.alternate);
+        }
+        Expression::MatchExpression(expression) => {
+            f(&expression.discriminant);
+            for arm in &expression.arms {
+                match_pattern(&arm.pattern, f);
+                if let Some(guard) = &arm.guard {
+                    f(guard);
+                }
+                f(&arm.body);
+            }
+        }
+        Expression::MemberExpression(expression) => member(expression, f),
+        Expression::ChainExpression(expression) => match &expression.expression {
+            ChainElement::CallExpression(expression) => {
+                f(&expression.callee);
+                arguments(&expression.arguments, f);
+            }
+            ChainElement::MemberExpression(expression) => member(expression, f),
+        },
+        Expression::YieldExpression(expression) => {
+            if let Some(argument) = &expression.argument {
+                f(argument);
+            }
+        }
+        Expression::AwaitExpression(expression) => f(&expression.argument),
+        Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::NumberLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::Identifier(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ThisExpression(_)
+        | Expression::Super(_) => {}
+    }
+}
+
+/// The expressions a statement evaluates before it runs its body, if it has one.
+fn statement_expressions<'a, 's>(statement: &'s Statement<'a>) -> StdVec<&'s Expression<'a>> {
+    match statement {
+        Statement::ExpressionStatement(statement) => vec![&statement.expression],
+        Statement::Declaration(Declaration::VariableDeclaration(declaration)) => declaration
+            .declarations
+            .iter()
+            .filter_map(|declarator| declarator.init.as_ref())
+            .collect(),
+        Statement::ReturnStatement(statement) => statement.argument.iter().collect(),
+        Statement::IfStatement(statement) => vec![&statement.test],
+        Statement::WhileStatement(statement) => vec![&statement.test],
+        Statement::ForOfStatement(statement) => vec![&statement.right],
+        Statement::SwitchStatement(statement) => std::iter::once(&statement.discriminant)
+            .chain(statement.cases.iter().filter_map(|case| case.test.as_ref()))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Whether running `statement` may suspend.
+fn contains_yield(statement: &Statement<'_>) -> bool {
+    if statement_expressions(statement).into_iter().any(suspends) {
+        return true;
+    }
+    match statement {
+        Statement::BlockStatement(block) => block.body.iter().any(contains_yield),
+        Statement::IfStatement(statement) => {
+            contains_yield(&statement.consequent)
+                || statement.alternate.as_ref().is_some_and(contains_yield)
+        }
+        Statement::WhileStatement(statement) => contains_yield(&statement.body),
+        Statement::ForOfStatement(statement) => contains_yield(&statement.body),
+        Statement::SwitchStatement(statement) => statement
+            .cases
+            .iter()
+            .any(|case| case.consequent.iter().any(contains_yield)),
+        Statement::LabeledStatement(statement) => contains_yield(&statement.body),
+        _ => false,
+    }
+}
+
+impl<'a> Runtime<'a> {
+    /// Generator and async functions keep references to their body, which they run as a
+    /// [`Generator`].
+    pub fn eval_resumable_function(
+        &self,
+        function: &Function<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        // SAFETY: the function is allocated in the arena of the program, which outlives
+        // every value created while evaluating it
+        let function = unsafe { &*(function as *const Function<'a>) };
+        let body = match &function.body {
+            Some(body) => body.statements.as_slice(),
+            None => &[],
+        };
+        let value = if function.r#async {
+            Primitive::AsyncFunction(&function.params, body, Rc::clone(&environment))
+        } else {
+            Primitive::GeneratorFunction(&function.params, body, Rc::clone(&environment))
+        };
+        if let Some(id) = &function.id {
+            if self.is_inbuilt_function(&id.name) {
+                return Err(diagnostics::CannotRedeclareInbuiltFunction(id.span).into());
+            }
+            environment
+                .borrow_mut()
+                .define(id.name.clone(), value.clone());
+        }
+        Ok(value)
+    }
+
+    /// Runs `generator` until its next `yield` or its end, `sent` is the value of the
+    /// `yield` it was suspended at.
+    pub fn resume_generator(
+        &self,
+        generator: &Rc<RefCell<Generator<'a>>>,
+        sent: Primitive<'a>,
+        span: Span,
+    ) -> Result<GeneratorStep<'a>> {
+        let (mut frames, mut replay) = {
+            let mut generator = generator.borrow_mut();
+            match generator.state {
+                GeneratorState::Running => {
+                    return Err(diagnostics::GeneratorAlreadyRunning(span).into())
+                }
+                GeneratorState::Completed => return Ok(GeneratorStep::Return(Primitive::Null)),
+                GeneratorState::Suspended => {}
+            }
+            generator.state = GeneratorState::Running;
+            (
+                std::mem::take(&mut generator.frames),
+                std::mem::take(&mut generator.replay),
+            )
+        };
+        if let Some(node) = replay.waiting.take() {
+            if replay.delegates.contains_key(&node) {
+                replay.sent = Some(sent);
+            } else {
+                replay.values.insert(node, sent);
+            }
+        }
+
+        self.replays.borrow_mut().push(replay);
+        let result = self.run_frames(&mut frames);
+        let replay = self.replays.borrow_mut().pop().unwrap_or_default();
+        let mut generator = generator.borrow_mut();
+        match result {
+            Ok(Flow::Suspend(value)) => {
+                generator.state = GeneratorState::Suspended;
+                generator.frames = frames;
+                generator.replay = replay;
+                Ok(GeneratorStep::Yield(value))
+            }
+            Ok(Flow::Jump(Primitive::Return(value))) => {
+                generator.complete();
+                Ok(GeneratorStep::Return(*value))
+            }
+            Ok(_) => {
+                generator.complete();
+                Ok(GeneratorStep::Return(Primitive::Null))
+            }
+            Err(error) => {
+                generator.complete();
+                Err(error)
+            }
+        }
+    }
+
+    fn run_frames(&self, frames: &mut StdVec<Frame<'a>>) -> Result<Flow<'a>> {
+        while let Some(frame) = frames.last_mut() {
+            let flow = match frame {
+                Frame::Block {
+                    statements,
+                    index,
+                    environment,
+                } => {
+                    let statements: &'a [Statement<'a>] = statements;
+                    let environment = Rc::clone(environment);
+                    *index += 1;
+                    match statements.get(*index - 1) {
+                        Some(statement) => {
+                            let flow = self.run_statement(statement, environment, frames)?;
+                            // the statement runs again once resumed
+                            if let (Flow::Suspend(_), Some(Frame::Block { index, .. })) =
+                                (&flow, frames.last_mut())
+                            {
+                                *index -= 1;
+                            }
+                            flow
+                        }
+                        None => {
+                            frames.pop();
+                            Flow::Next
+                        }
+                    }
+                }
+                Frame::While {
+                    statement,
+                    environment,
+                    ..
+                } => {
+                    let statement: &'a WhileStatement<'a> = statement;
+                    let environment = Rc::clone(environment);
+                    let test = self.replay(&[&statement.test], || {
+                        self.eval_expression(&statement.test, Rc::clone(&environment))
+                    })?;
+                    let test = match test {
+                        ControlFlow::Continue(test) => test,
+                        ControlFlow::Break(value) => return Ok(Flow::Suspend(value)),
+                    };
+                    if test.is_truthy() {
+                        frames.push(Frame::body(&statement.body, environment));
+                    } else {
+                        frames.pop();
+                    }
+                    Flow::Next
+                }
+                Frame::ForOf {
+                    statement,
+                    iterator,
+                    environment,
+                    ..
+                } => {
+                    let statement: &'a ForOfStatement<'a> = statement;
+                    let environment = Rc::clone(environment);
+                    match self.iterator_next(iterator, statement.right.span())? {
+                        Some(value) => {
+                            let iteration_env =
+                                self.alloc_environment(Environment::extend(environment));
+                            for declarator in &statement.left.declarations {
+                                self.bind_pattern(
+                                    &declarator.id,
+                                    value.clone(),
+                                    Rc::clone(&iteration_env),
+                                )?;
+                            }
+                            frames.push(Frame::body(&statement.body, iteration_env));
+                        }
+                        None => {
+                            frames.pop();
+                        }
+                    }
+                    Flow::Next
+                }
+                Frame::Switch {
+                    cases,
+                    next,
+                    environment,
+                    ..
+                } => {
+                    let cases: &'a [SwitchCase<'a>] = cases;
+                    let environment = Rc::clone(environment);
+                    *next += 1;
+                    match cases.get(*next - 1) {
+                        Some(case) => frames.push(Frame::Block {
+                            statements: case.consequent.as_slice(),
+                            index: 0,
+                            environment,
+                        }),
+                        None => {
+                            frames.pop();
+                        }
+                    }
+                    Flow::Next
+                }
+                Frame::Label { .. } => {
+                    frames.pop();
+                    Flow::Next
+                }
+            };
+
+            match flow {
+                Flow::Next => {}
+                Flow::Jump(jump @ Primitive::Return(_)) => return Ok(Flow::Jump(jump)),
+                Flow::Jump(jump) => Self::unwind(frames, &jump),
+                suspend @ Flow::Suspend(..) => return Ok(suspend),
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Runs one statement of a generator body, statements that may suspend push frames
+    /// instead of running to completion.
+    fn run_statement(
+        &self,
+        statement: &'a Statement<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+        frames: &mut StdVec<Frame<'a>>,
+    ) -> Result<Flow<'a>> {
+        if !contains_yield(statement) {
+            return Ok(match self.eval_statement(statement, environment)? {
+                jump @ (Primitive::Return(_) | Primitive::Break(_) | Primitive::Continue(_)) => {
+                    Flow::Jump(jump)
+                }
+                _ => Flow::Next,
+            });
+        }
+
+        match statement {
+            Statement::ExpressionStatement(_)
+            | Statement::Declaration(Declaration::VariableDeclaration(_))
+            | Statement::ReturnStatement(_) => {
+                let expressions = statement_expressions(statement);
+                let value = self.replay(&expressions, || {
+                    self.eval_statement(statement, Rc::clone(&environment))
+                })?;
+                Ok(match value {
+                    ControlFlow::Continue(jump @ Primitive::Return(_)) => Flow::Jump(jump),
+                    ControlFlow::Continue(_) => Flow::Next,
+                    ControlFlow::Break(value) => Flow::Suspend(value),
+                })
+            }
+            Statement::BlockStatement(block) => {
+                frames.push(Frame::Block {
+                    statements: block.body.as_slice(),
+                    index: 0,
+                    environment,
+                });
+                Ok(Flow::Next)
+            }
+            Statement::IfStatement(statement) => {
+                let test = self.replay(&[&statement.test], || {
+                    self.eval_expression(&statement.test, Rc::clone(&environment))
+                })?;
+                let test = match test {
+                    ControlFlow::Continue(test) => test,
+                    ControlFlow::Break(value) => return Ok(Flow::Suspend(value)),
+                };
+                if test.is_truthy() {
+                    frames.push(Frame::body(&statement.consequent, environment));
+                } else if let Some(alternate) = &statement.alternate {
+                    frames.push(Frame::body(alternate, environment));
+                }
+                Ok(Flow::Next)
+            }
+            Statement::LabeledStatement(statement) => {
+                let mut labels = vec![statement.label.name.clone()];
+                let mut body = &statement.body;
+                while let Statement::LabeledStatement(inner) = body {
+                    labels.push(inner.label.name.clone());
+                    body = &inner.body;
+                }
+                self.push_statement_frame(body, labels, environment, frames)
+            }
+            statement => self.push_statement_frame(statement, vec![], environment, frames),
+        }
+    }
+
+    /// Pushes the frame of a loop, a `switch` or a labeled statement.
+    fn push_statement_frame(
+        &self,
+        statement: &'a Statement<'a>,
+        labels: StdVec<Atom>,
+        environment: Rc<RefCell<Environment<'a>>>,
+        frames: &mut StdVec<Frame<'a>>,
+    ) -> Result<Flow<'a>> {
+        match statement {
+            Statement::WhileStatement(statement) => frames.push(Frame::While {
+                statement,
+                labels,
+                environment,
+            }),
+            Statement::ForOfStatement(statement) => {
+                let span = statement.right.span();
+                let iterable = self.replay(&[&statement.right], || {
+                    self.eval_expression(&statement.right, Rc::clone(&environment))
+                })?;
+                let iterable = match iterable {
+                    ControlFlow::Continue(iterable) => iterable,
+                    ControlFlow::Break(value) => return Ok(Flow::Suspend(value)),
+                };
+                let Some(iterator) = self.get_iterator(iterable, span)? else {
+                    return Err(diagnostics::NotIterable(span).into());
+                };
+                frames.push(Frame::ForOf {
+                    statement,
+                    iterator,
+                    labels,
+                    environment,
+                });
+            }
+            Statement::SwitchStatement(statement) => {
+                let expressions = std::iter::once(&statement.discriminant)
+                    .chain(statement.cases.iter().filter_map(|case| case.test.as_ref()))
+                    .collect::<StdVec<_>>();
+                let next = self.replay(&expressions, || {
+                    self.find_switch_case(statement, Rc::clone(&environment))
+                })?;
+                let next = match next {
+                    ControlFlow::Continue(next) => next,
+                    ControlFlow::Break(value) => return Ok(Flow::Suspend(value)),
+                };
+                if let Some(next) = next {
+                    frames.push(Frame::Switch {
+                        cases: statement.cases.as_slice(),
+                        next,
+                        labels,
+                        environment,
+                    });
+                }
+            }
+            statement => {
+                if !labels.is_empty() {
+                    frames.push(Frame::Label { labels });
+                }
+                frames.push(Frame::body(statement, environment));
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Runs `evaluate`, which evaluates `expressions` of a statement of the running generator,
+    /// breaking with what the statement yielded or awaited if it suspended. See [`Replay`].
+    fn replay<T>(
+        &self,
+        expressions: &[&Expression<'a>],
+        evaluate: impl FnOnce() -> Result<T>,
+    ) -> Result<ControlFlow<Primitive<'a>, T>> {
+        if let Some(replay) = self.replays.borrow_mut().last_mut() {
+            if replay.kept.is_empty() {
+                expressions
+                    .iter()
+                    .for_each(|expression| replay.keep(expression));
+            }
+        }
+        let result = evaluate();
+        let mut replays = self.replays.borrow_mut();
+        let Some(replay) = replays.last_mut() else {
+            return result.map(ControlFlow::Continue);
+        };
+        if let Some(value) = replay.suspended.take() {
+            return Ok(ControlFlow::Break(value));
+        }
+        *replay = Replay::default();
+        result.map(ControlFlow::Continue)
+    }
+
+    /// Whether the statement the running generator replays keeps the value of `node`.
+    pub(crate) fn keeps(&self, node: usize) -> bool {
+        self.replays
+            .borrow()
+            .last()
+            .is_some_and(|replay| replay.kept.contains(&node))
+    }
+
+    /// The value a kept expression evaluated to before the statement suspended.
+    pub(crate) fn replayed_value(&self, node: usize) -> Option<Primitive<'a>> {
+        let replays = self.replays.borrow();
+        replays.last()?.values.get(&node).cloned()
+    }
+
+    pub(crate) fn keep_value(&self, node: usize, value: &Primitive<'a>) {
+        if let Some(replay) = self.replays.borrow_mut().last_mut() {
+            replay.values.insert(node, value.clone());
+        }
+    }
+
+    /// Suspends the statement the running generator evaluates, the error unwinds its
+    /// evaluation and [`Runtime::replay`] tells it apart from other errors.
+    fn suspend(
+        &self,
+        node: usize,
+        value: Primitive<'a>,
+        error: impl Into<wave_diagnostics::Error>,
+    ) -> wave_diagnostics::Error {
+        if let Some(replay) = self.replays.borrow_mut().last_mut() {
+            replay.waiting = Some(node);
+            replay.suspended = Some(value);
+        }
+        error.into()
+    }
+
+    /// A `yield` suspends the generator with its argument, and evaluates to the value sent by
+    /// the `next(value)` resuming it.
+    pub(crate) fn eval_yield_expression(
+        &self,
+        expression: &YieldExpression<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        let node = expression as *const YieldExpression as usize;
+        if !self.keeps(node) {
+            return Err(diagnostics::UnsupportedYield(expression.span).into());
+        }
+        if expression.delegate {
+            return self.eval_delegate(node, expression, environment);
+        }
+        let value = match &expression.argument {
+            Some(argument) => self.eval_expression(argument, environment)?,
+            None => Primitive::Null,
+        };
+        let error = diagnostics::UnsupportedYield(expression.span);
+        Err(self.suspend(node, value, error))
+    }
+
+    /// A `yield*` forwards every value of the inner iterator, and evaluates to the value the
+    /// inner generator returns.
+    fn eval_delegate(
+        &self,
+        node: usize,
+        expression: &YieldExpression<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        let span = expression.span;
+        let (iterator, sent) = match self.replays.borrow_mut().last_mut() {
+            Some(replay) => (replay.delegates.remove(&node), replay.sent.take()),
+            None => (None, None),
+        };
+        let mut iterator = match iterator {
+            Some(iterator) => iterator,
+            None => {
+                let value = match &expression.argument {
+                    Some(argument) => self.eval_expression(argument, environment)?,
+                    None => Primitive::Null,
+                };
+                let Some(iterator) = self.get_iterator(value, span)? else {
+                    return Err(diagnostics::NotIterable(span).into());
+                };
+                iterator
+            }
+        };
+        match self.delegate_next(&mut iterator, sent.unwrap_or(Primitive::Null), span)? {
+            GeneratorStep::Yield(value) => {
+                if let Some(replay) = self.replays.borrow_mut().last_mut() {
+                    replay.delegates.insert(node, iterator);
+                }
+                Err(self.suspend(node, value, diagnostics::UnsupportedYield(span)))
+            }
+            GeneratorStep::Return(value) => Ok(value),
+        }
+    }
+
+    /// An `await` suspends the async function until the promise settles, and evaluates to the
+    /// value it fulfilled with.
+    pub(crate) fn eval_await_expression(
+        &self,
+        expression: &AwaitExpression<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        let node = expression as *const AwaitExpression as usize;
+        if !self.keeps(node) {
+            return Err(diagnostics::UnsupportedAwait(expression.span).into());
+        }
+        let value = self.eval_expression(&expression.argument, environment)?;
+        let error = diagnostics::UnsupportedAwait(expression.span);
+        Err(self.suspend(node, value, error))
+    }
+
+    /// The next step of the iterator of a `yield*`, generators receive the value sent to the
+    /// outer generator.
+    fn delegate_next(
+        &self,
+        iterator: &mut ValueIterator<'a>,
+        sent: Primitive<'a>,
+        span: Span,
+    ) -> Result<GeneratorStep<'a>> {
+        if let ValueIterator::Generator(generator) = iterator {
+            return self.resume_generator(&Rc::clone(generator), sent, span);
+        }
+        Ok(match self.iterator_next(iterator, span)? {
+            Some(value) => GeneratorStep::Yield(value),
+            None => GeneratorStep::Return(Primitive::Null),
+        })
+    }
+
+    /// Pops the frames a `break` or `continue` jumps out of.
+    fn unwind(frames: &mut StdVec<Frame<'a>>, jump: &Primitive<'a>) {
+        while let Some(frame) = frames.last() {
+            let is_loop = matches!(frame, Frame::While { .. } | Frame::ForOf { .. });
+            let is_switch = matches!(frame, Frame::Switch { .. });
+            let targeted = match jump {
+                Primitive::Break(Some(label)) | Primitive::Continue(Some(label)) => {
+                    frame.labels().contains(label)
+                }
+                _ => false,
+            };
+            match jump {
+                Primitive::Break(None) if is_loop || is_switch => {
+                    frames.pop();
+                    return;
+                }
+                Primitive::Break(Some(_)) if targeted => {
+                    frames.pop();
+                    return;
+                }
+                Primitive::Continue(None) if is_loop => return,
+                Primitive::Continue(Some(_)) if is_loop && targeted => return,
+                _ => {
+                    frames.pop();
+                }
+            }
+        }
+    }
+
+    /// `next(value)` and `return(value)` of a generator.
+    pub fn get_generator_method(
+        &self,
+        generator: Primitive<'a>,
+        name: Atom,
+        span: Span,
+    ) -> Result<Primitive<'a>> {
+        let function = match name.as_str() {
+            "next" => generator_next,
+            "return" => generator_return,
+            "iterator" => generator_iterator,
+            _ => return Err(diagnostics::CannotAccessProperty(span).into()),
+        };
+        Ok(Primitive::NativeFunction(NativeFunction::method(
+            name, generator, function,
+        )))
+    }
+}
+
+fn generator_next<'a>(
+    runtime: &Runtime<'a>,
+    generator: &Primitive<'a>,
+    arguments: StdVec<Primitive<'a>>,
+    span: Span,
+) -> Result<Primitive<'a>> {
+    let Primitive::Generator(generator) = generator else {
+        unreachable!()
+    };
+    let sent = arguments.into_iter().next().unwrap_or(Primitive::Null);
+    Ok(match runtime.resume_generator(generator, sent, span)? {
+        GeneratorStep::Yield(value) => runtime.iterator_result(value, false),
+        GeneratorStep::Return(value) => runtime.iterator_result(value, true),
+    })
+}
+
+/// Finishes the generator without running the rest of its body.
+fn generator_return<'a>(
+    runtime: &Runtime<'a>,
+    generator: &Primitive<'a>,
+    arguments: StdVec<Primitive<'a>>,
+    span: Span,
+) -> Result<Primitive<'a>> {
+    let Primitive::Generator(generator) = generator else {
+        unreachable!()
+    };
+    if generator.borrow().state == GeneratorState::Running {
+        return Err(diagnostics::GeneratorAlreadyRunning(span).into());
+    }
+    generator.borrow_mut().complete();
+    let value = arguments.into_iter().next().unwrap_or(Primitive::Null);
+    Ok(runtime.iterator_result(value, true))
+}
+
+/// Generators are their own iterator, so a class can return one from `iterator()`.
+fn generator_iterator<'a>(
+    _: &Runtime<'a>,
+    generator: &Primitive<'a>,
+    _: StdVec<Primitive<'a>>,
+    _: Span,
+) -> Result<Primitive<'a>> {
+    Ok(generator.clone())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::eval;
+
+    #[test]
+    fn generators() {
+        let counter = "function* count(n) {
+                let i = 0;
+                while (true) {
+                    if (i == n) { return \"done\"; }
+                    yield i;
+                    i = i + 1;
+                }
+            }";
+        let run = |code: &str| eval(&format!("{counter} {code}")).unwrap();
+        assert_eq!(run("[...count(4)];"), "[0, 1, 2, 3]");
+        assert_eq!(
+            run("let g = count(1); let a = g.next(); let b = g.next(); [a.value, a.done, b.value, b.done];"),
+            "[0, false, done, true]"
+        );
+        assert_eq!(run("let g = count(5); g.next(); g.return(7).value;"), "7");
+        assert_eq!(
+            run("function* all() { yield* count(2); yield* [\"a\", \"b\"]; } [...all()];"),
+            "[0, 1, a, b]"
+        );
+    }
+
+    #[test]
+    fn sent_values_and_classes() {
+        let echo = "function* echo() {
+                let total = 0;
+                while (true) { let value = yield total; total = total + value; }
+            }
+            let g = echo(); g.next(); g.next(2); g.next(3).value;";
+        assert_eq!(eval(echo).unwrap(), "5");
+
+        let tree = "class Pair {
+                constructor(a, b) { this.a = a; this.b = b; }
+                *iterator() { yield this.a; yield this.b; }
+            }
+            let out = [];
+            for (const x of new Pair(1, 2)) { for (const y of new Pair(x, 0)) { out = [...out, y]; } }
+            out;";
+        assert_eq!(eval(tree).unwrap(), "[1, 0, 2, 0]");
+
+        let labeled = "function* g() {
+                outer: for (let i of [1, 2, 3]) {
+                    switch (i) { case 2: continue outer; default: yield i; }
+                }
+            }
+            [...g()];";
+        assert_eq!(eval(labeled).unwrap(), "[1, 3]");
+    }
+
+    #[test]
+    fn yield_in_expressions() {
+        let calls = "let calls = 0;
+            function count() { calls = calls + 1; return calls; }
+            function pair(a, b) { return [a, b]; }
+            function* g() {
+                let a = 0;
+                a = yield 1;
+                let b = pair(count(), yield a);
+                if ((yield b) == 0) { return \"zero\"; }
+            }
+            let it = g();
+            [it.next().value, it.next(10).value, it.next(20).value, it.next(0).value, calls];";
+        // the call before the `yield` is not repeated when the generator resumes
+        assert_eq!(eval(calls).unwrap(), "[1, 10, [1, 20], zero, 1]");
+
+        let delegate = "function* inner() { yield 1; return 2; }
+            function* outer() {
+                let total = (yield* inner()) + (yield 3);
+                return yield total;
+            }
+            let it = outer();
+            [it.next().value, it.next().value, it.next(4).value, it.next(5).value, it.next().done];";
+        assert_eq!(eval(delegate).unwrap(), "[1, 3, 6, 5, true]");
+
+        let test = "function* g() {
+                let n = 0;
+                while ((yield n) != \"stop\") { n = n + 1; }
+                return n;
+            }
+            let it = g(); it.next(); it.next(\"go\"); it.next(\"go\"); it.next(\"stop\").value;";
+        assert_eq!(eval(test).unwrap(), "2");
+    }
+}
@@ -0,0 +1,294 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::vec::Vec as StdVec;
+
+use crate::evaluator::collection::map_entries;
+use crate::evaluator::function::NativeFunction;
+use crate::evaluator::generator::{Generator, GeneratorStep};
+use crate::evaluator::Primitive;
+use crate::{diagnostics, environment::Environment, Runtime};
+use wave_allocator::Box;
+use wave_ast::ast::ForOfStatement;
+use wave_diagnostics::Result;
+use wave_span::{Atom, GetSpan, Span};
+
+/// The values `for-of`, spread and array destructuring walk through.
+pub enum ValueIterator<'a> {
+    /// Arrays and strings are iterated natively, strings yield their characters
+    Values(std::vec::IntoIter<Primitive<'a>>),
+    /// The object returned by the `iterator()` method of an instance, each call to its
+    /// `next()` returns a step with a boolean `done` and, until done, a `value`
+    Protocol(Primitive<'a>),
+    /// Generators are resumed directly rather than through their `next()`
+    Generator(Rc<RefCell<Generator<'a>>>),
+}
+
+impl<'a> Runtime<'a> {
+    /// Starts iterating `value`, `None` when it does not implement the iterator protocol.
+    pub fn get_iterator(
+        &self,
+        value: Primitive<'a>,
+        span: Span,
+    ) -> Result<Option<ValueIterator<'a>>> {
+        match value {
+            Primitive::Array(values) => Ok(Some(ValueIterator::Values(values.into_iter()))),
+            Primitive::Generator(generator) => Ok(Some(ValueIterator::Generator(generator))),
+            // Maps iterate their `[key, value]` entries, both see the entries of when iteration started
+            Primitive::Map(map) => Ok(Some(ValueIterator::Values(
+                map_entries(&map.borrow()).into_iter(),
+            ))),
+            Primitive::Set(set) => {
+                let values = set
+                    .borrow()
+                    .entries()
+                    .map(|(value, _)| value.clone())
+                    .collect::<StdVec<_>>();
+                Ok(Some(ValueIterator::Values(values.into_iter())))
+            }
+            Primitive::String(value) => {
+                let characters = value
+                    .chars()
+                    .map(|character| Primitive::String(character.to_string()))
+                    .collect::<StdVec<_>>();
+                Ok(Some(ValueIterator::Values(characters.into_iter())))
+            }
+            Primitive::Instance(env) | Primitive::This(env) => {
+                let Some((method, home)) =
+                    self.find_member(&env, &self.bind_this("iterator".into()))
+                else {
+                    return Ok(None);
+                };
+                let method = self.bind_method(method, &env, &home, span)?;
+                match self.apply_function(method, vec![], span)? {
+                    Primitive::Generator(generator) => {
+                        Ok(Some(ValueIterator::Generator(generator)))
+                    }
+                    iterator => Ok(Some(ValueIterator::Protocol(iterator))),
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// The next value of `iterator`, `None` once it is done.
+    pub fn iterator_next(
+        &self,
+        iterator: &mut ValueIterator<'a>,
+        span: Span,
+    ) -> Result<Option<Primitive<'a>>> {
+        match iterator {
+            ValueIterator::Values(values) => Ok(values.next()),
+            ValueIterator::Generator(generator) => {
+                match self.resume_generator(generator, Primitive::Null, span)? {
+                    GeneratorStep::Yield(value) => Ok(Some(value)),
+                    GeneratorStep::Return(_) => Ok(None),
+                }
+            }
+            ValueIterator::Protocol(iterator) => {
+                let next = self.read_property(iterator.clone(), "next".into(), span)?;
+                let step = self.apply_function(next, vec![], span)?;
+                match self.read_property(step.clone(), "done".into(), span) {
+                    Ok(Primitive::Boolean(true)) => Ok(None),
+                    Ok(Primitive::Boolean(false)) => {
+                        self.read_property(step, "value".into(), span).map(Some)
+                    }
+                    _ => Err(diagnostics::InvalidIteratorResult(span).into()),
+                }
+            }
+        }
+    }
+
+    /// Collects every value of an iterable, `None` when `value` is not iterable.
+    pub fn collect_iterable(
+        &self,
+        value: Primitive<'a>,
+        span: Span,
+    ) -> Result<Option<StdVec<Primitive<'a>>>> {
+        let Some(mut iterator) = self.get_iterator(value, span)? else {
+            return Ok(None);
+        };
+        let mut values = vec![];
+        while let Some(value) = self.iterator_next(&mut iterator, span)? {
+            values.push(value);
+        }
+        Ok(Some(values))
+    }
+
+    /// Every iteration binds the value in a new scope, so closures capture their own value.
+    /// `labels` are the labels of the loop, which `continue` may target.
+    pub fn eval_for_of_statement(
+        &self,
+        for_of: &Box<'_, ForOfStatement<'a>>,
+        labels: &[Atom],
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        let span = for_of.right.span();
+        let iterable = self.eval_expression(&for_of.right, Rc::clone(&environment))?;
+        let Some(mut iterator) = self.get_iterator(iterable, span)? else {
+            return Err(diagnostics::NotIterable(span).into());
+        };
+
+        while let Some(value) = self.iterator_next(&mut iterator, span)? {
+            let iteration_env =
+                self.alloc_environment(Environment::extend(Rc::clone(&environment)));
+            for declarator in &for_of.left.declarations {
+                self.bind_pattern(&declarator.id, value.clone(), Rc::clone(&iteration_env))?;
+            }
+            match self.eval_statement(&for_of.body, iteration_env)? {
+                Primitive::Break(None) => return Ok(Primitive::Null),
+                Primitive::Continue(Some(label)) if labels.contains(&label) => {}
+                result @ (Primitive::Return(_)
+                | Primitive::Break(_)
+                | Primitive::Continue(Some(_))) => return Ok(result),
+                _ => {}
+            }
+        }
+        Ok(Primitive::Null)
+    }
+
+    /// The `iterator()` method of arrays and strings, its object steps through the values
+    /// `for-of` sees with `next()`, like the iterator objects of classes.
+    pub fn get_sequence_method(
+        &self,
+        object: Primitive<'a>,
+        name: Atom,
+        span: Span,
+    ) -> Result<Primitive<'a>> {
+        if name != "iterator" {
+            return Err(diagnostics::CannotAccessProperty(span).into());
+        }
+        let function = NativeFunction::method(name, object, |runtime, object, _, span| {
+            let Some(ValueIterator::Values(values)) = runtime.get_iterator(object.clone(), span)?
+            else {
+                unreachable!()
+            };
+            let values = RefCell::new(values);
+            let next = NativeFunction::new("next", move |runtime, _, _| {
+                Ok(match values.borrow_mut().next() {
+                    Some(value) => runtime.iterator_result(value, false),
+                    None => runtime.iterator_result(Primitive::Null, true),
+                })
+            });
+            let mut iterator = Environment::default();
+            iterator.define(
+                runtime.bind_this("next".into()),
+                Primitive::NativeFunction(next),
+            );
+            Ok(Primitive::Instance(runtime.alloc_environment(iterator)))
+        });
+        Ok(Primitive::NativeFunction(function))
+    }
+
+    /// The `{ value, done }` step returned by `next()`.
+    pub(crate) fn iterator_result(&self, value: Primitive<'a>, done: bool) -> Primitive<'a> {
+        let mut step = Environment::default();
+        step.define(self.bind_this("value".into()), value);
+        step.define(self.bind_this("done".into()), Primitive::Boolean(done));
+        Primitive::Instance(self.alloc_environment(step))
+    }
+}
+
+/// `Array.from(iterable, mapper)`, the optional mapper is called with each value and its index.
+pub(crate) fn array_from<'a>(
+    runtime: &Runtime<'a>,
+    arguments: StdVec<Primitive<'a>>,
+    span: Span,
+) -> Result<Primitive<'a>> {
+    let mut arguments = arguments.into_iter();
+    let iterable = arguments.next().unwrap_or(Primitive::Null);
+    let Some(values) = runtime.collect_iterable(iterable, span)? else {
+        return Err(diagnostics::NotIterable(span).into());
+    };
+    let Some(mapper) = arguments.next() else {
+        return Ok(Primitive::Array(values));
+    };
+    let mut mapped = StdVec::with_capacity(values.len());
+    for (index, value) in values.into_iter().enumerate() {
+        let arguments = vec![value, Primitive::Number(index as f64)];
+        mapped.push(runtime.apply_function(mapper.clone(), arguments, span)?);
+    }
+    Ok(Primitive::Array(mapped))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::eval;
+
+    const RANGE: &str =
+        "class Step { constructor(done, value) { this.done = done; this.value = value; } }
+        class RangeIterator {
+            constructor(start, end) { this.at = start; this.end = end; }
+            next() {
+                if (this.at >= this.end) { return new Step(true, null); }
+                this.at = this.at + 1;
+                return new Step(false, this.at - 1);
+            }
+        }
+        class Range {
+            constructor(start, end) { this.start = start; this.end = end; }
+            iterator() { return new RangeIterator(this.start, this.end); }
+        }";
+
+    #[test]
+    fn iterator_protocol() {
+        let run = |code: &str| eval(&format!("{RANGE} {code}")).unwrap();
+        assert_eq!(
+            run("let sum = 0; for (const i of new Range(1, 5)) { sum = sum + i; } sum;"),
+            "10"
+        );
+        assert_eq!(run("[0, ...new Range(1, 3)];"), "[0, 1, 2]");
+        assert_eq!(
+            run("let [a, , c, ...rest] = new Range(0, 6); [a, c, rest];"),
+            "[0, 2, [3, 4, 5]]"
+        );
+        assert_eq!(
+            run("Array.from(new Range(1, 4), function (v, i) { return v * i; });"),
+            "[0, 2, 6]"
+        );
+    }
+
+    #[test]
+    fn for_of_native_values() {
+        assert_eq!(
+            eval("let out = []; for (let [k, v] of [[1, 2], [3, 4]]) { out = [...out, k + v]; } out;")
+                .unwrap(),
+            "[3, 7]"
+        );
+        assert_eq!(eval("Array.from(\"wave\");").unwrap(), "[w, a, v, e]");
+        assert_eq!(
+            eval("function f(Array) { return Array.length; } f([1, 2]);").unwrap(),
+            "2"
+        );
+        let labeled = "let n = 0;
+            outer: for (let i of [1, 2, 3]) {
+                for (let j of [1, 2, 3]) {
+                    if (j == 2) { continue outer; }
+                    if (i == 3) { break outer; }
+                    n = n + 1;
+                }
+            }
+            n;";
+        assert_eq!(eval(labeled).unwrap(), "2");
+        assert_eq!(
+            eval("for (let x of 1) {}").unwrap_err().to_string(),
+            "Value is not iterable."
+        );
+    }
+
+    #[test]
+    fn iterator_method_of_values() {
+        let next = "function steps(iterator) {
+                let out = [];
+                let step = iterator.next();
+                while (!step.done) { out = [...out, step.value]; step = iterator.next(); }
+                return out;
+            }";
+        let run = |code: &str| eval(&format!("{next} {code}")).unwrap();
+        assert_eq!(run("steps([1, 2].iterator());"), "[1, 2]");
+        assert_eq!(run("steps(\"ab\".iterator());"), "[a, b]");
+        assert_eq!(
+            run("let it = [].iterator(); [it.next().done, it.next().done];"),
+            "[true, true]"
+        );
+    }
+}
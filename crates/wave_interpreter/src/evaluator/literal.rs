@@ -1,7 +1,13 @@
+use std::cell::RefCell;
+use std::fmt::Write;
+use std::rc::Rc;
+
+use crate::environment::Environment;
 use crate::evaluator::Primitive;
 use crate::Runtime;
 use wave_allocator::Box;
-use wave_ast::{BooleanLiteral, NumberLiteral, StringLiteral};
+use wave_ast::ast::TemplateLiteral;
+use wave_ast::{BigIntLiteral, BooleanLiteral, NumberLiteral, StringLiteral};
 use wave_diagnostics::Result;
 
 impl<'a> Runtime<'a> {
@@ -19,10 +25,47 @@ impl<'a> Runtime<'a> {
         Ok(Primitive::Number(expression.value))
     }
 
+    pub fn eval_bigint_literal(
+        &self,
+        expression: &Box<'_, BigIntLiteral>,
+    ) -> Result<Primitive<'a>> {
+        Ok(Primitive::BigInt(expression.value.clone()))
+    }
+
     pub fn eval_string_literal(
         &self,
         expression: &Box<'_, StringLiteral>,
     ) -> Result<Primitive<'a>> {
         Ok(Primitive::String(expression.value.to_string()))
     }
+
+    /// Substitutions are formatted the way `print` shows them.
+    pub fn eval_template_literal(
+        &self,
+        expression: &Box<'_, TemplateLiteral<'a>>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        let mut value = String::new();
+        for (index, quasi) in expression.quasis.iter().enumerate() {
+            value.push_str(&quasi.value.cooked);
+            if let Some(substitution) = expression.expressions.get(index) {
+                let substitution = self.eval_expression(substitution, Rc::clone(&environment))?;
+                write!(value, "{:?}", substitution).unwrap();
+            }
+        }
+        Ok(Primitive::String(value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::eval;
+
+    #[test]
+    fn template_literals() {
+        let source = "let name = \"wave\"; let n = 2;
+            `hi ${name}, ${n + 1} \\${n} \\u0041 ${[1, `${n}`]} ${`a${`b${n}`}`}`;";
+        assert_eq!(eval(source).unwrap(), "hi wave, 3 ${n} A [1, 2] ab2");
+        assert_eq!(eval("`${1n}${null}${true}`;").unwrap(), "1nNulltrue");
+    }
 }
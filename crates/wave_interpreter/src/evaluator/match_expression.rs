@@ -0,0 +1,169 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::environment::Environment;
+use crate::evaluator::Primitive;
+use crate::{diagnostics, Runtime};
+use wave_allocator::Box;
+use wave_ast::ast::{MatchExpression, MatchPattern};
+use wave_diagnostics::Result;
+use wave_span::GetSpan;
+
+impl<'a> Runtime<'a> {
+    /// Evaluates the body of the first arm whose pattern and guard match.
+    pub fn eval_match_expression(
+        &self,
+        expression: &Box<'_, MatchExpression<'a>>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        let value = self.eval_expression(&expression.discriminant, Rc::clone(&environment))?;
+
+        for arm in &expression.arms {
+            let arm_env = self.alloc_environment(Environment::extend(Rc::clone(&environment)));
+            if !self.match_pattern(&arm.pattern, &value, Rc::clone(&arm_env))? {
+                continue;
+            }
+            if let Some(guard) = &arm.guard {
+                if !self
+                    .eval_expression(guard, Rc::clone(&arm_env))?
+                    .is_truthy()
+                {
+                    continue;
+                }
+            }
+            return self.eval_expression(&arm.body, arm_env);
+        }
+
+        Err(diagnostics::NoMatchingArm(format!("{value:?}"), expression.span).into())
+    }
+
+    /// Binds the identifiers of `pattern` in `environment` if it matches `value`.
+    fn match_pattern(
+        &self,
+        pattern: &MatchPattern<'a>,
+        value: &Primitive<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<bool> {
+        match pattern {
+            MatchPattern::Literal(literal) => {
+                let span = literal.span();
+                let literal = self.eval_expression(literal, environment)?;
+                self.equals(value, &literal, span)
+            }
+            MatchPattern::Identifier(identifier) => {
+                environment
+                    .borrow_mut()
+                    .define(identifier.name.to_owned(), value.clone());
+                Ok(true)
+            }
+            MatchPattern::Array(pattern) => {
+                let Primitive::Array(values) = value else {
+                    return Ok(false);
+                };
+                let length_matches = match pattern.rest {
+                    Some(_) => values.len() >= pattern.elements.len(),
+                    None => values.len() == pattern.elements.len(),
+                };
+                if !length_matches {
+                    return Ok(false);
+                }
+
+                for (element, value) in pattern.elements.iter().zip(values) {
+                    if !self.match_pattern(element, value, Rc::clone(&environment))? {
+                        return Ok(false);
+                    }
+                }
+                if let Some(rest) = &pattern.rest {
+                    let rest_values = values[pattern.elements.len()..].to_vec();
+                    environment
+                        .borrow_mut()
+                        .define(rest.name.to_owned(), Primitive::Array(rest_values));
+                }
+                Ok(true)
+            }
+            MatchPattern::Instance(pattern) => {
+                let (Primitive::Instance(instance) | Primitive::This(instance)) = value else {
+                    return Ok(false);
+                };
+                let class = environment
+                    .borrow()
+                    .get(pattern.class.name.to_owned(), pattern.class.span)?;
+                let Primitive::Class(class) = class else {
+                    return Err(diagnostics::InvalidMatchClass(pattern.class.span).into());
+                };
+                if !self.is_instance_of(instance, &class) {
+                    return Ok(false);
+                }
+
+                for property in &pattern.properties {
+                    let field = self.get_property(
+                        Rc::clone(instance),
+                        property.key.name.to_owned(),
+                        property.span,
+                    );
+                    let Ok(field) = field else {
+                        return Ok(false);
+                    };
+                    if !self.match_pattern(&property.value, &field, Rc::clone(&environment))? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::eval;
+
+    #[test]
+    fn switch_falls_through_until_break() {
+        let switch = |value: &str| {
+            eval(&format!(
+                "let out = []; switch ({value}) {{ case 1: out = [...out, 1]; case 2: out = [...out, 2]; break; default: out = [...out, 0]; }} out;"
+            ))
+            .unwrap()
+        };
+        assert_eq!(switch("1"), "[1, 2]");
+        assert_eq!(switch("2"), "[2]");
+        assert_eq!(switch("3"), "[0]");
+    }
+
+    #[test]
+    fn match_patterns() {
+        let source = "class Point { x = 0; y = 0; constructor(x, y) { this.x = x; this.y = y; } }
+            function f(v) {
+                return match (v) {
+                    0 => \"zero\",
+                    [a, ...rest] => rest,
+                    Point { x: 0, y } => y,
+                    n if n > 10 => \"big\",
+                    _ => \"other\"
+                };
+            }";
+        let call = |args: &str| eval(&format!("{source} f({args});")).unwrap();
+        assert_eq!(call("0"), "zero");
+        assert_eq!(call("[1, 2, 3]"), "[2, 3]");
+        assert_eq!(call("new Point(0, 7)"), "7");
+        assert_eq!(call("11"), "big");
+        assert_eq!(call("5"), "other");
+
+        let error = eval("match (3) { 1 => 1 };").unwrap_err().to_string();
+        assert!(error.starts_with("No match arm"), "{error}");
+    }
+
+    #[test]
+    fn match_is_contextual() {
+        let source = "let match = 3;
+            function f(x) { return [x]; }
+            let g = f;
+            [match (match) { 3 => match + 1, _ => 0 }, g(match)];";
+        assert_eq!(eval(source).unwrap(), "[4, [3]]");
+        assert_eq!(
+            eval("function match(x) { return x * 2; } match(4);").unwrap(),
+            "8"
+        );
+    }
+}
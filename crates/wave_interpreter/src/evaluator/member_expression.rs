@@ -1,11 +1,12 @@
-use std::{cell::RefCell, ptr, rc::Rc};
+use std::{cell::RefCell, rc::Rc};
 
-use wave_ast::ast::MemberExpression;
+use wave_ast::ast::{CallExpression, ChainElement, ChainExpression, Expression, MemberExpression};
 
 use super::Primitive;
 use crate::{diagnostics, environment::Environment, Runtime};
 use wave_allocator::Box;
 use wave_diagnostics::Result;
+use wave_span::{Atom, GetSpan, Span};
 
 impl<'a> Runtime<'a> {
     pub fn eval_member_expression(
@@ -13,80 +14,320 @@ impl<'a> Runtime<'a> {
         expression: &Box<'_, MemberExpression<'a>>,
         environment: Rc<RefCell<Environment<'a>>>,
     ) -> Result<Primitive<'a>> {
-        unsafe {
-            let expression = ptr::read(expression).unbox();
-
-            match expression {
-                MemberExpression::StaticMemberExpression(expression) => {
-                    let primitive =
-                        self.eval_expression(&expression.object, Rc::clone(&environment))?;
-
-                    match primitive {
-                        Primitive::Instance(env) | Primitive::This(env) => {
-                            let property_name = expression.property.name;
-                            let property_name = self.bind_this(property_name);
-                            let property = env.borrow().get(property_name.clone(), expression.span);
-                            let property = match property {
-                                Ok(property) => property,
-                                Err(_) => {
-                                    let parent =
-                                        self.get_parent_class(expression.span, Rc::clone(&env))?;
-
-                                    match parent {
-                                        Primitive::Class(parent_class) => parent_class
-                                            .borrow()
-                                            .get(property_name, expression.span)?,
-                                        _ => unreachable!(),
-                                    }
-                                }
-                            };
-
-                            match property {
-                                Primitive::Number(_)
-                                | Primitive::String(_)
-                                | Primitive::Boolean(_)
-                                | Primitive::Array(_)
-                                | Primitive::Null => Ok(property),
-                                Primitive::Function(params, body, _) => {
-                                    Ok(Primitive::Function(params, body, Rc::clone(&env)))
-                                }
-                                _ => Err(diagnostics::CannotAccessProperty(expression.span).into()),
-                            }
-                        }
-                        Primitive::Array(array) => {
-                            let property_name = expression.property.name;
+        if let Expression::Super(_) = expression.object() {
+            return self.eval_super_member(expression, environment);
+        }
+        let object = self.eval_expression(expression.object(), Rc::clone(&environment))?;
+        self.eval_member_of(object, expression, environment)
+    }
 
-                            match property_name.as_str() {
-                                "length" => Ok(Primitive::Number(array.len() as f64)),
-                                _ => Err(diagnostics::CannotAccessProperty(expression.span).into()),
-                            }
-                        }
-                        _ => todo!(),
-                    }
+    /// `super.name` reads from the parent class of the running method, with `this` unchanged.
+    fn eval_super_member(
+        &self,
+        expression: &MemberExpression<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        let MemberExpression::StaticMemberExpression(member) = expression else {
+            return Err(diagnostics::CannotAccessProperty(expression.span()).into());
+        };
+        let parent_class = self.get_parent_class(member.span, Rc::clone(&environment))?;
+        self.get_member(
+            &parent_class,
+            &environment,
+            member.property.name.clone(),
+            member.span,
+        )
+    }
+
+    /// Looks up the property of `expression` on an already evaluated `object`.
+    fn eval_member_of(
+        &self,
+        object: Primitive<'a>,
+        expression: &MemberExpression<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        match expression {
+            MemberExpression::StaticMemberExpression(expression) => match object {
+                Primitive::Array(array) if expression.property.name == "length" => {
+                    Ok(Primitive::Number(array.len() as f64))
+                }
+                object => {
+                    self.read_property(object, expression.property.name.clone(), expression.span)
                 }
-                MemberExpression::ComputedMemberExpression(computed_expression) => {
-                    let array =
-                        self.eval_expression(&computed_expression.object, Rc::clone(&environment))?;
-                    let index = self.eval_expression(
-                        &computed_expression.expression,
-                        Rc::clone(&environment),
-                    )?;
-
-                    match (array, index) {
-                        (Primitive::Array(array), Primitive::Number(index)) => {
-                            let index = index as usize;
-                            if index >= array.len() {
-                                return Err(diagnostics::IndexOutOfBounds(
-                                    computed_expression.span,
-                                )
-                                .into());
-                            }
-                            Ok(array[index].clone())
+            },
+            MemberExpression::PrivateFieldExpression(expression) => {
+                let name = self.get_private_key(
+                    &object,
+                    &expression.field.name,
+                    &environment,
+                    expression.span,
+                )?;
+                self.read_property(object, name, expression.span)
+            }
+            MemberExpression::ComputedMemberExpression(computed_expression) => {
+                let index = self.eval_expression(&computed_expression.expression, environment)?;
+
+                match (object, index) {
+                    (Primitive::Array(array), Primitive::Number(index)) => {
+                        let index = index as usize;
+                        if index >= array.len() {
+                            return Err(
+                                diagnostics::IndexOutOfBounds(computed_expression.span).into()
+                            );
                         }
-                        _ => Err(diagnostics::InvalidArrayAccess(computed_expression.span).into()),
+                        Ok(array[index].clone())
                     }
+                    _ => Err(diagnostics::InvalidArrayAccess(computed_expression.span).into()),
                 }
             }
         }
     }
+
+    /// Reads a named property of an instance, or a static member of a class.
+    pub fn read_property(
+        &self,
+        object: Primitive<'a>,
+        name: Atom,
+        span: Span,
+    ) -> Result<Primitive<'a>> {
+        match object {
+            Primitive::Instance(env) | Primitive::This(env) => self.get_property(env, name, span),
+            Primitive::Class(env) => match self.get_static_env(&env) {
+                Some(static_env) => self.get_property(static_env, name, span),
+                None => Err(diagnostics::CannotAccessProperty(span).into()),
+            },
+            Primitive::Generator(_) => self.get_generator_method(object, name, span),
+            Primitive::Promise(_) => self.get_promise_method(object, name, span),
+            Primitive::Map(_) | Primitive::Set(_) => self.get_collection_member(object, name, span),
+            Primitive::Array(_) | Primitive::String(_) => {
+                self.get_sequence_method(object, name, span)
+            }
+            _ => Err(diagnostics::CannotAccessProperty(span).into()),
+        }
+    }
+
+    /// Assigns a named property of an instance, or a static member of a class.
+    pub fn assign_property(
+        &self,
+        object: Primitive<'a>,
+        name: Atom,
+        value: Primitive<'a>,
+        span: Span,
+    ) -> Result<()> {
+        match object {
+            Primitive::Instance(env) | Primitive::This(env) => {
+                self.set_property(env, name, value, span)
+            }
+            Primitive::Class(env) => match self.get_static_env(&env) {
+                Some(static_env) => self.set_property(static_env, name, value, span),
+                None => Err(diagnostics::CannotAccessProperty(span).into()),
+            },
+            _ => Err(diagnostics::CannotAccessProperty(span).into()),
+        }
+    }
+
+    /// Resolves a property of an instance through the chain of its classes.
+    /// A getter is called with `this` bound to the instance.
+    pub fn get_property(
+        &self,
+        env: Rc<RefCell<Environment<'a>>>,
+        name: Atom,
+        span: Span,
+    ) -> Result<Primitive<'a>> {
+        self.get_member(&env, &env, name, span)
+    }
+
+    /// Resolves a property starting the lookup at `start`, binding methods to `this_env`.
+    fn get_member(
+        &self,
+        start: &Rc<RefCell<Environment<'a>>>,
+        this_env: &Rc<RefCell<Environment<'a>>>,
+        name: Atom,
+        span: Span,
+    ) -> Result<Primitive<'a>> {
+        if self.is_internal_property(&name) {
+            return Err(diagnostics::CannotAccessProperty(span).into());
+        }
+        if let Some((property, home)) = self.find_member(start, &self.bind_this(name.clone())) {
+            return self.bind_method(property, this_env, &home, span);
+        }
+        match self.find_member(start, &self.bind_getter(name)) {
+            Some((getter, home)) => {
+                let getter = self.bind_method(getter, this_env, &home, span)?;
+                self.apply_function(getter, vec![], span)
+            }
+            None => Err(diagnostics::VariableNotFound(span).into()),
+        }
+    }
+
+    /// Assigns a property of an instance, calling its setter if there is one.
+    /// Accessors without a setter are read-only.
+    pub fn set_property(
+        &self,
+        env: Rc<RefCell<Environment<'a>>>,
+        name: Atom,
+        value: Primitive<'a>,
+        span: Span,
+    ) -> Result<()> {
+        if self.is_internal_property(&name) {
+            return Err(diagnostics::CannotAccessProperty(span).into());
+        }
+        if let Some((setter, home)) = self.find_member(&env, &self.bind_setter(name.clone())) {
+            let setter = self.bind_method(setter, &env, &home, span)?;
+            self.apply_function(setter, vec![value], span)?;
+            return Ok(());
+        }
+        if self
+            .find_member(&env, &self.bind_getter(name.clone()))
+            .is_some()
+        {
+            return Err(diagnostics::AssignToGetterOnly(span).into());
+        }
+
+        let property_name = self.bind_this(name);
+        let holder = match self.find_member(&env, &property_name) {
+            Some((_, holder)) => holder,
+            None => self.get_receiver(&env),
+        };
+        holder.borrow_mut().define(property_name, value);
+        Ok(())
+    }
+
+    /// Finds a member by walking the scopes of `env`, continuing with the parent class
+    /// after each class, so the nearest override wins.
+    /// Returns the member with the environment it was found in.
+    pub fn find_member(
+        &self,
+        env: &Rc<RefCell<Environment<'a>>>,
+        key: &Atom,
+    ) -> Option<(Primitive<'a>, Rc<RefCell<Environment<'a>>>)> {
+        let mut current = Some(Rc::clone(env));
+        while let Some(env) = current {
+            if let Some(member) = env.borrow().values.get(key) {
+                return Some((member.clone(), Rc::clone(&env)));
+            }
+            current = match self.get_superclass(&env) {
+                Some(parent_env) => Some(parent_env),
+                None => env.borrow().outer.clone(),
+            };
+        }
+        None
+    }
+
+    /// Methods read from an instance are bound to it.
+    pub fn bind_method(
+        &self,
+        property: Primitive<'a>,
+        env: &Rc<RefCell<Environment<'a>>>,
+        home: &Rc<RefCell<Environment<'a>>>,
+        span: Span,
+    ) -> Result<Primitive<'a>> {
+        match property {
+            Primitive::Number(_)
+            | Primitive::BigInt(_)
+            | Primitive::String(_)
+            | Primitive::Boolean(_)
+            | Primitive::Array(_)
+            | Primitive::NativeFunction(_)
+            | Primitive::Generator(_)
+            | Primitive::Promise(_)
+            | Primitive::Map(_)
+            | Primitive::Set(_)
+            | Primitive::Class(_)
+            | Primitive::Instance(_)
+            | Primitive::Null => Ok(property),
+            Primitive::Function(params, body, _) => {
+                let method_env = self.alloc_environment(Environment::extend(Rc::clone(env)));
+                self.bind_home(&method_env, home);
+                Ok(Primitive::Function(params, body, method_env))
+            }
+            Primitive::GeneratorFunction(params, body, _) => {
+                let method_env = self.alloc_environment(Environment::extend(Rc::clone(env)));
+                self.bind_home(&method_env, home);
+                Ok(Primitive::GeneratorFunction(params, body, method_env))
+            }
+            Primitive::AsyncFunction(params, body, _) => {
+                let method_env = self.alloc_environment(Environment::extend(Rc::clone(env)));
+                self.bind_home(&method_env, home);
+                Ok(Primitive::AsyncFunction(params, body, method_env))
+            }
+            _ => Err(diagnostics::CannotAccessProperty(span).into()),
+        }
+    }
+
+    /// Whether reading `name` finds a field, a method or a getter, without calling the getter.
+    pub fn has_property(&self, env: &Rc<RefCell<Environment<'a>>>, name: &Atom) -> bool {
+        self.find_member(env, &self.bind_this(name.clone()))
+            .is_some()
+            || self
+                .find_member(env, &self.bind_getter(name.clone()))
+                .is_some()
+    }
+
+    /// A chain that short-circuits on a `null` receiver evaluates to `null`.
+    pub fn eval_chain_expression(
+        &self,
+        expression: &Box<'_, ChainExpression<'a>>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        let value = match &expression.expression {
+            ChainElement::MemberExpression(member) => {
+                self.eval_chain_member(member, environment)?
+            }
+            ChainElement::CallExpression(call) => self.eval_chain_call(call, environment)?,
+        };
+        Ok(value.unwrap_or(Primitive::Null))
+    }
+
+    /// Evaluates a link of an optional chain, `None` once the chain has short-circuited.
+    fn eval_chain_link(
+        &self,
+        expression: &Expression<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Option<Primitive<'a>>> {
+        match expression {
+            Expression::MemberExpression(member) => self.eval_chain_member(member, environment),
+            Expression::CallExpression(call) => self.eval_chain_call(call, environment),
+            _ => self.eval_expression(expression, environment).map(Some),
+        }
+    }
+
+    fn eval_chain_member(
+        &self,
+        expression: &MemberExpression<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Option<Primitive<'a>>> {
+        let object = match self.eval_chain_link(expression.object(), Rc::clone(&environment))? {
+            Some(Primitive::Null) if expression.optional() => return Ok(None),
+            Some(object) => object,
+            None => return Ok(None),
+        };
+        self.eval_member_of(object, expression, environment)
+            .map(Some)
+    }
+
+    fn eval_chain_call(
+        &self,
+        expression: &Box<'_, CallExpression<'a>>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Option<Primitive<'a>>> {
+        let is_link = matches!(
+            expression.callee,
+            Expression::MemberExpression(_) | Expression::CallExpression(_)
+        );
+        if !is_link && !expression.optional {
+            return self.eval_call_expression(expression, environment).map(Some);
+        }
+
+        let function = match self.eval_chain_link(&expression.callee, Rc::clone(&environment))? {
+            Some(Primitive::Null) if expression.optional => return Ok(None),
+            Some(function) => function,
+            None => return Ok(None),
+        };
+
+        let arguments = self.eval_arguments(&expression.arguments, Rc::clone(&environment))?;
+
+        self.apply_function(function, arguments, expression.span)
+            .map(Some)
+    }
 }
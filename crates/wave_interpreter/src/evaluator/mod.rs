@@ -1,13 +1,21 @@
 pub mod assignment;
+pub mod binding;
 pub mod class;
+pub mod collection;
 pub mod declaration;
 pub mod expression;
 pub mod function;
+pub mod generator;
+pub mod iterator;
 pub mod literal;
+pub mod match_expression;
 pub mod member_expression;
 pub mod module;
+pub mod overload;
 pub mod primitive;
 pub mod primitive_operations;
+pub mod promise;
+pub mod reflection;
 pub mod statement;
 
 pub use primitive::Primitive;
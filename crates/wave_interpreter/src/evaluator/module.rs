@@ -1,7 +1,14 @@
-use crate::{environment::Environment, Runtime};
+use crate::{
+    diagnostics,
+    environment::Environment,
+    stdlib::{NativeModule, NATIVE_MODULES},
+    Runtime,
+};
 use std::{cell::RefCell, env, rc::Rc};
 use wave_allocator::Allocator;
-use wave_ast::ast::{ImportDeclarationSpecifier, ModuleDeclaration, ModuleExportName};
+use wave_ast::ast::{
+    ImportDeclaration, ImportDeclarationSpecifier, ModuleDeclaration, ModuleExportName,
+};
 use wave_diagnostics::Result;
 use wave_parser::{Parser, ParserReturn};
 use wave_span::Span;
@@ -16,9 +23,11 @@ impl<'a> Runtime<'a> {
     ) -> Result<Primitive<'a>> {
         match statement {
             ModuleDeclaration::ImportDeclaration(import_stmt) => {
-                let source = &import_stmt.source;
-                let source = source.value.to_string();
-                let source = source.trim_matches('\"');
+                let source = import_stmt.source.value.as_str();
+
+                if let Some(module) = source.strip_prefix("std:") {
+                    return self.eval_native_import(import_stmt, module, environment);
+                }
 
                 if let Some(specifiers) = &import_stmt.specifiers {
                     let path = env::current_dir()
@@ -35,7 +44,10 @@ impl<'a> Runtime<'a> {
 
                         if ret.errors.is_empty() {
                             let program = ret.program;
-                            let runtime = Runtime::new(program);
+                            let mut runtime = Runtime::new(program)
+                                .with_io(self.allow_io)
+                                .with_clock(Rc::clone(&self.clock));
+                            runtime.host_functions = self.host_functions.clone();
                             let imported = Runtime::eval_environment(&runtime);
                             match imported {
                                 Ok(env) => {
@@ -75,4 +87,43 @@ impl<'a> Runtime<'a> {
 
         Ok(Primitive::Null)
     }
+
+    fn eval_native_import(
+        &self,
+        import_stmt: &ImportDeclaration<'a>,
+        module_name: &str,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        let Some(module) = NativeModule::get(module_name) else {
+            return Err(diagnostics::UnknownNativeModule(
+                module_name.to_string(),
+                NATIVE_MODULES,
+                import_stmt.source.span,
+            )
+            .into());
+        };
+
+        if module.name == "io" && !self.allow_io {
+            return Err(diagnostics::IoDisabled(import_stmt.span).into());
+        }
+
+        for specifier in import_stmt.specifiers.iter().flatten() {
+            match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(import_specifier) => {
+                    let name = import_specifier.imported.name();
+                    let Some(value) = module.export(name) else {
+                        return Err(diagnostics::NativeExportNotFound(
+                            module.name,
+                            name.to_string(),
+                            import_specifier.span,
+                        )
+                        .into());
+                    };
+                    environment.borrow_mut().define(name.clone(), value);
+                }
+            }
+        }
+
+        Ok(Primitive::Null)
+    }
 }
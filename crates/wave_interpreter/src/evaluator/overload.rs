@@ -0,0 +1,146 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::evaluator::Primitive;
+use crate::{environment::Environment, Runtime};
+use wave_diagnostics::Result;
+use wave_span::{Atom, Span};
+use wave_syntax::operator::BinaryOperator;
+
+/// The method a class defines to overload `operator`.
+fn operator_method(operator: &BinaryOperator) -> Option<&'static str> {
+    match operator {
+        BinaryOperator::Addition => Some("__add__"),
+        BinaryOperator::Subtraction => Some("__sub__"),
+        BinaryOperator::Multiplication => Some("__mul__"),
+        BinaryOperator::Division => Some("__div__"),
+        BinaryOperator::Remainder => Some("__mod__"),
+        BinaryOperator::Exponential => Some("__pow__"),
+        BinaryOperator::Equality => Some("__eq__"),
+        BinaryOperator::Inequality => Some("__ne__"),
+        BinaryOperator::LessThan => Some("__lt__"),
+        BinaryOperator::LessEqualThan => Some("__le__"),
+        BinaryOperator::GreaterThan => Some("__gt__"),
+        BinaryOperator::GreaterEqualThan => Some("__ge__"),
+        BinaryOperator::BitwiseOR => Some("__or__"),
+        BinaryOperator::BitwiseXOR => Some("__xor__"),
+        BinaryOperator::BitwiseAnd => Some("__and__"),
+        BinaryOperator::Instanceof => None,
+    }
+}
+
+impl<'a> Runtime<'a> {
+    /// Calls the method overloading `operator` when the left operand is an instance of a
+    /// class defining it, `None` when the operator is not overloaded.
+    /// `!=` falls back to negating `__eq__`.
+    pub fn apply_overloaded_operator(
+        &self,
+        operator: &BinaryOperator,
+        left: &Primitive<'a>,
+        right: &Primitive<'a>,
+        span: Span,
+    ) -> Result<Option<Primitive<'a>>> {
+        let (Primitive::Instance(env) | Primitive::This(env)) = left else {
+            return Ok(None);
+        };
+        let Some(name) = operator_method(operator) else {
+            return Ok(None);
+        };
+
+        if let Some(method) = self.get_operator_method(env, name, span)? {
+            return self
+                .apply_function(method, vec![right.clone()], span)
+                .map(Some);
+        }
+        if *operator == BinaryOperator::Inequality {
+            if let Some(method) = self.get_operator_method(env, "__eq__", span)? {
+                let equal = self.apply_function(method, vec![right.clone()], span)?;
+                return Ok(Some(Primitive::Boolean(!equal.is_truthy())));
+            }
+        }
+        Ok(None)
+    }
+
+    fn get_operator_method(
+        &self,
+        env: &Rc<RefCell<Environment<'a>>>,
+        name: &str,
+        span: Span,
+    ) -> Result<Option<Primitive<'a>>> {
+        match self.find_member(env, &self.bind_this(Atom::from(name))) {
+            Some((method, home)) => self.bind_method(method, env, &home, span).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::eval;
+
+    #[test]
+    fn overloaded_operators() {
+        let class = "class Vec2 {
+                constructor(x, y) { this.x = x; this.y = y; }
+                __add__(other) { return new Vec2(this.x + other.x, this.y + other.y); }
+                __mul__(k) { return new Vec2(this.x * k, this.y * k); }
+                __eq__(other) { return this.x == other.x && this.y == other.y; }
+                __lt__(other) { return this.x < other.x; }
+            }";
+        let run = |code: &str| eval(&format!("{class} {code}"));
+        assert_eq!(
+            run("let v = new Vec2(1, 2) + new Vec2(3, 4) * 2; [v.x, v.y];").unwrap(),
+            "[7, 10]"
+        );
+        assert_eq!(
+            run("let v = new Vec2(1, 1); v += new Vec2(1, 2); [v.x, v.y];").unwrap(),
+            "[2, 3]"
+        );
+        assert_eq!(
+            run("let a = new Vec2(1, 2); [a == new Vec2(1, 2), a != new Vec2(1, 2), a < new Vec2(2, 0)];")
+                .unwrap(),
+            "[true, false, true]"
+        );
+        assert!(run("new Vec2(1, 2) - new Vec2(1, 2);").is_err());
+    }
+
+    #[test]
+    fn instances_without_eq_compare_by_identity() {
+        let code =
+            "class Plain {} let p = new Plain(); [p == p, p == new Plain(), p != new Plain()];";
+        assert_eq!(eval(code).unwrap(), "[true, false, true]");
+    }
+
+    #[test]
+    fn every_comparison_uses_eq() {
+        let class = "class P {
+                constructor(x) { this.x = x; }
+                __eq__(other) { return this.x == other.x; }
+            }
+            let a = new P(1);
+            let b = new P(1);";
+        let run = |code: &str| eval(&format!("{class} {code}"));
+        assert_eq!(run("a == b;").unwrap(), "true");
+        assert_eq!(run("contains([a], b);").unwrap(), "true");
+        assert_eq!(
+            run("[[a] == [b], [a] != [b], [a] == [new P(2)]];").unwrap(),
+            "[true, false, false]"
+        );
+        assert_eq!(
+            run("let found = 0; switch (a) { case new P(2): found = 2; break; case b: found = 1; break; default: found = -1; } found;")
+                .unwrap(),
+            "1"
+        );
+
+        let class = "class Meters {
+                constructor(value) { this.value = value; }
+                __eq__(other) { return this.value == other; }
+            }";
+        let run = |code: &str| eval(&format!("{class} {code}"));
+        assert_eq!(
+            run("match (new Meters(3)) { 1 => 10, 3 => 30, _ => 0 };").unwrap(),
+            "30"
+        );
+        assert!(run("class Broken { __eq__(other) { return other.missing(); } } contains([new Broken()], 1);").is_err());
+    }
+}
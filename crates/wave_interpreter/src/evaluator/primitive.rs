@@ -1,39 +1,93 @@
+use num_bigint::BigInt;
+use num_traits::Zero;
 use std::fmt::Debug;
 use std::vec::Vec as StdVec;
 use std::{cell::RefCell, ptr, rc::Rc};
 use wave_allocator::Vec;
-use wave_ast::ast::{FormalParameter, Statement};
+use wave_ast::ast::{FormalParameters, Statement};
+use wave_span::{Atom, Span};
 
 use crate::environment::Environment;
+use crate::evaluator::collection::Collection;
+use crate::evaluator::function::NativeFunction;
+use crate::evaluator::generator::Generator;
+use crate::evaluator::promise::{Promise, PromiseState};
 
 pub enum Primitive<'a> {
     Number(f64),
+    BigInt(BigInt),
     Boolean(bool),
     String(String),
     Array(StdVec<Primitive<'a>>),
     Function(
-        Option<Vec<'a, FormalParameter<'a>>>,
+        Option<FormalParameters<'a>>,
         Option<Vec<'a, Statement<'a>>>,
         Rc<RefCell<Environment<'a>>>,
     ),
+    /// A `function*`, calling it creates a suspended generator
+    GeneratorFunction(
+        &'a FormalParameters<'a>,
+        &'a [Statement<'a>],
+        Rc<RefCell<Environment<'a>>>,
+    ),
+    /// An `async function`, calling it returns a promise
+    AsyncFunction(
+        &'a FormalParameters<'a>,
+        &'a [Statement<'a>],
+        Rc<RefCell<Environment<'a>>>,
+    ),
+    /// A built-in method bound to its receiver, like the `next()` of a generator
+    NativeFunction(NativeFunction<'a>),
+    Generator(Rc<RefCell<Generator<'a>>>),
+    Promise(Rc<RefCell<Promise<'a>>>),
+    Map(Rc<RefCell<Collection<'a>>>),
+    Set(Rc<RefCell<Collection<'a>>>),
     Class(Rc<RefCell<Environment<'a>>>),
     Instance(Rc<RefCell<Environment<'a>>>),
     This(Rc<RefCell<Environment<'a>>>),
     Return(Box<Primitive<'a>>),
-    Break,
-    Continue,
+    /// `break` with an optional label
+    Break(Option<Atom>),
+    /// `continue` with an optional label
+    Continue(Option<Atom>),
     Null,
 }
 
-impl<'a> PartialEq for Primitive<'a> {
-    fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Primitive::Number(a), Primitive::Number(b)) => a == b,
-            (Primitive::Boolean(a), Primitive::Boolean(b)) => a == b,
-            (Primitive::String(a), Primitive::String(b)) => a == b,
-            (Primitive::Array(a), Primitive::Array(b)) => a == b,
-            (Primitive::Return(a), Primitive::Return(b)) => a == b,
-            _ => false,
+impl<'a> Primitive<'a> {
+    /// Where a function of the script was declared and the environment it closes over,
+    /// which together identify it.
+    pub fn function_source(&self) -> Option<(Span, &Rc<RefCell<Environment<'a>>>)> {
+        match self {
+            Primitive::Function(params, _, env) => Some((
+                params
+                    .as_ref()
+                    .map_or(Span::default(), |params| params.span),
+                env,
+            )),
+            Primitive::GeneratorFunction(params, _, env)
+            | Primitive::AsyncFunction(params, _, env) => Some((params.span, env)),
+            _ => None,
+        }
+    }
+
+    /// Whether the value counts as true in a condition or a logical expression.
+    ///
+    /// | value                    | truthy              |
+    /// |--------------------------|---------------------|
+    /// | `null`                   | no                  |
+    /// | booleans                 | their value         |
+    /// | numbers                  | unless `0` or `NaN` |
+    /// | BigInts                  | unless `0n`         |
+    /// | strings                  | unless empty        |
+    /// | everything else          | yes, even `[]`      |
+    pub fn is_truthy(&self) -> bool {
+        match self {
+            Primitive::Null => false,
+            Primitive::Boolean(value) => *value,
+            Primitive::Number(value) => *value != 0.0 && !value.is_nan(),
+            Primitive::BigInt(value) => !value.is_zero(),
+            Primitive::String(value) => !value.is_empty(),
+            _ => true,
         }
     }
 }
@@ -42,16 +96,38 @@ impl<'a> Debug for Primitive<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Primitive::Number(value) => write!(f, "{}", value),
+            Primitive::BigInt(value) => write!(f, "{}n", value),
             Primitive::Boolean(value) => write!(f, "{}", value),
             Primitive::String(value) => write!(f, "{}", value),
             Primitive::Array(value) => write!(f, "{:?}", value),
             Primitive::Function(_, _, _) => write!(f, "Function"),
+            Primitive::GeneratorFunction(_, _, _) => write!(f, "GeneratorFunction"),
+            Primitive::NativeFunction(function) => write!(f, "Function({})", function.name),
+            Primitive::AsyncFunction(_, _, _) => write!(f, "AsyncFunction"),
+            Primitive::Generator(_) => write!(f, "Generator"),
+            Primitive::Promise(promise) => match &promise.borrow().state {
+                PromiseState::Pending => write!(f, "Promise(pending)"),
+                PromiseState::Fulfilled(value) => write!(f, "Promise({:?})", value),
+                PromiseState::Rejected(reason) => write!(f, "Promise(rejected: {:?})", reason),
+            },
+            Primitive::Map(map) => {
+                let entries = map.borrow();
+                let entries = entries
+                    .entries()
+                    .map(|(key, value)| format!("{:?} => {:?}", key, value));
+                write!(f, "Map {{{}}}", entries.collect::<StdVec<_>>().join(", "))
+            }
+            Primitive::Set(set) => {
+                let values = set.borrow();
+                let values = values.entries().map(|(value, _)| format!("{:?}", value));
+                write!(f, "Set {{{}}}", values.collect::<StdVec<_>>().join(", "))
+            }
             Primitive::Class(env) => write!(f, "{:?}", env.borrow().values),
             Primitive::Instance(env) => write!(f, "{:?}", env.borrow().values),
             Primitive::This(env) => write!(f, "{:?}", env.borrow().values),
             Primitive::Return(value) => write!(f, "Return({:?})", value),
-            Primitive::Break => write!(f, "Break"),
-            Primitive::Continue => write!(f, "Continue"),
+            Primitive::Break(_) => write!(f, "Break"),
+            Primitive::Continue(_) => write!(f, "Continue"),
             Primitive::Null => write!(f, "Null"),
         }
     }
@@ -61,6 +137,7 @@ impl<'a> Clone for Primitive<'a> {
     fn clone(&self) -> Self {
         match self {
             Primitive::Number(value) => Primitive::Number(*value),
+            Primitive::BigInt(value) => Primitive::BigInt(value.clone()),
             Primitive::Boolean(value) => Primitive::Boolean(*value),
             Primitive::String(value) => Primitive::String(value.to_owned()),
             Primitive::Array(value) => Primitive::Array(value.to_owned()),
@@ -69,12 +146,23 @@ impl<'a> Clone for Primitive<'a> {
                 let body = ptr::read(function_body);
                 Primitive::Function(params, body, Rc::clone(environment))
             },
+            Primitive::GeneratorFunction(params, body, environment) => {
+                Primitive::GeneratorFunction(params, body, Rc::clone(environment))
+            }
+            Primitive::NativeFunction(function) => Primitive::NativeFunction(function.clone()),
+            Primitive::AsyncFunction(params, body, environment) => {
+                Primitive::AsyncFunction(params, body, Rc::clone(environment))
+            }
+            Primitive::Generator(generator) => Primitive::Generator(Rc::clone(generator)),
+            Primitive::Promise(promise) => Primitive::Promise(Rc::clone(promise)),
+            Primitive::Map(map) => Primitive::Map(Rc::clone(map)),
+            Primitive::Set(set) => Primitive::Set(Rc::clone(set)),
             Primitive::Class(environment) => Primitive::Class(Rc::clone(environment)),
             Primitive::Instance(environment) => Primitive::Instance(Rc::clone(environment)),
             Primitive::This(environment) => Primitive::This(Rc::clone(environment)),
             Primitive::Null => Primitive::Null,
-            Primitive::Break => Primitive::Break,
-            Primitive::Continue => Primitive::Continue,
+            Primitive::Break(label) => Primitive::Break(label.clone()),
+            Primitive::Continue(label) => Primitive::Continue(label.clone()),
             Primitive::Return(value) => Primitive::Return(Box::clone(value)),
         }
     }
@@ -1,16 +1,19 @@
 use std::cell::RefCell;
+use std::cmp::Ordering;
 use std::rc::Rc;
 
 use crate::evaluator::Primitive;
 use crate::Runtime;
 use crate::{diagnostics, environment::Environment};
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, ToPrimitive, Zero};
 use wave_allocator::Box;
 use wave_ast::ast::{
-    BinaryExpression, Expression, LogicalExpression, SimpleAssignmentTarget, UnaryExpression,
-    UpdateExpression,
+    BinaryExpression, ConditionalExpression, Expression, LogicalExpression, SimpleAssignmentTarget,
+    UnaryExpression, UpdateExpression,
 };
 use wave_diagnostics::Result;
-use wave_span::GetSpan;
+use wave_span::{GetSpan, Span};
 use wave_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator};
 
 impl<'a> Runtime<'a> {
@@ -43,6 +46,27 @@ impl<'a> Runtime<'a> {
             BinaryOperator::BitwiseOR | BinaryOperator::BitwiseAnd | BinaryOperator::BitwiseXOR => {
                 self.eval_bitwise(left, right, environment, &expression.operator)
             }
+
+            BinaryOperator::Instanceof => self.eval_instanceof(left, right, environment),
+        }
+    }
+
+    /// `instanceof` holds when the class on the right is in the class chain of the left.
+    pub fn eval_instanceof(
+        &self,
+        left: &Expression<'a>,
+        right: &Expression<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        let left_eval = self.eval_expression(left, Rc::clone(&environment))?;
+        let Primitive::Class(class) = self.eval_expression(right, environment)? else {
+            return Err(diagnostics::InvalidInstanceofTarget(right.span()).into());
+        };
+        match left_eval {
+            Primitive::Instance(instance) | Primitive::This(instance) => {
+                Ok(Primitive::Boolean(self.is_instance_of(&instance, &class)))
+            }
+            _ => Ok(Primitive::Boolean(false)),
         }
     }
 
@@ -54,11 +78,7 @@ impl<'a> Runtime<'a> {
         let left = &expression.left;
         let right = &expression.right;
 
-        match expression.operator {
-            LogicalOperator::Or | LogicalOperator::And => {
-                self.eval_logical(left, right, environment, &expression.operator)
-            }
-        }
+        self.eval_logical(left, right, environment, &expression.operator)
     }
 
     pub fn eval_arithmetic(
@@ -70,8 +90,25 @@ impl<'a> Runtime<'a> {
     ) -> Result<Primitive<'a>> {
         let left_eval = self.eval_expression(left, Rc::clone(&environment))?;
         let right_eval = self.eval_expression(right, Rc::clone(&environment))?;
+        self.apply_arithmetic(
+            operator,
+            left_eval,
+            right_eval,
+            left.span().merge(&right.span()),
+        )
+    }
 
-        match (left_eval, right_eval) {
+    pub fn apply_arithmetic(
+        &self,
+        operator: &BinaryOperator,
+        left: Primitive<'a>,
+        right: Primitive<'a>,
+        span: Span,
+    ) -> Result<Primitive<'a>> {
+        if let Some(value) = self.apply_overloaded_operator(operator, &left, &right, span)? {
+            return Ok(value);
+        }
+        match (left, right) {
             (Primitive::Number(left), Primitive::Number(right)) => match operator {
                 BinaryOperator::Addition => Ok(Primitive::Number(left + right)),
                 BinaryOperator::Subtraction => Ok(Primitive::Number(left - right)),
@@ -81,7 +118,29 @@ impl<'a> Runtime<'a> {
                 BinaryOperator::Exponential => Ok(Primitive::Number(left.powf(right))),
                 _ => unreachable!(),
             },
-            _ => Err(diagnostics::InvalidNumber(left.span().merge(&right.span())).into()),
+            (Primitive::BigInt(left), Primitive::BigInt(right)) => match operator {
+                BinaryOperator::Addition => Ok(Primitive::BigInt(left + right)),
+                BinaryOperator::Subtraction => Ok(Primitive::BigInt(left - right)),
+                BinaryOperator::Multiplication => Ok(Primitive::BigInt(left * right)),
+                BinaryOperator::Division | BinaryOperator::Remainder if right.is_zero() => {
+                    Err(diagnostics::DivisionByZero(span).into())
+                }
+                // both truncate towards zero, like integer division in JS
+                BinaryOperator::Division => Ok(Primitive::BigInt(left / right)),
+                BinaryOperator::Remainder => Ok(Primitive::BigInt(left % right)),
+                BinaryOperator::Exponential => {
+                    let exponent = right
+                        .to_u32()
+                        .ok_or(diagnostics::InvalidBigIntExponent(span))?;
+                    Ok(Primitive::BigInt(left.pow(exponent)))
+                }
+                _ => unreachable!(),
+            },
+            (Primitive::BigInt(_), Primitive::Number(_))
+            | (Primitive::Number(_), Primitive::BigInt(_)) => {
+                Err(diagnostics::MixedBigIntArithmetic(span).into())
+            }
+            _ => Err(diagnostics::InvalidNumber(span).into()),
         }
     }
 
@@ -94,30 +153,110 @@ impl<'a> Runtime<'a> {
     ) -> Result<Primitive<'a>> {
         let left_eval = self.eval_expression(left, Rc::clone(&environment))?;
         let right_eval = self.eval_expression(right, Rc::clone(&environment))?;
+        self.apply_ord(
+            operator,
+            left_eval,
+            right_eval,
+            left.span().merge(&right.span()),
+        )
+    }
 
-        match (left_eval, right_eval) {
-            (Primitive::Number(l), Primitive::Number(r)) => match operator {
-                BinaryOperator::LessThan => Ok(Primitive::Boolean(l < r)),
-                BinaryOperator::LessEqualThan => Ok(Primitive::Boolean(l <= r)),
-                BinaryOperator::GreaterThan => Ok(Primitive::Boolean(l > r)),
-                BinaryOperator::GreaterEqualThan => Ok(Primitive::Boolean(l >= r)),
-                BinaryOperator::Equality => Ok(Primitive::Boolean(l == r)),
-                BinaryOperator::Inequality => Ok(Primitive::Boolean(l != r)),
-                _ => unreachable!(),
-            },
-            (Primitive::Boolean(l), Primitive::Boolean(r)) => match operator {
-                BinaryOperator::Equality => Ok(Primitive::Boolean(l == r)),
-                BinaryOperator::Inequality => Ok(Primitive::Boolean(l != r)),
-                _ => Err(diagnostics::InvalidNumber(left.span().merge(&right.span())).into()),
-            },
+    pub fn apply_ord(
+        &self,
+        operator: &BinaryOperator,
+        left: Primitive<'a>,
+        right: Primitive<'a>,
+        span: Span,
+    ) -> Result<Primitive<'a>> {
+        if *operator == BinaryOperator::Equality {
+            return self.equals(&left, &right, span).map(Primitive::Boolean);
+        }
+        if let Some(value) = self.apply_overloaded_operator(operator, &left, &right, span)? {
+            return Ok(value);
+        }
+        if *operator == BinaryOperator::Inequality {
+            return Ok(Primitive::Boolean(!self.equals(&left, &right, span)?));
+        }
+        match (left, right) {
+            (Primitive::Number(l), Primitive::Number(r)) => {
+                Ok(Primitive::Boolean(compare(operator, l.partial_cmp(&r))))
+            }
+            (Primitive::BigInt(l), Primitive::BigInt(r)) => {
+                Ok(Primitive::Boolean(compare(operator, Some(l.cmp(&r)))))
+            }
+            // mixed comparisons are exact, `2n ** 64n > 2 ** 64` must not round the BigInt
+            (Primitive::BigInt(l), Primitive::Number(r)) => Ok(Primitive::Boolean(compare(
+                operator,
+                compare_bigint_to_number(&l, r),
+            ))),
+            (Primitive::Number(l), Primitive::BigInt(r)) => Ok(Primitive::Boolean(compare(
+                operator,
+                compare_bigint_to_number(&r, l).map(Ordering::reverse),
+            ))),
+            (Primitive::Boolean(_), Primitive::Boolean(_))
+            | (Primitive::String(_), Primitive::String(_)) => {
+                Err(diagnostics::InvalidNumber(span).into())
+            }
+            _ => Err(diagnostics::TypeMismatch(span).into()),
+        }
+    }
 
-            (Primitive::String(l), Primitive::String(r)) => match operator {
-                BinaryOperator::Equality => Ok(Primitive::Boolean(l == r)),
-                BinaryOperator::Inequality => Ok(Primitive::Boolean(l != r)),
-                _ => Err(diagnostics::InvalidNumber(left.span().merge(&right.span())).into()),
-            },
-            _ => Err(diagnostics::TypeMismatch(left.span().merge(&right.span())).into()),
+    /// `==` for values of any type, values of different types are never equal. Every
+    /// comparison of values goes through here, like `match` patterns, `switch` cases and
+    /// `contains`.
+    /// Primitives compare by value and arrays element by element. An instance of a class
+    /// defining `__eq__` is equal to whatever that method accepts, other instances, classes
+    /// and functions are only equal to themselves, whether they are read as `this` or not.
+    pub fn equals(&self, left: &Primitive<'a>, right: &Primitive<'a>, span: Span) -> Result<bool> {
+        if let Some(equal) =
+            self.apply_overloaded_operator(&BinaryOperator::Equality, left, right, span)?
+        {
+            return Ok(equal.is_truthy());
+        }
+        if let (Some((l_span, l_env)), Some((r_span, r_env))) =
+            (left.function_source(), right.function_source())
+        {
+            return Ok(l_span == r_span && self.is_same_binding(l_env, r_env));
         }
+        let equal = match (left, right) {
+            (
+                Primitive::Instance(l) | Primitive::This(l),
+                Primitive::Instance(r) | Primitive::This(r),
+            ) => Rc::ptr_eq(&self.get_receiver(l), &self.get_receiver(r)),
+            (Primitive::Array(l), Primitive::Array(r)) => {
+                if l.len() != r.len() {
+                    return Ok(false);
+                }
+                for (l, r) in l.iter().zip(r) {
+                    if !self.equals(l, r, span)? {
+                        return Ok(false);
+                    }
+                }
+                true
+            }
+            (Primitive::BigInt(l), Primitive::Number(r))
+            | (Primitive::Number(r), Primitive::BigInt(l)) => {
+                compare_bigint_to_number(l, *r) == Some(Ordering::Equal)
+            }
+            (Primitive::Number(l), Primitive::Number(r)) => l == r,
+            (Primitive::BigInt(l), Primitive::BigInt(r)) => l == r,
+            (Primitive::Boolean(l), Primitive::Boolean(r)) => l == r,
+            (Primitive::String(l), Primitive::String(r)) => l == r,
+            (Primitive::Null, Primitive::Null) => true,
+            (Primitive::NativeFunction(l), Primitive::NativeFunction(r)) => {
+                Rc::ptr_eq(&l.function, &r.function)
+                    && self.equals(&l.receiver, &r.receiver, span)?
+            }
+            (Primitive::Generator(l), Primitive::Generator(r)) => Rc::ptr_eq(l, r),
+            (Primitive::Promise(l), Primitive::Promise(r)) => Rc::ptr_eq(l, r),
+            (Primitive::Map(l), Primitive::Map(r)) | (Primitive::Set(l), Primitive::Set(r)) => {
+                Rc::ptr_eq(l, r)
+            }
+            (Primitive::Class(l), Primitive::Class(r)) => Rc::ptr_eq(l, r),
+            (Primitive::Return(l), Primitive::Return(r)) => return self.equals(l, r, span),
+            _ => false,
+        };
+        Ok(equal)
     }
 
     pub fn eval_bitwise(
@@ -129,24 +268,49 @@ impl<'a> Runtime<'a> {
     ) -> Result<Primitive<'a>> {
         let left_eval = self.eval_expression(left, Rc::clone(&environment))?;
         let right_eval = self.eval_expression(right, Rc::clone(&environment))?;
+        self.apply_bitwise(
+            operator,
+            left_eval,
+            right_eval,
+            left.span().merge(&right.span()),
+        )
+    }
 
-        match (left_eval, right_eval) {
-            (Primitive::Number(left), Primitive::Number(right)) => match operator {
-                BinaryOperator::BitwiseOR => {
-                    Ok(Primitive::Number((left as u64 | right as u64) as f64))
-                }
-                BinaryOperator::BitwiseAnd => {
-                    Ok(Primitive::Number((left as u64 & right as u64) as f64))
-                }
-                BinaryOperator::BitwiseXOR => {
-                    Ok(Primitive::Number((left as u64 ^ right as u64) as f64))
+    pub fn apply_bitwise(
+        &self,
+        operator: &BinaryOperator,
+        left: Primitive<'a>,
+        right: Primitive<'a>,
+        span: Span,
+    ) -> Result<Primitive<'a>> {
+        if let Some(value) = self.apply_overloaded_operator(operator, &left, &right, span)? {
+            return Ok(value);
+        }
+        match (left, right) {
+            (Primitive::Number(left), Primitive::Number(right)) => {
+                let (left, right) = (left as i64, right as i64);
+                match operator {
+                    BinaryOperator::BitwiseOR => Ok(Primitive::Number((left | right) as f64)),
+                    BinaryOperator::BitwiseAnd => Ok(Primitive::Number((left & right) as f64)),
+                    BinaryOperator::BitwiseXOR => Ok(Primitive::Number((left ^ right) as f64)),
+                    _ => unreachable!(),
                 }
+            }
+            (Primitive::BigInt(left), Primitive::BigInt(right)) => match operator {
+                BinaryOperator::BitwiseOR => Ok(Primitive::BigInt(left | right)),
+                BinaryOperator::BitwiseAnd => Ok(Primitive::BigInt(left & right)),
+                BinaryOperator::BitwiseXOR => Ok(Primitive::BigInt(left ^ right)),
                 _ => unreachable!(),
             },
-            _ => Err(diagnostics::InvalidNumber(left.span().merge(&right.span())).into()),
+            (Primitive::BigInt(_), Primitive::Number(_))
+            | (Primitive::Number(_), Primitive::BigInt(_)) => {
+                Err(diagnostics::MixedBigIntArithmetic(span).into())
+            }
+            _ => Err(diagnostics::InvalidNumber(span).into()),
         }
     }
 
+    /// The right side is only evaluated when the left side does not decide the result.
     pub fn eval_logical(
         &self,
         left: &Expression<'a>,
@@ -155,14 +319,33 @@ impl<'a> Runtime<'a> {
         operator: &LogicalOperator,
     ) -> Result<Primitive<'a>> {
         let left_eval = self.eval_expression(left, Rc::clone(&environment))?;
-        let right_eval = self.eval_expression(right, Rc::clone(&environment))?;
 
-        match (left_eval, right_eval) {
-            (Primitive::Boolean(left), Primitive::Boolean(right)) => match operator {
-                LogicalOperator::Or => Ok(Primitive::Boolean(left || right)),
-                LogicalOperator::And => Ok(Primitive::Boolean(left && right)),
-            },
-            _ => Err(diagnostics::InvalidBoolean(left.span().merge(&right.span())).into()),
+        if *operator == LogicalOperator::Coalesce {
+            return match left_eval {
+                Primitive::Null => self.eval_expression(right, environment),
+                value => Ok(value),
+            };
+        }
+
+        // like in JS, the result is the operand that decided it rather than a boolean
+        match (operator, left_eval.is_truthy()) {
+            (LogicalOperator::Or, true) | (LogicalOperator::And, false) => Ok(left_eval),
+            _ => self.eval_expression(right, environment),
+        }
+    }
+
+    pub fn eval_conditional_expression(
+        &self,
+        expression: &Box<'_, ConditionalExpression<'a>>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        if self
+            .eval_expression(&expression.test, Rc::clone(&environment))?
+            .is_truthy()
+        {
+            self.eval_expression(&expression.consequent, environment)
+        } else {
+            self.eval_expression(&expression.alternate, environment)
         }
     }
 
@@ -179,12 +362,11 @@ impl<'a> Runtime<'a> {
             },
             UnaryOperator::UnaryNegation => match value {
                 Primitive::Number(value) => Ok(Primitive::Number(-value)),
+                Primitive::BigInt(value) => Ok(Primitive::BigInt(-value)),
                 _ => Err(diagnostics::InvalidNumber(expression.span).into()),
             },
-            UnaryOperator::LogicalNot => match value {
-                Primitive::Boolean(value) => Ok(Primitive::Boolean(!value)),
-                _ => Err(diagnostics::InvalidBoolean(expression.span).into()),
-            },
+            UnaryOperator::LogicalNot => Ok(Primitive::Boolean(!value.is_truthy())),
+            UnaryOperator::Typeof => Ok(Primitive::String(self.type_of(&value).into())),
         }
     }
 
@@ -198,38 +380,173 @@ impl<'a> Runtime<'a> {
             todo!()
         };
 
-        match expression.operator {
-            UpdateOperator::Increment => {
-                let value = environment
-                    .borrow()
-                    .get(identifier.name.to_owned(), identifier.span)?;
-                match value {
-                    Primitive::Number(value) => {
-                        let new_value = Primitive::Number(value + 1.0);
-                        environment
-                            .borrow_mut()
-                            .define(identifier.name.to_owned(), new_value.clone());
-                        Ok(new_value)
-                    }
-                    _ => Err(diagnostics::InvalidNumber(expression.span).into()),
-                }
-            }
-            UpdateOperator::Decrement => {
-                let value = environment
-                    .borrow()
-                    .get(identifier.name.to_owned(), identifier.span)?;
-
-                match value {
-                    Primitive::Number(value) => {
-                        let new_value = Primitive::Number(value + 1.0);
-                        environment
-                            .borrow_mut()
-                            .define(identifier.name.to_owned(), new_value.clone());
-                        Ok(new_value)
-                    }
-                    _ => Err(diagnostics::InvalidNumber(expression.span).into()),
-                }
-            }
+        let value = environment
+            .borrow()
+            .get(identifier.name.to_owned(), identifier.span)?;
+
+        let new_value = match (value, expression.operator) {
+            (Primitive::Number(value), UpdateOperator::Increment) => Primitive::Number(value + 1.0),
+            (Primitive::Number(value), UpdateOperator::Decrement) => Primitive::Number(value - 1.0),
+            (Primitive::BigInt(value), UpdateOperator::Increment) => Primitive::BigInt(value + 1),
+            (Primitive::BigInt(value), UpdateOperator::Decrement) => Primitive::BigInt(value - 1),
+            _ => return Err(diagnostics::InvalidNumber(expression.span).into()),
+        };
+
+        environment
+            .borrow_mut()
+            .assign(identifier.name.to_owned(), new_value.clone());
+        Ok(new_value)
+    }
+}
+
+fn compare(operator: &BinaryOperator, ordering: Option<Ordering>) -> bool {
+    match operator {
+        BinaryOperator::LessThan => ordering == Some(Ordering::Less),
+        BinaryOperator::LessEqualThan => {
+            matches!(ordering, Some(Ordering::Less | Ordering::Equal))
         }
+        BinaryOperator::GreaterThan => ordering == Some(Ordering::Greater),
+        BinaryOperator::GreaterEqualThan => {
+            matches!(ordering, Some(Ordering::Greater | Ordering::Equal))
+        }
+        BinaryOperator::Equality => ordering == Some(Ordering::Equal),
+        BinaryOperator::Inequality => ordering != Some(Ordering::Equal),
+        _ => unreachable!(),
+    }
+}
+
+/// Compares without converting the BigInt to a float, `None` when the number is `NaN`.
+fn compare_bigint_to_number(left: &BigInt, right: f64) -> Option<Ordering> {
+    if right.is_nan() {
+        return None;
+    }
+    if right.is_infinite() {
+        return Some(if right > 0.0 {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        });
+    }
+    match left.cmp(&BigInt::from_f64(right.trunc())?) {
+        Ordering::Equal => 0.0.partial_cmp(&right.fract()),
+        ordering => Some(ordering),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::evaluator::Primitive;
+    use crate::test_utils::eval;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn bigint_arithmetic_is_exact() {
+        let square = "9007199254740993".parse::<BigInt>().unwrap().pow(2);
+        assert_eq!(
+            eval("let a = 9007199254740993n; a * a;").unwrap(),
+            format!("{:?}", Primitive::BigInt(square))
+        );
+        assert_eq!(eval("-7n / 2n;").unwrap(), "-3n");
+        assert_eq!(eval("-7n % 2n;").unwrap(), "-1n");
+        assert!(eval("1n / 0n;").is_err());
+    }
+
+    #[test]
+    fn bigint_mixed_with_number() {
+        assert!(eval("1n + 1;").is_err());
+        assert_eq!(eval("2n > 1;").unwrap(), "true");
+        assert_eq!(
+            eval("9007199254740993n > 9007199254740992;").unwrap(),
+            "true"
+        );
+        assert!(eval("Number(2n) * 0n;").is_err());
+        assert_eq!(eval("BigInt(3) + 2n;").unwrap(), "5n");
+    }
+
+    #[test]
+    fn logical_operators_short_circuit() {
+        assert_eq!(eval("false && missing;").unwrap(), "false");
+        assert_eq!(eval("true || missing;").unwrap(), "true");
+        assert_eq!(eval("null ?? 1;").unwrap(), "1");
+        assert_eq!(eval("0 ?? missing;").unwrap(), "0");
+        assert_eq!(eval("let a = null; a ??= 2; a ??= 3; a;").unwrap(), "2");
+        assert_eq!(eval("true ? 1 : missing;").unwrap(), "1");
+        assert_eq!(eval("0 ? 2 : 3;").unwrap(), "3");
+    }
+
+    #[test]
+    fn truthiness() {
+        let falsy = ["null", "false", "0", "-0", "0 / 0", "0n", "\"\""];
+        for value in falsy {
+            assert_eq!(eval(&format!("!({value});")).unwrap(), "true", "{value}");
+        }
+        let truthy = [
+            "true",
+            "1",
+            "-1n",
+            "\"0\"",
+            "[]",
+            "new Map()",
+            "function () {}",
+        ];
+        for value in truthy {
+            assert_eq!(eval(&format!("!({value});")).unwrap(), "false", "{value}");
+        }
+
+        let source = "let out = [];
+            let i = 3;
+            while (i) { out = [...out, i]; i = i - 1; }
+            if (\"\") { out = [...out, \"empty\"]; } else { out = [...out, \"else\"]; }
+            out;";
+        assert_eq!(eval(source).unwrap(), "[3, 2, 1, else]");
+
+        // logical operators return the operand that decided the result
+        assert_eq!(eval("0 || \"default\";").unwrap(), "default");
+        assert_eq!(eval("\"a\" && \"b\";").unwrap(), "b");
+        assert_eq!(eval("null && missing;").unwrap(), "Null");
+        assert_eq!(
+            eval("let a = 0; a ||= 5; let b = 1; b &&= 2; [a, b];").unwrap(),
+            "[5, 2]"
+        );
+    }
+
+    #[test]
+    fn equality_across_types() {
+        let source = "class A { m() { return 1; } }
+            function f() {}
+            function g() {}
+            let a = new A();
+            let b = new A();
+            [a == a, a == b, a == null, null == null, 1 == \"1\", 1 == 1n, [1, [a]] == [1, [a]],
+             f == f, f == g, a.m == a.m, a.m == b.m, A == A, A != null, a != b];";
+        assert_eq!(
+            eval(source).unwrap(),
+            "[true, false, false, true, false, true, true, true, false, true, false, true, true, true]"
+        );
+        let source = "class A { is(other) { return this == other && other == this; } }
+            let a = new A();
+            [a.is(a), a.is(new A())];";
+        assert_eq!(eval(source).unwrap(), "[true, false]");
+        // `contains` compares like `==`, also for `this` and bound methods
+        let source = "class A {
+                m() { return 1; }
+                check(a) { return [contains(a, this), a[0] == this, contains([this.m], this.m)]; }
+            }
+            let a = new A();
+            [a.check([a]), contains([1n, [2]], 1), contains([[2]], [2])];";
+        assert_eq!(eval(source).unwrap(), "[[true, true, true], true, true]");
+        assert!(eval("1 < \"2\";").is_err());
+    }
+
+    #[test]
+    fn optional_chaining() {
+        let source = "class A { constructor() { this.x = [1]; } get() { return this.x; } }";
+        assert_eq!(
+            eval(&format!("{source} let a = new A(); a?.get()?.[0];")).unwrap(),
+            "1"
+        );
+        assert_eq!(eval("let a = null; a?.b.c(missing);").unwrap(), "Null");
+        assert_eq!(eval("let a = null; a?.[0];").unwrap(), "Null");
+        assert!(eval("let a = null; a.b;").is_err());
     }
 }
@@ -0,0 +1,391 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::vec::Vec as StdVec;
+
+use crate::environment::Environment;
+use crate::evaluator::function::NativeFunction;
+use crate::evaluator::generator::{Generator, GeneratorStep};
+use crate::evaluator::Primitive;
+use crate::event_loop::Task;
+use crate::{diagnostics, Runtime};
+use wave_ast::ast::{FormalParameters, Statement};
+use wave_diagnostics::Result;
+use wave_span::{Atom, Span};
+
+#[derive(Debug, Clone)]
+pub enum PromiseState<'a> {
+    Pending,
+    Fulfilled(Primitive<'a>),
+    Rejected(Primitive<'a>),
+}
+
+/// The eventual result of an asynchronous operation. Handlers always run as microtasks,
+/// even when the promise is already settled.
+pub struct Promise<'a> {
+    pub state: PromiseState<'a>,
+    reactions: StdVec<Reaction<'a>>,
+    /// Whether a handler was ever attached, rejections without one are reported
+    pub handled: bool,
+}
+
+/// What runs once a promise settles.
+pub enum Reaction<'a> {
+    /// `then(on_fulfilled, on_rejected)`, `derived` takes the result of the handler, or the
+    /// state of the promise when there is no handler for it
+    Then {
+        on_fulfilled: Option<Primitive<'a>>,
+        on_rejected: Option<Primitive<'a>>,
+        derived: Rc<RefCell<Promise<'a>>>,
+        span: Span,
+    },
+    /// An `await`, resuming the async function with the settled value
+    Await {
+        coroutine: Rc<RefCell<Generator<'a>>>,
+        result: Rc<RefCell<Promise<'a>>>,
+        span: Span,
+    },
+}
+
+impl<'a> Runtime<'a> {
+    pub fn new_promise(&self) -> Rc<RefCell<Promise<'a>>> {
+        Rc::new(RefCell::new(Promise {
+            state: PromiseState::Pending,
+            reactions: vec![],
+            handled: false,
+        }))
+    }
+
+    /// Fulfills `promise` with `value`, or makes it follow `value` when that is a promise.
+    /// Settled promises are left as they are.
+    pub fn resolve_promise(&self, promise: &Rc<RefCell<Promise<'a>>>, value: Primitive<'a>) {
+        match value {
+            Primitive::Promise(inner) if !Rc::ptr_eq(&inner, promise) => {
+                let reaction = Reaction::Then {
+                    on_fulfilled: None,
+                    on_rejected: None,
+                    derived: Rc::clone(promise),
+                    span: Span::default(),
+                };
+                self.subscribe(&inner, reaction);
+            }
+            value => self.settle(promise, PromiseState::Fulfilled(value)),
+        }
+    }
+
+    pub fn reject_promise(&self, promise: &Rc<RefCell<Promise<'a>>>, reason: Primitive<'a>) {
+        self.settle(promise, PromiseState::Rejected(reason));
+    }
+
+    fn settle(&self, promise: &Rc<RefCell<Promise<'a>>>, state: PromiseState<'a>) {
+        let reactions = {
+            let mut promise = promise.borrow_mut();
+            if !matches!(promise.state, PromiseState::Pending) {
+                return;
+            }
+            promise.state = state.clone();
+            std::mem::take(&mut promise.reactions)
+        };
+
+        let mut event_loop = self.event_loop.borrow_mut();
+        if matches!(state, PromiseState::Rejected(_)) && !promise.borrow().handled {
+            event_loop.track_rejection(Rc::clone(promise));
+        }
+        for reaction in reactions {
+            event_loop.queue_microtask(Task::Reaction(reaction, state.clone()));
+        }
+    }
+
+    fn subscribe(&self, promise: &Rc<RefCell<Promise<'a>>>, reaction: Reaction<'a>) {
+        let mut promise = promise.borrow_mut();
+        promise.handled = true;
+        match &promise.state {
+            PromiseState::Pending => promise.reactions.push(reaction),
+            state => self
+                .event_loop
+                .borrow_mut()
+                .queue_microtask(Task::Reaction(reaction, state.clone())),
+        }
+    }
+
+    /// The promise of an awaited value, other values are wrapped in a fulfilled promise.
+    fn to_promise(&self, value: Primitive<'a>) -> Rc<RefCell<Promise<'a>>> {
+        match value {
+            Primitive::Promise(promise) => promise,
+            value => {
+                let promise = self.new_promise();
+                self.resolve_promise(&promise, value);
+                promise
+            }
+        }
+    }
+
+    pub fn run_reaction(&self, reaction: Reaction<'a>, state: PromiseState<'a>) -> Result<()> {
+        match reaction {
+            Reaction::Then {
+                on_fulfilled,
+                on_rejected,
+                derived,
+                span,
+            } => {
+                let (handler, value) = match &state {
+                    PromiseState::Fulfilled(value) => (on_fulfilled, value.clone()),
+                    PromiseState::Rejected(reason) => (on_rejected, reason.clone()),
+                    PromiseState::Pending => unreachable!("only settled promises react"),
+                };
+                match handler {
+                    Some(handler) => {
+                        let result = self.apply_function(handler, vec![value], span)?;
+                        self.resolve_promise(&derived, result);
+                    }
+                    None => self.settle(&derived, state),
+                }
+                Ok(())
+            }
+            Reaction::Await {
+                coroutine,
+                result,
+                span,
+            } => self.resume_async(&coroutine, &result, state, span),
+        }
+    }
+
+    /// Calls an async function, its body runs until the first `await` before this returns.
+    pub fn call_async_function(
+        &self,
+        params: &'a FormalParameters<'a>,
+        body: &'a [Statement<'a>],
+        environment: Rc<RefCell<Environment<'a>>>,
+        arguments: StdVec<Primitive<'a>>,
+        span: Span,
+    ) -> Result<Primitive<'a>> {
+        let environment = self.alloc_environment(Environment::extend(environment));
+        self.bind_arguments(Some(params), arguments, span, Rc::clone(&environment))?;
+        let coroutine = Rc::new(RefCell::new(Generator::new(body, environment)));
+        let result = self.new_promise();
+        self.resume_async(
+            &coroutine,
+            &result,
+            PromiseState::Fulfilled(Primitive::Null),
+            span,
+        )?;
+        Ok(Primitive::Promise(result))
+    }
+
+    /// Continues an async function with the state of the promise it awaited. Awaiting a
+    /// rejected promise rejects the async function with the same reason.
+    fn resume_async(
+        &self,
+        coroutine: &Rc<RefCell<Generator<'a>>>,
+        result: &Rc<RefCell<Promise<'a>>>,
+        state: PromiseState<'a>,
+        span: Span,
+    ) -> Result<()> {
+        let value = match state {
+            PromiseState::Fulfilled(value) => value,
+            PromiseState::Rejected(reason) => {
+                coroutine.borrow_mut().complete();
+                self.reject_promise(result, reason);
+                return Ok(());
+            }
+            PromiseState::Pending => unreachable!("only settled promises are awaited"),
+        };
+        match self.resume_generator(coroutine, value, span)? {
+            GeneratorStep::Yield(awaited) => {
+                let reaction = Reaction::Await {
+                    coroutine: Rc::clone(coroutine),
+                    result: Rc::clone(result),
+                    span,
+                };
+                self.subscribe(&self.to_promise(awaited), reaction);
+            }
+            GeneratorStep::Return(value) => self.resolve_promise(result, value),
+        }
+        Ok(())
+    }
+
+    /// `then(on_fulfilled, on_rejected)` and `catch(on_rejected)` of a promise.
+    pub fn get_promise_method(
+        &self,
+        promise: Primitive<'a>,
+        name: Atom,
+        span: Span,
+    ) -> Result<Primitive<'a>> {
+        let catch = match name.as_str() {
+            "then" => false,
+            "catch" => true,
+            _ => return Err(diagnostics::CannotAccessProperty(span).into()),
+        };
+        Ok(Primitive::NativeFunction(NativeFunction::method(
+            name,
+            promise,
+            move |runtime, promise, arguments, span| {
+                let Primitive::Promise(promise) = promise else {
+                    unreachable!()
+                };
+                let mut handlers = arguments
+                    .into_iter()
+                    .map(|handler| match handler {
+                        Primitive::Null => None,
+                        handler => Some(handler),
+                    })
+                    .chain(std::iter::repeat(None));
+                let on_fulfilled = if catch {
+                    None
+                } else {
+                    handlers.next().flatten()
+                };
+                let on_rejected = handlers.next().flatten();
+                let derived = runtime.new_promise();
+                let reaction = Reaction::Then {
+                    on_fulfilled,
+                    on_rejected,
+                    derived: Rc::clone(&derived),
+                    span,
+                };
+                runtime.subscribe(promise, reaction);
+                Ok(Primitive::Promise(derived))
+            },
+        )))
+    }
+
+    /// `new Promise(executor)` calls the executor with the `resolve` and `reject` functions
+    /// of the new promise.
+    pub fn construct_promise(
+        &self,
+        arguments: StdVec<Primitive<'a>>,
+        span: Span,
+    ) -> Result<Primitive<'a>> {
+        let promise = self.new_promise();
+        let settle_function = |name: &str, reject: bool| {
+            Primitive::NativeFunction(NativeFunction::method(
+                name.into(),
+                Primitive::Promise(Rc::clone(&promise)),
+                move |runtime, promise, arguments, _| {
+                    let Primitive::Promise(promise) = promise else {
+                        unreachable!()
+                    };
+                    let value = arguments.into_iter().next().unwrap_or(Primitive::Null);
+                    if reject {
+                        runtime.reject_promise(promise, value);
+                    } else {
+                        runtime.resolve_promise(promise, value);
+                    }
+                    Ok(Primitive::Null)
+                },
+            ))
+        };
+        let resolve = settle_function("resolve", false);
+        let reject = settle_function("reject", true);
+
+        let Some(executor) = arguments.into_iter().next() else {
+            return Err(
+                diagnostics::InvalidNumberOfArguments("1 argument".to_string(), 0, span).into(),
+            );
+        };
+        let arguments = self.leading_arguments(&executor, vec![resolve, reject]);
+        self.apply_function(executor, arguments, span)?;
+        Ok(Primitive::Promise(promise))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::evaluator::Primitive;
+    use crate::event_loop::{Clock, VirtualClock};
+    use crate::test_utils::with_runtime;
+    use wave_span::Span;
+
+    /// Runs `source` on a virtual clock with a host function `later(value, delay)`, returning
+    /// the final `log` and the time the event loop finished at.
+    fn run(source: &str) -> wave_diagnostics::Result<(String, f64)> {
+        let clock = Rc::new(VirtualClock::default());
+        with_runtime(source, |runtime| {
+            let runtime = runtime.with_clock(clock.clone()).with_host_function(
+                "later",
+                |runtime, arguments, _| {
+                    let mut arguments = arguments.into_iter();
+                    let value = arguments.next().unwrap_or(Primitive::Null);
+                    let Some(Primitive::Number(delay)) = arguments.next() else {
+                        panic!("`later` takes a delay");
+                    };
+                    let promise = runtime.new_promise();
+                    let pending = Rc::clone(&promise);
+                    runtime.schedule(delay, move |runtime| {
+                        runtime.resolve_promise(&pending, value);
+                        Ok(())
+                    });
+                    Ok(Primitive::Promise(promise))
+                },
+            );
+            let environment = runtime.eval_environment()?;
+            let log = environment.borrow().get("log".into(), Span::default())?;
+            Ok((format!("{:?}", log), clock.now()))
+        })
+    }
+
+    #[test]
+    fn async_functions_and_timers() {
+        let source = "let log = [];
+            async function task(name, delay) {
+                let value = await later(name, delay);
+                log = [...log, value];
+                return delay;
+            }
+            setTimeout(function (name) { log = [...log, name]; }, 50, \"timer\");
+            task(\"slow\", 100).then(function (delay) { log = [...log, delay]; });
+            task(\"fast\", 10);
+            log = [...log, \"sync\"];";
+        assert_eq!(
+            run(source).unwrap(),
+            ("[sync, fast, timer, slow, 100]".to_string(), 100.0)
+        );
+
+        let cancelled = "let log = [];
+            let id = setTimeout(function () { log = [1]; }, 10);
+            clearTimeout(id);";
+        assert_eq!(run(cancelled).unwrap(), ("[]".to_string(), 0.0));
+    }
+
+    #[test]
+    fn promise_chains_and_rejections() {
+        let source = "let log = [];
+            async function value() { let x = await 1; return x + 1; }
+            new Promise(function (resolve) { resolve(value()); })
+                .then(function (v) { return later(v * 10, 5); })
+                .then(function (v) { log = [...log, v]; });
+            new Promise(function (resolve, reject) { reject(\"no\"); })
+                .then(function (v) { log = [...log, v]; })
+                .catch(function (reason) { log = [...log, reason]; });";
+        assert_eq!(run(source).unwrap(), ("[no, 20]".to_string(), 5.0));
+
+        let unhandled = "let log = [];
+            async function fail() { await new Promise(function (resolve, reject) { reject(1); }); }
+            fail();";
+        assert_eq!(
+            run(unhandled).unwrap_err().to_string(),
+            "Uncaught promise rejection: 1"
+        );
+    }
+
+    #[test]
+    fn await_in_expressions() {
+        let source = "let log = [];
+            let calls = 0;
+            function count() { calls = calls + 1; return calls; }
+            async function sum(a, b) { return (await later(a, 10)) + (await later(b, 20)); }
+            async function main() {
+                let x = await sum(1, 2) + 1;
+                if (await later(x > 3, 5)) { log = [...log, x]; }
+                log = [...log, [count(), await later(\"arg\", 5)]];
+                return await later(\"done\", 5);
+            }
+            main().then(function (v) { log = [...log, v, calls]; });";
+        // the call before the `await` is not repeated when the function resumes
+        assert_eq!(
+            run(source).unwrap(),
+            ("[4, [1, arg], done, 1]".to_string(), 45.0)
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::vec::Vec as StdVec;
+
+use crate::environment::Environment;
+use crate::evaluator::function::NativeFunction;
+use crate::evaluator::Primitive;
+use crate::{diagnostics, Runtime};
+
+impl<'a> Runtime<'a> {
+    /// The result of `typeof`. Built-in objects like Maps, generators and promises are instances.
+    pub fn type_of(&self, value: &Primitive<'a>) -> &'static str {
+        match value {
+            Primitive::Number(_) => "number",
+            Primitive::BigInt(_) => "bigint",
+            Primitive::String(_) => "string",
+            Primitive::Boolean(_) => "boolean",
+            Primitive::Array(_) => "array",
+            Primitive::Function(..)
+            | Primitive::GeneratorFunction(..)
+            | Primitive::AsyncFunction(..)
+            | Primitive::NativeFunction(_) => "function",
+            Primitive::Class(_) => "class",
+            Primitive::Instance(_)
+            | Primitive::This(_)
+            | Primitive::Generator(_)
+            | Primitive::Promise(_)
+            | Primitive::Map(_)
+            | Primitive::Set(_) => "instance",
+            Primitive::Null
+            | Primitive::Return(_)
+            | Primitive::Break(_)
+            | Primitive::Continue(_) => "null",
+        }
+    }
+
+    /// `classOf`, `fieldsOf` and `methodsOf`, defined as globals.
+    pub fn reflection_functions(&self) -> [NativeFunction<'a>; 3] {
+        let class_of = NativeFunction::new("classOf", |runtime, arguments, _| {
+            let class = match arguments.first() {
+                Some(Primitive::Instance(env) | Primitive::This(env)) => {
+                    runtime.class_env_of(env).map(Primitive::Class)
+                }
+                _ => None,
+            };
+            Ok(class.unwrap_or(Primitive::Null))
+        });
+        let fields_of = NativeFunction::new("fieldsOf", |runtime, arguments, span| {
+            let instance = match arguments.first() {
+                Some(Primitive::Instance(env) | Primitive::This(env)) => runtime.get_receiver(env),
+                _ => {
+                    return Err(diagnostics::InvalidReflectionTarget(
+                        "fieldsOf",
+                        "an instance",
+                        span,
+                    )
+                    .into())
+                }
+            };
+            let fields = runtime.public_names(&instance.borrow(), |_| true);
+            Ok(names_array(fields))
+        });
+        let methods_of = NativeFunction::new("methodsOf", |runtime, arguments, span| {
+            let Some(Primitive::Class(class_env)) = arguments.first() else {
+                return Err(
+                    diagnostics::InvalidReflectionTarget("methodsOf", "a class", span).into(),
+                );
+            };
+            let mut methods = vec![];
+            let mut current = Some(Rc::clone(class_env));
+            while let Some(class_env) = current {
+                methods.extend(runtime.public_names(&class_env.borrow(), is_method));
+                current = runtime.get_superclass(&class_env);
+            }
+            methods.retain(|name| name != "constructor");
+            Ok(names_array(methods))
+        });
+        [class_of, fields_of, methods_of]
+    }
+
+    /// The class an instance was created from, `None` for objects without a class.
+    fn class_env_of(
+        &self,
+        env: &Rc<RefCell<Environment<'a>>>,
+    ) -> Option<Rc<RefCell<Environment<'a>>>> {
+        let class_env = self.get_receiver(env).borrow().outer.clone()?;
+        self.is_class_env(&class_env).then_some(class_env)
+    }
+
+    /// The names of the members of `env` whose value matches `filter`, skipping private names.
+    fn public_names(
+        &self,
+        env: &Environment<'a>,
+        filter: impl Fn(&Primitive<'a>) -> bool,
+    ) -> StdVec<String> {
+        env.values
+            .iter()
+            .filter(|(_, value)| filter(value))
+            .filter_map(|(name, _)| name.as_str().strip_prefix("this_"))
+            .filter(|name| !name.starts_with('#'))
+            .map(String::from)
+            .collect()
+    }
+}
+
+fn is_method(value: &Primitive<'_>) -> bool {
+    matches!(
+        value,
+        Primitive::Function(..) | Primitive::GeneratorFunction(..) | Primitive::AsyncFunction(..)
+    )
+}
+
+/// Member names are stored in a hash map, so they are sorted to give a stable order.
+fn names_array<'a>(mut names: StdVec<String>) -> Primitive<'a> {
+    names.sort();
+    names.dedup();
+    Primitive::Array(names.into_iter().map(Primitive::String).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::eval;
+
+    #[test]
+    fn type_of() {
+        let source = "class A {}
+            function f() {}
+            [typeof 1, typeof 1n, typeof \"s\", typeof true, typeof [], typeof f,
+             typeof A, typeof new A(), typeof null, typeof new Map(), typeof 1 == \"number\"];";
+        assert_eq!(
+            eval(source).unwrap(),
+            "[number, bigint, string, boolean, array, function, class, instance, null, instance, true]"
+        );
+    }
+
+    #[test]
+    fn reflection() {
+        let source = "class Animal {
+                #secret = 1;
+                constructor(name) { this.name = name; }
+                speak() { return 1; }
+                get kind() { return \"animal\"; }
+            }
+            class Dog extends Animal {
+                age = 3;
+                bark() { return 2; }
+                static create() { return new Dog(\"x\"); }
+            }
+            let rex = new Dog(\"rex\");
+            let Class = classOf(rex);
+            [typeof Class, new Class(\"a\") instanceof Dog, classOf(1), fieldsOf(rex),
+             methodsOf(Dog), methodsOf(Animal)];";
+        assert_eq!(
+            eval(source).unwrap(),
+            "[class, true, Null, [age, name], [bark, speak], [speak]]"
+        );
+        assert_eq!(
+            eval("class A {} fieldsOf(A);").unwrap_err().to_string(),
+            "'fieldsOf' expects an instance."
+        );
+    }
+}
@@ -6,7 +6,10 @@ use crate::Runtime;
 use crate::{diagnostics, environment::Environment};
 use std::boxed::Box as StdBox;
 use wave_allocator::{Box, Vec};
-use wave_ast::ast::{ExpressionStatement, IfStatement, ReturnStatement, Statement, WhileStatement};
+use wave_ast::ast::{
+    ExpressionStatement, IfStatement, LabeledStatement, ReturnStatement, Statement,
+    SwitchStatement, WhileStatement,
+};
 use wave_diagnostics::Result;
 use wave_span::GetSpan;
 
@@ -16,6 +19,7 @@ impl<'a> Runtime<'a> {
         statement: &Statement<'a>,
         environment: Rc<RefCell<Environment<'a>>>,
     ) -> Result<Primitive<'a>> {
+        self.debug_statement(statement, &environment);
         match statement {
             Statement::ExpressionStatement(expression_stmt) => {
                 self.eval_expression_statement(expression_stmt, environment)
@@ -29,11 +33,27 @@ impl<'a> Runtime<'a> {
             Statement::WhileStatement(while_stmt) => {
                 self.eval_while_statement(while_stmt, environment)
             }
-            Statement::BreakStatement(_) => Ok(Primitive::Break),
-            Statement::ContinueStatement(_) => Ok(Primitive::Continue),
+            Statement::ForOfStatement(for_of) => {
+                self.eval_for_of_statement(for_of, &[], environment)
+            }
+            Statement::SwitchStatement(switch_stmt) => {
+                self.eval_switch_statement(switch_stmt, environment)
+            }
+            Statement::LabeledStatement(labeled_stmt) => {
+                self.eval_labeled_statement(labeled_stmt, environment)
+            }
+            Statement::BreakStatement(break_stmt) => Ok(Primitive::Break(
+                break_stmt.label.as_ref().map(|label| label.name.clone()),
+            )),
+            Statement::ContinueStatement(continue_stmt) => Ok(Primitive::Continue(
+                continue_stmt.label.as_ref().map(|label| label.name.clone()),
+            )),
             Statement::ModuleDeclaration(import_stmt) => {
                 self.eval_import_statement(import_stmt, environment)
             }
+            Statement::InvalidStatement(invalid_stmt) => {
+                Err(diagnostics::InvalidStatement(invalid_stmt.span).into())
+            }
         }
     }
 
@@ -55,7 +75,7 @@ impl<'a> Runtime<'a> {
             if matches!(result, Primitive::Return(_)) {
                 return Ok(result);
             }
-            if matches!(result, Primitive::Break | Primitive::Continue) {
+            if matches!(result, Primitive::Break(_) | Primitive::Continue(_)) {
                 return Ok(result);
             }
         }
@@ -68,16 +88,12 @@ impl<'a> Runtime<'a> {
         environment: Rc<RefCell<Environment<'a>>>,
     ) -> Result<Primitive<'a>> {
         let test = self.eval_expression(&if_stmt.test, Rc::clone(&environment))?;
-        match test {
-            Primitive::Boolean(true) => self.eval_statement(&if_stmt.consequent, environment),
-            Primitive::Boolean(false) => {
-                if let Some(alternate) = &if_stmt.alternate {
-                    self.eval_statement(alternate, environment)
-                } else {
-                    Ok(Primitive::Null)
-                }
-            }
-            _ => Err(diagnostics::InvalidBoolean(if_stmt.test.span()).into()),
+        if test.is_truthy() {
+            self.eval_statement(&if_stmt.consequent, environment)
+        } else if let Some(alternate) = &if_stmt.alternate {
+            self.eval_statement(alternate, environment)
+        } else {
+            Ok(Primitive::Null)
         }
     }
 
@@ -94,22 +110,125 @@ impl<'a> Runtime<'a> {
         }
     }
 
+    /// Runs from the first matching case, or `default`, until a `break`.
+    pub fn eval_switch_statement(
+        &self,
+        switch_stmt: &Box<'_, SwitchStatement<'a>>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        let Some(start) = self.find_switch_case(switch_stmt, Rc::clone(&environment))? else {
+            return Ok(Primitive::Null);
+        };
+
+        for case in switch_stmt.cases.iter().skip(start) {
+            match self.eval_block(&case.consequent, Rc::clone(&environment))? {
+                Primitive::Break(None) => break,
+                result @ (Primitive::Return(_) | Primitive::Break(_) | Primitive::Continue(_)) => {
+                    return Ok(result)
+                }
+                _ => {}
+            }
+        }
+        Ok(Primitive::Null)
+    }
+
+    /// The index of the first case matching the discriminant, or of the `default` case.
+    pub fn find_switch_case(
+        &self,
+        switch_stmt: &SwitchStatement<'a>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Option<usize>> {
+        let discriminant =
+            self.eval_expression(&switch_stmt.discriminant, Rc::clone(&environment))?;
+
+        for (index, case) in switch_stmt.cases.iter().enumerate() {
+            if let Some(test) = &case.test {
+                let test_value = self.eval_expression(test, Rc::clone(&environment))?;
+                if self.equals(&discriminant, &test_value, test.span())? {
+                    return Ok(Some(index));
+                }
+            }
+        }
+        Ok(switch_stmt
+            .cases
+            .iter()
+            .position(|case| case.is_default_case()))
+    }
+
     pub fn eval_while_statement(
         &self,
         while_stmt: &Box<'_, WhileStatement<'a>>,
         environment: Rc<RefCell<Environment<'a>>>,
     ) -> Result<Primitive<'a>> {
-        let test = self.eval_expression(&while_stmt.test, Rc::clone(&environment))?;
-        match test {
-            Primitive::Boolean(true) => {
-                let eval = self.eval_statement(&while_stmt.body, Rc::clone(&environment))?;
-                if !matches!(eval, Primitive::Break) {
-                    let _ = self.eval_while_statement(while_stmt, environment);
-                }
-                Ok(Primitive::Null)
+        loop {
+            let test = self.eval_expression(&while_stmt.test, Rc::clone(&environment))?;
+            if !test.is_truthy() {
+                return Ok(Primitive::Null);
+            }
+            match self.eval_statement(&while_stmt.body, Rc::clone(&environment))? {
+                Primitive::Break(None) => return Ok(Primitive::Null),
+                // Labeled jumps are handled by the enclosing labeled statement
+                result @ (Primitive::Return(_)
+                | Primitive::Break(_)
+                | Primitive::Continue(Some(_))) => return Ok(result),
+                _ => {}
+            }
+        }
+    }
+
+    /// A `continue` targeting this label re-enters the labeled loop, which tests its condition again.
+    pub fn eval_labeled_statement(
+        &self,
+        labeled_stmt: &Box<'_, LabeledStatement<'a>>,
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<Primitive<'a>> {
+        let name = &labeled_stmt.label.name;
+
+        // A `for-of` continues itself, re-entering it would restart the iteration
+        let mut labels = vec![name.clone()];
+        let mut body = &labeled_stmt.body;
+        while let Statement::LabeledStatement(inner) = body {
+            labels.push(inner.label.name.clone());
+            body = &inner.body;
+        }
+        if let Statement::ForOfStatement(for_of) = body {
+            return match self.eval_for_of_statement(for_of, &labels, environment)? {
+                Primitive::Break(Some(label)) if labels.contains(&label) => Ok(Primitive::Null),
+                result => Ok(result),
+            };
+        }
+
+        loop {
+            match self.eval_statement(&labeled_stmt.body, Rc::clone(&environment))? {
+                Primitive::Break(Some(label)) if label == *name => return Ok(Primitive::Null),
+                Primitive::Continue(Some(label)) if label == *name => {}
+                result => return Ok(result),
             }
-            Primitive::Boolean(false) => Ok(Primitive::Null),
-            _ => Err(diagnostics::InvalidBoolean(while_stmt.test.span()).into()),
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::eval;
+
+    #[test]
+    fn labeled_break_and_continue() {
+        let source = "let out = []; let i = 0;
+            outer: while (i < 3) {
+                i = i + 1; let j = 0;
+                while (j < 3) {
+                    j = j + 1;
+                    if (j == 2) { continue outer; }
+                    if (i == 3) { break outer; }
+                    out = [...out, [i, j]];
+                }
+            }
+            out;";
+        assert_eq!(eval(source).unwrap(), "[[1, 1], [2, 1]]");
+        assert_eq!(
+            eval("let x = 1; block: { x = 2; break block; x = 3; } x;").unwrap(),
+            "2"
+        );
+    }
+}
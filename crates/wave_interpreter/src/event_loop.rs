@@ -0,0 +1,174 @@
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use crate::evaluator::promise::{Promise, PromiseState, Reaction};
+use crate::evaluator::Primitive;
+use crate::{diagnostics, Runtime};
+use wave_diagnostics::Result;
+use wave_span::Span;
+
+/// The time source of the event loop, in milliseconds since the runtime started.
+pub trait Clock {
+    fn now(&self) -> f64;
+    /// Waits until `time`, called when only timers are left to run.
+    fn advance_to(&self, time: f64);
+}
+
+/// Wall clock time, waiting for a timer sleeps the thread.
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> f64 {
+        self.start.elapsed().as_secs_f64() * 1000.0
+    }
+
+    fn advance_to(&self, time: f64) {
+        let remaining = time - self.now();
+        if remaining > 0.0 {
+            std::thread::sleep(Duration::from_secs_f64(remaining / 1000.0));
+        }
+    }
+}
+
+/// A clock that jumps straight to the next timer, so tests run timers without waiting.
+#[derive(Default)]
+pub struct VirtualClock {
+    now: Cell<f64>,
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> f64 {
+        self.now.get()
+    }
+
+    fn advance_to(&self, time: f64) {
+        self.now.set(self.now.get().max(time));
+    }
+}
+
+/// Work scheduled by the host, run with the runtime once its turn comes.
+pub type HostTask<'a> = Box<dyn FnOnce(&Runtime<'a>) -> Result<()> + 'a>;
+
+/// A unit of work queued on the event loop.
+pub enum Task<'a> {
+    /// Calls a function of the script with the arguments, like a `setTimeout` callback
+    Call(Primitive<'a>, Vec<Primitive<'a>>),
+    /// Runs the reaction to a settled promise
+    Reaction(Reaction<'a>, PromiseState<'a>),
+    /// Work scheduled by the host, like resolving the promise a host function returned
+    Host(HostTask<'a>),
+}
+
+struct Timer<'a> {
+    id: u64,
+    due: f64,
+    task: Task<'a>,
+}
+
+/// The queues of the single-threaded event loop. Every microtask, like the continuation of an
+/// `await`, runs before the next timer.
+#[derive(Default)]
+pub struct EventLoop<'a> {
+    microtasks: VecDeque<Task<'a>>,
+    timers: Vec<Timer<'a>>,
+    next_timer_id: u64,
+    /// Promises rejected without a handler, reported once the microtasks are drained
+    rejections: Vec<Rc<RefCell<Promise<'a>>>>,
+}
+
+impl<'a> EventLoop<'a> {
+    pub fn queue_microtask(&mut self, task: Task<'a>) {
+        self.microtasks.push_back(task);
+    }
+
+    /// Queues `task` to run at `due`, timers due at the same time run in the order they were added.
+    pub fn add_timer(&mut self, due: f64, task: Task<'a>) -> u64 {
+        self.next_timer_id += 1;
+        let id = self.next_timer_id;
+        self.timers.push(Timer { id, due, task });
+        id
+    }
+
+    pub fn cancel_timer(&mut self, id: u64) {
+        self.timers.retain(|timer| timer.id != id);
+    }
+
+    pub fn track_rejection(&mut self, promise: Rc<RefCell<Promise<'a>>>) {
+        self.rejections.push(promise);
+    }
+
+    fn next_timer(&mut self) -> Option<Timer<'a>> {
+        let index = self
+            .timers
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.due.total_cmp(&b.due).then(a.id.cmp(&b.id)))
+            .map(|(index, _)| index)?;
+        Some(self.timers.remove(index))
+    }
+}
+
+impl<'a> Runtime<'a> {
+    /// Runs queued work until no microtask or timer is left.
+    pub fn run_event_loop(&self) -> Result<()> {
+        loop {
+            self.run_microtasks()?;
+            let timer = self.event_loop.borrow_mut().next_timer();
+            let Some(timer) = timer else {
+                return Ok(());
+            };
+            self.clock.advance_to(timer.due);
+            self.run_task(timer.task)?;
+        }
+    }
+
+    fn run_microtasks(&self) -> Result<()> {
+        loop {
+            let task = self.event_loop.borrow_mut().microtasks.pop_front();
+            let Some(task) = task else {
+                break;
+            };
+            self.run_task(task)?;
+        }
+
+        let rejections = std::mem::take(&mut self.event_loop.borrow_mut().rejections);
+        for promise in rejections {
+            let promise = promise.borrow();
+            if let (false, PromiseState::Rejected(reason)) = (promise.handled, &promise.state) {
+                return Err(diagnostics::UnhandledRejection(format!("{:?}", reason)).into());
+            }
+        }
+        Ok(())
+    }
+
+    fn run_task(&self, task: Task<'a>) -> Result<()> {
+        match task {
+            Task::Call(function, arguments) => {
+                self.apply_function(function, arguments, Span::default())?;
+                Ok(())
+            }
+            Task::Reaction(reaction, state) => self.run_reaction(reaction, state),
+            Task::Host(task) => task(self),
+        }
+    }
+
+    /// Runs `task` from the event loop after `delay` milliseconds, returning the id of its timer.
+    pub fn schedule(&self, delay: f64, task: impl FnOnce(&Runtime<'a>) -> Result<()> + 'a) -> u64 {
+        let due = self.clock.now() + delay.max(0.0);
+        self.event_loop
+            .borrow_mut()
+            .add_timer(due, Task::Host(Box::new(task)))
+    }
+}
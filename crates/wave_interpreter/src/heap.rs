@@ -0,0 +1,305 @@
+use std::cell::RefCell;
+use std::mem;
+use std::rc::{Rc, Weak};
+
+use crate::environment::Environment;
+use crate::evaluator::Primitive;
+use crate::Runtime;
+use rustc_hash::FxHashMap;
+
+/// Collections run once this many environments were allocated since the last one, or as many
+/// as survived it if that is more.
+pub const DEFAULT_GC_THRESHOLD: usize = 10_000;
+
+/// Environments hold the scopes of calls and blocks, but also instances, classes and the
+/// environments closures capture. They reference each other with `Rc`, so an instance storing
+/// a closure or two instances referencing each other form cycles reference counting never frees.
+///
+/// The heap keeps a weak reference to every environment and collects the unreachable cycles.
+/// An environment is reachable when something outside of the environments holds it, like a
+/// local of the interpreter, the global environment the host keeps or a value queued on the
+/// event loop, or when a reachable environment references it.
+pub struct Heap<'a> {
+    environments: Vec<Weak<RefCell<Environment<'a>>>>,
+    threshold: usize,
+    allocated_since_collection: usize,
+    allocated: usize,
+    collections: usize,
+    reclaimed: usize,
+}
+
+impl<'a> Default for Heap<'a> {
+    fn default() -> Self {
+        Self::with_threshold(DEFAULT_GC_THRESHOLD)
+    }
+}
+
+/// Counters of the heap, to observe that cycles are reclaimed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeapStats {
+    /// Environments that are still allocated, freed ones are only noticed by a collection
+    /// or by this count
+    pub live: usize,
+    /// Environments allocated since the runtime was created
+    pub allocated: usize,
+    pub collections: usize,
+    /// Environments freed by collections, the ones reference counting freed are not included
+    pub reclaimed: usize,
+}
+
+impl<'a> Heap<'a> {
+    pub fn with_threshold(threshold: usize) -> Self {
+        Self {
+            environments: vec![],
+            threshold,
+            allocated_since_collection: 0,
+            allocated: 0,
+            collections: 0,
+            reclaimed: 0,
+        }
+    }
+
+    fn register(&mut self, environment: &Rc<RefCell<Environment<'a>>>) {
+        self.environments.push(Rc::downgrade(environment));
+        self.allocated += 1;
+        self.allocated_since_collection += 1;
+    }
+
+    fn should_collect(&self) -> bool {
+        self.allocated_since_collection >= self.threshold.max(self.environments.len() / 2)
+    }
+
+    pub fn stats(&self) -> HeapStats {
+        HeapStats {
+            live: self
+                .environments
+                .iter()
+                .filter(|environment| environment.strong_count() > 0)
+                .count(),
+            allocated: self.allocated,
+            collections: self.collections,
+            reclaimed: self.reclaimed,
+        }
+    }
+
+    /// Frees the environments only reachable from each other, returning how many there were.
+    ///
+    /// Counting how often the environments reference each other tells which ones are also held
+    /// from outside, the roots. Whatever is not reachable from a root is garbage, and clearing
+    /// it breaks its cycles so reference counting frees it. References held by shared values,
+    /// like the entries of a Map or the frames of a generator, count as outside references,
+    /// so cycles through them are kept rather than risking freeing a live environment.
+    pub fn collect(&mut self) -> usize {
+        let nodes = self
+            .environments
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect::<Vec<_>>();
+        let index = nodes
+            .iter()
+            .enumerate()
+            .map(|(position, node)| (Rc::as_ptr(node), position))
+            .collect::<FxHashMap<_, _>>();
+
+        // the targets of the references inside each environment, `None` when it is borrowed
+        let edges = nodes
+            .iter()
+            .map(|node| {
+                let environment = node.try_borrow().ok()?;
+                let mut targets = vec![];
+                trace_environment(&environment, &mut |target| {
+                    if let Some(position) = index.get(&Rc::as_ptr(target)) {
+                        targets.push(*position);
+                    }
+                });
+                Some(targets)
+            })
+            .collect::<Vec<_>>();
+
+        let mut internal = vec![0; nodes.len()];
+        for target in edges.iter().flatten().flatten() {
+            internal[*target] += 1;
+        }
+
+        let mut reachable = vec![false; nodes.len()];
+        let mut pending = (0..nodes.len())
+            .filter(|&position| {
+                // `nodes` holds one of the strong references itself
+                Rc::strong_count(&nodes[position]) > 1 + internal[position]
+                    || edges[position].is_none()
+            })
+            .collect::<Vec<_>>();
+        while let Some(position) = pending.pop() {
+            if mem::replace(&mut reachable[position], true) {
+                continue;
+            }
+            pending.extend(edges[position].iter().flatten());
+        }
+
+        let mut garbage = vec![];
+        for (position, node) in nodes.iter().enumerate() {
+            if !reachable[position] {
+                let mut environment = node.borrow_mut();
+                garbage.push((
+                    mem::take(&mut environment.values),
+                    environment.outer.take(),
+                    mem::take(&mut environment.fields),
+                ));
+            }
+        }
+        let reclaimed = garbage.len();
+        // dropped while `nodes` still holds every environment, so no drop frees a cleared one
+        drop(garbage);
+
+        self.environments = nodes
+            .iter()
+            .zip(&reachable)
+            .filter(|(_, reachable)| **reachable)
+            .map(|(node, _)| Rc::downgrade(node))
+            .collect();
+        self.allocated_since_collection = 0;
+        self.collections += 1;
+        self.reclaimed += reclaimed;
+        reclaimed
+    }
+}
+
+/// Calls `visit` with every environment `environment` holds a strong reference to.
+fn trace_environment<'a>(
+    environment: &Environment<'a>,
+    visit: &mut impl FnMut(&Rc<RefCell<Environment<'a>>>),
+) {
+    if let Some(outer) = &environment.outer {
+        visit(outer);
+    }
+    for value in environment.values.values() {
+        trace_value(value, visit);
+    }
+}
+
+fn trace_value<'a>(value: &Primitive<'a>, visit: &mut impl FnMut(&Rc<RefCell<Environment<'a>>>)) {
+    match value {
+        Primitive::Function(_, _, environment)
+        | Primitive::GeneratorFunction(_, _, environment)
+        | Primitive::AsyncFunction(_, _, environment)
+        | Primitive::Class(environment)
+        | Primitive::Instance(environment)
+        | Primitive::This(environment) => visit(environment),
+        Primitive::Array(values) => {
+            for value in values {
+                trace_value(value, visit);
+            }
+        }
+        Primitive::Return(value) => trace_value(value, visit),
+        // shared values are opaque, what they reference counts as referenced from outside
+        Primitive::NativeFunction(_)
+        | Primitive::Generator(_)
+        | Primitive::Promise(_)
+        | Primitive::Map(_)
+        | Primitive::Set(_)
+        | Primitive::Number(_)
+        | Primitive::BigInt(_)
+        | Primitive::Boolean(_)
+        | Primitive::String(_)
+        | Primitive::Break(_)
+        | Primitive::Continue(_)
+        | Primitive::Null => {}
+    }
+}
+
+impl<'a> Runtime<'a> {
+    /// Allocates an environment on the heap, collecting garbage first when enough environments
+    /// were allocated since the last collection.
+    pub fn alloc_environment(&self, environment: Environment<'a>) -> Rc<RefCell<Environment<'a>>> {
+        if self.heap.borrow().should_collect() {
+            self.collect_garbage();
+        }
+        let environment = Rc::new(RefCell::new(environment));
+        self.heap.borrow_mut().register(&environment);
+        environment
+    }
+
+    /// Frees the unreachable cycles of environments, returning how many environments were freed.
+    pub fn collect_garbage(&self) -> usize {
+        self.heap.borrow_mut().collect()
+    }
+
+    pub fn heap_stats(&self) -> HeapStats {
+        self.heap.borrow().stats()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::with_runtime;
+    use wave_span::Span;
+
+    const NODES: &str = "class Node {
+            constructor(id) {
+                this.id = id;
+                this.other = null;
+                this.handler = function () { return id; };
+            }
+        }
+        function pair(id) {
+            let a = new Node(id);
+            let b = new Node(id + 1);
+            a.other = b;
+            b.other = a;
+            return null;
+        }";
+
+    #[test]
+    fn collects_cycles() {
+        let source = format!(
+            "{NODES}
+            let kept = new Node(1);
+            kept.other = new Node(2);
+            kept.other.other = kept;
+            let cache = new Map();
+            cache.set(\"self\", cache);
+            let i = 0;
+            while (i < 100) {{ pair(i); i = i + 1; }}"
+        );
+        with_runtime(&source, |runtime| {
+            let environment = runtime.eval_environment()?;
+
+            let before = runtime.heap_stats();
+            // each node is an instance, the scope of its constructor and the method binding it
+            assert_eq!(runtime.collect_garbage(), 100 * 2 * 3);
+            let after = runtime.heap_stats();
+            assert_eq!(after.live, before.live - 600);
+            assert_eq!(after.reclaimed, 600);
+            assert_eq!(runtime.collect_garbage(), 0);
+
+            // the cycle held by a global survives
+            let kept = environment.borrow().get("kept".into(), Span::default())?;
+            let other = runtime.read_property(kept, "other".into(), Span::default())?;
+            let back = runtime.read_property(other, "other".into(), Span::default())?;
+            let id = runtime.read_property(back, "id".into(), Span::default())?;
+            assert_eq!(format!("{id:?}"), "1");
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn collects_automatically() {
+        let source = format!(
+            "{NODES}
+            let i = 0;
+            while (i < 1000) {{ pair(i); i = i + 1; }}"
+        );
+        with_runtime(&source, |runtime| {
+            let runtime = runtime.with_gc_threshold(500);
+            runtime.eval()?;
+
+            let stats = runtime.heap_stats();
+            assert!(stats.collections > 0);
+            assert!(stats.reclaimed > 0);
+            assert!(stats.live < 1000, "{stats:?}");
+            Ok(())
+        })
+        .unwrap();
+    }
+}
@@ -1,6 +1,12 @@
+pub mod debugger;
 mod diagnostics;
 pub mod environment;
 pub mod evaluator;
+pub mod event_loop;
+pub mod heap;
 pub mod runtime;
+pub mod stdlib;
+#[cfg(test)]
+pub(crate) mod test_utils;
 
 pub use runtime::Runtime;
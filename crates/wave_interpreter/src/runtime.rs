@@ -1,28 +1,57 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
 
 use crate::{
+    debugger::Debugger,
     diagnostics,
     environment::Environment,
-    evaluator::{function::InbuiltFunction, Primitive},
+    evaluator::{
+        function::{InbuiltFunction, NativeFunction},
+        generator::Replay,
+        iterator::array_from,
+        Primitive,
+    },
+    event_loop::{Clock, EventLoop, SystemClock, Task},
+    heap::Heap,
+    stdlib::{json, math},
 };
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, ToPrimitive};
 use wave_ast::ast::Program;
 use wave_diagnostics::Result;
+use wave_span::Span;
 
 pub struct Runtime<'a> {
     pub program: Program<'a>,
     pub inbuilt_functions: Vec<InbuiltFunction>,
+    /// Whether scripts may import `std:io` and touch the file system or stdin.
+    pub allow_io: bool,
+    /// Globals implemented by the host, they may return pending promises
+    pub host_functions: Vec<NativeFunction<'a>>,
+    /// The time source of timers, tests use a [`crate::event_loop::VirtualClock`]
+    pub clock: Rc<dyn Clock>,
+    pub event_loop: RefCell<EventLoop<'a>>,
+    /// Every environment the runtime allocated, to collect the ones caught in cycles
+    pub heap: RefCell<Heap<'a>>,
+    pub debugger: RefCell<Option<Box<dyn Debugger<'a> + 'a>>>,
+    /// The statements of the running generators and async functions, the innermost is last
+    pub(crate) replays: RefCell<Vec<Replay<'a>>>,
+    /// The state of `random` from `std:math`, each runtime has its own sequence
+    pub random_state: Cell<u64>,
 }
 
 impl<'a> Runtime<'a> {
     pub fn new(program: Program<'a>) -> Self {
         let mut inbuilt_functions = vec![];
 
-        fn print(arg: &[Primitive]) -> Result<Primitive<'static>> {
+        fn print<'a>(_: &Runtime<'a>, arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
             println!("{:?}", arg);
             Ok(Primitive::Null)
         }
 
-        fn append(arg: &[Primitive]) -> Result<Primitive<'static>> {
+        fn append<'a>(_: &Runtime<'a>, arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
             let Primitive::Array(array) = &arg[0] else {
                 return Err(diagnostics::NotAnArray().into());
             };
@@ -32,20 +61,50 @@ impl<'a> Runtime<'a> {
             for primitive in arg.iter().skip(1) {
                 array.push(primitive.clone());
             }
-            unsafe {
-                return Ok(Primitive::Array(std::mem::transmute::<
-                    Vec<Primitive>,
-                    Vec<Primitive<'static>>,
-                >(array)));
-            };
+            Ok(Primitive::Array(array))
         }
 
-        fn contains(arg: &[Primitive]) -> Result<Primitive<'static>> {
+        /// Elements are compared like `==` compares them.
+        fn contains<'a>(runtime: &Runtime<'a>, arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
             let Primitive::Array(array) = &arg[0] else {
                 return Err(diagnostics::NotAnArray().into());
             };
             let value = &arg[1];
-            Ok(Primitive::Boolean(array.contains(value)))
+            for element in array {
+                if runtime.equals(element, value, Span::default())? {
+                    return Ok(Primitive::Boolean(true));
+                }
+            }
+            Ok(Primitive::Boolean(false))
+        }
+
+        /// Integral numbers and decimal strings convert exactly, anything else is an error.
+        fn bigint<'a>(_: &Runtime<'a>, arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+            let value = match arg.first() {
+                Some(Primitive::BigInt(value)) => Some(value.clone()),
+                Some(Primitive::Number(value)) if value.fract() == 0.0 => BigInt::from_f64(*value),
+                Some(Primitive::String(value)) => value.trim().parse::<BigInt>().ok(),
+                _ => None,
+            };
+            value.map(Primitive::BigInt).ok_or_else(|| {
+                let value = arg
+                    .first()
+                    .map_or("nothing".to_string(), |v| format!("{:?}", v));
+                diagnostics::InvalidBigIntConversion(value).into()
+            })
+        }
+
+        /// Converting a BigInt rounds to the nearest representable number.
+        fn number<'a>(_: &Runtime<'a>, arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+            let value = match arg.first() {
+                Some(Primitive::Number(value)) => *value,
+                Some(Primitive::BigInt(value)) => value.to_f64().unwrap_or(f64::NAN),
+                Some(Primitive::Boolean(value)) => f64::from(u8::from(*value)),
+                Some(Primitive::String(value)) => value.trim().parse().unwrap_or(f64::NAN),
+                Some(Primitive::Null) => 0.0,
+                _ => f64::NAN,
+            };
+            Ok(Primitive::Number(value))
         }
 
         inbuilt_functions.push(InbuiltFunction {
@@ -61,15 +120,145 @@ impl<'a> Runtime<'a> {
             function: contains,
         });
 
+        inbuilt_functions.push(InbuiltFunction {
+            name: "BigInt".into(),
+            function: bigint,
+        });
+        inbuilt_functions.push(InbuiltFunction {
+            name: "Number".into(),
+            function: number,
+        });
+
         Self {
             program,
             inbuilt_functions,
+            allow_io: true,
+            host_functions: vec![],
+            clock: Rc::new(SystemClock::default()),
+            event_loop: RefCell::default(),
+            heap: RefCell::default(),
+            debugger: RefCell::default(),
+            replays: RefCell::default(),
+            random_state: Cell::new(math::DEFAULT_SEED),
         }
     }
 
+    /// Enable or disable the `std:io` module, sandboxed hosts should turn it off.
+    #[must_use]
+    pub fn with_io(mut self, yes: bool) -> Self {
+        self.allow_io = yes;
+        self
+    }
+
+    /// Collect garbage once `threshold` environments were allocated since the last collection,
+    /// or as many as survived it if that is more.
+    #[must_use]
+    pub fn with_gc_threshold(mut self, threshold: usize) -> Self {
+        self.heap = RefCell::new(Heap::with_threshold(threshold));
+        self
+    }
+
+    #[must_use]
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    #[must_use]
+    pub fn with_debugger(mut self, debugger: impl Debugger<'a> + 'a) -> Self {
+        self.debugger = RefCell::new(Some(Box::new(debugger)));
+        self
+    }
+
+    /// Defines a global implemented in Rust. It can return a promise from
+    /// [`Runtime::new_promise`] and settle it later from a task queued with [`Runtime::schedule`].
+    #[must_use]
+    pub fn with_host_function(
+        mut self,
+        name: &str,
+        function: impl Fn(&Runtime<'a>, Vec<Primitive<'a>>, Span) -> Result<Primitive<'a>> + 'a,
+    ) -> Self {
+        self.host_functions
+            .push(NativeFunction::new(name, function));
+        self
+    }
+
+    /// Evaluates the program, then runs the event loop until no timer or promise reaction is left.
     pub fn eval(&self) -> Result<Primitive<'a>> {
-        let environment = Rc::new(RefCell::new(Environment::default()));
-        self.eval_program(&self.program, environment.clone())
+        let environment = self.global_environment();
+        let result = self.eval_program(&self.program, environment)?;
+        self.run_event_loop()?;
+        Ok(result)
+    }
+
+    fn global_environment(&self) -> Rc<RefCell<Environment<'a>>> {
+        let mut environment = Environment::default();
+
+        let set_timeout = NativeFunction::new("setTimeout", |runtime, arguments, span| {
+            let mut arguments = arguments.into_iter();
+            let Some(callback) = arguments.next() else {
+                return Err(diagnostics::InvalidNumberOfArguments(
+                    "at least 1 argument".into(),
+                    0,
+                    span,
+                )
+                .into());
+            };
+            let delay = match arguments.next() {
+                Some(Primitive::Number(delay)) => delay.max(0.0),
+                _ => 0.0,
+            };
+            let due = runtime.clock.now() + delay;
+            let task = Task::Call(callback, arguments.collect());
+            let id = runtime.event_loop.borrow_mut().add_timer(due, task);
+            Ok(Primitive::Number(id as f64))
+        });
+        let clear_timeout = NativeFunction::new("clearTimeout", |runtime, arguments, _| {
+            if let Some(Primitive::Number(id)) = arguments.first() {
+                runtime.event_loop.borrow_mut().cancel_timer(*id as u64);
+            }
+            Ok(Primitive::Null)
+        });
+        let promise = NativeFunction::new("Promise", |runtime, arguments, span| {
+            runtime.construct_promise(arguments, span)
+        });
+
+        let map = NativeFunction::new("Map", |runtime, arguments, span| {
+            runtime.construct_collection(false, arguments, span)
+        });
+        let set = NativeFunction::new("Set", |runtime, arguments, span| {
+            runtime.construct_collection(true, arguments, span)
+        });
+
+        let globals = [set_timeout, clear_timeout, promise, map, set];
+        for function in globals
+            .into_iter()
+            .chain(self.reflection_functions())
+            .chain(self.host_functions.iter().cloned())
+        {
+            environment.define(function.name.clone(), Primitive::NativeFunction(function));
+        }
+
+        // `JSON.parse` and `JSON.stringify` are the exports of `std:json` as members of an object
+        let mut namespace = Environment::default();
+        for (name, value) in json::module().exports {
+            namespace.define(self.bind_this(name), value);
+        }
+        environment.define(
+            "JSON".into(),
+            Primitive::Instance(self.alloc_environment(namespace)),
+        );
+        let mut namespace = Environment::default();
+        let from = NativeFunction::new("from", array_from);
+        namespace.define(
+            self.bind_this("from".into()),
+            Primitive::NativeFunction(from),
+        );
+        environment.define(
+            "Array".into(),
+            Primitive::Instance(self.alloc_environment(namespace)),
+        );
+        self.alloc_environment(environment)
     }
 
     pub fn eval_program(
@@ -85,8 +274,9 @@ impl<'a> Runtime<'a> {
     }
 
     pub fn eval_environment(&self) -> Result<Rc<RefCell<Environment<'a>>>> {
-        let environment = Rc::new(RefCell::new(Environment::default()));
+        let environment = self.global_environment();
         self.eval_program(&self.program, environment.clone())?;
+        self.run_event_loop()?;
         Ok(environment)
     }
 }
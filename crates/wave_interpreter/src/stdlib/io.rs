@@ -0,0 +1,128 @@
+use std::{fs, io::BufRead, path::Path};
+
+use crate::{
+    diagnostics,
+    evaluator::Primitive,
+    stdlib::{expect_string, native_function, NativeModule},
+};
+use wave_diagnostics::Result;
+
+pub fn module<'a>() -> NativeModule<'a> {
+    NativeModule {
+        name: "io",
+        exports: vec![
+            native_function("readFile", read_file),
+            native_function("writeFile", write_file),
+            native_function("readLine", read_line),
+            native_function("exists", exists),
+            native_function("listDir", list_dir),
+        ],
+    }
+}
+
+fn read_file<'a>(arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    let path = expect_string("readFile", arg, 0)?;
+    fs::read_to_string(path)
+        .map(Primitive::String)
+        .map_err(|error| diagnostics::IoFailure("read", path.to_string(), error).into())
+}
+
+fn write_file<'a>(arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    let path = expect_string("writeFile", arg, 0)?;
+    let contents = expect_string("writeFile", arg, 1)?;
+    fs::write(path, contents)
+        .map(|_| Primitive::Null)
+        .map_err(|error| diagnostics::IoFailure("write", path.to_string(), error).into())
+}
+
+/// Reads a line from stdin without its line terminator, `null` once stdin is exhausted.
+fn read_line<'a>(_: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    let mut line = String::new();
+    let read = std::io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .map_err(|error| diagnostics::IoFailure("read", "stdin".to_string(), error))?;
+
+    if read == 0 {
+        return Ok(Primitive::Null);
+    }
+
+    let trimmed = line.trim_end_matches(['\n', '\r']).len();
+    line.truncate(trimmed);
+    Ok(Primitive::String(line))
+}
+
+fn exists<'a>(arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    let path = expect_string("exists", arg, 0)?;
+    Ok(Primitive::Boolean(Path::new(path).exists()))
+}
+
+/// Entries are sorted by name so scripts behave the same on every platform.
+fn list_dir<'a>(arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    let path = expect_string("listDir", arg, 0)?;
+    let entries = fs::read_dir(path)
+        .map_err(|error| diagnostics::IoFailure("list", path.to_string(), error))?;
+
+    let mut names = vec![];
+    for entry in entries {
+        let entry =
+            entry.map_err(|error| diagnostics::IoFailure("list", path.to_string(), error))?;
+        names.push(entry.file_name().to_string_lossy().into_owned());
+    }
+    names.sort();
+
+    Ok(Primitive::Array(
+        names.into_iter().map(Primitive::String).collect(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{exists, list_dir, read_file, write_file};
+    use crate::diagnostics;
+    use crate::evaluator::Primitive;
+    use crate::test_utils::{eval, with_runtime};
+
+    #[test]
+    fn file_round_trip() {
+        // unique per process so concurrent runs don't share files
+        let dir =
+            std::env::temp_dir().join(format!("wave_io_{}_file_round_trip", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("hello.txt").to_string_lossy().into_owned();
+
+        let path = Primitive::String(file.clone());
+        let contents = Primitive::String("hello wave".to_string());
+        write_file(&[path.clone(), contents.clone()]).unwrap();
+
+        let read = read_file(std::slice::from_ref(&path)).unwrap();
+        assert_eq!(format!("{read:?}"), "hello wave");
+        assert_eq!(format!("{:?}", exists(&[path]).unwrap()), "true");
+
+        let listing = list_dir(&[Primitive::String(dir.to_string_lossy().into_owned())]);
+        assert_eq!(format!("{:?}", listing.unwrap()), "[hello.txt]");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn read_missing_file() {
+        let path = Primitive::String("/wave/does/not/exist".to_string());
+        assert!(read_file(&[path]).is_err());
+        assert!(read_file(&[Primitive::Number(1.0)]).is_err());
+    }
+
+    #[test]
+    fn import_exports() {
+        let source = r#"import { exists } from "std:io"; exists("/wave/does/not/exist");"#;
+        assert_eq!(eval(source).unwrap(), "false");
+    }
+
+    #[test]
+    fn io_disabled() {
+        let source = r#"import { readFile } from "std:io";"#;
+        let result = with_runtime(source, |runtime| runtime.with_io(false).eval().map(|_| ()));
+        let error = result.unwrap_err();
+        assert!(error.downcast_ref::<diagnostics::IoDisabled>().is_some());
+    }
+}
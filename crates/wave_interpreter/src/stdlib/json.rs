@@ -0,0 +1,578 @@
+use std::rc::Rc;
+
+use crate::{
+    diagnostics,
+    environment::Environment,
+    evaluator::{function::NativeFunction, Primitive},
+    stdlib::{expect_string, NativeModule},
+    Runtime,
+};
+use wave_diagnostics::Result;
+
+/// Nesting deeper than this is rejected instead of overflowing the stack.
+const MAX_DEPTH: usize = 512;
+
+pub fn module<'a>() -> NativeModule<'a> {
+    NativeModule {
+        name: "json",
+        exports: vec![
+            (
+                "parse".into(),
+                Primitive::NativeFunction(NativeFunction::new("parse", |runtime, arguments, _| {
+                    parse(runtime, &arguments)
+                })),
+            ),
+            (
+                "stringify".into(),
+                Primitive::NativeFunction(NativeFunction::new(
+                    "stringify",
+                    |runtime, arguments, _| stringify(runtime, &arguments),
+                )),
+            ),
+        ],
+    }
+}
+
+/// Objects become instances without a class, each key is a field, so `parse(text).a` reads
+/// the key `a`. A key given twice keeps its last value.
+fn parse<'a>(runtime: &Runtime<'a>, arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    let text = expect_string("parse", arg, 0)?;
+    let mut parser = JsonParser {
+        runtime,
+        text,
+        position: 0,
+        depth: 0,
+    };
+    parser.skip_whitespace();
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(value),
+        Some(c) => Err(parser.error(format!("Unexpected character '{c}' after the value"))),
+    }
+}
+
+/// `stringify(value, replacer, space)`, `replacer` is not supported and must be `null`.
+/// `space` indents nested values by a number of spaces or by a string, up to 10 characters.
+///
+/// Instances are written as objects of their public fields sorted by name, since fields have
+/// no order. Private fields and fields holding functions are left out. Maps with string keys
+/// are written as objects too, in the order of their entries.
+fn stringify<'a>(runtime: &Runtime<'a>, arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    if !matches!(arg.get(1), None | Some(Primitive::Null)) {
+        return Err(diagnostics::InvalidNativeArgument("stringify", 2, "null").into());
+    }
+    let indent = match arg.get(2) {
+        None | Some(Primitive::Null) => String::new(),
+        Some(Primitive::Number(spaces)) => " ".repeat(spaces.clamp(0.0, 10.0) as usize),
+        Some(Primitive::String(indent)) => indent.chars().take(10).collect(),
+        Some(_) => {
+            return Err(
+                diagnostics::InvalidNativeArgument("stringify", 3, "number or string").into(),
+            )
+        }
+    };
+
+    let mut writer = JsonWriter {
+        runtime,
+        out: vec![],
+        indent,
+        depth: 0,
+        objects: vec![],
+    };
+    writer.write_value(arg.first().unwrap_or(&Primitive::Null))?;
+    let json = String::from_utf8(writer.out).expect("JSON output is UTF-8");
+    Ok(Primitive::String(json))
+}
+
+struct JsonParser<'r, 'a, 't> {
+    runtime: &'r Runtime<'a>,
+    text: &'t str,
+    /// Byte offset of the next character
+    position: usize,
+    depth: usize,
+}
+
+impl<'r, 'a, 't> JsonParser<'r, 'a, 't> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let eaten = self.peek() == Some(c);
+        if eaten {
+            self.position += c.len_utf8();
+        }
+        eaten
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.position += 1;
+        }
+    }
+
+    /// An error at the current position, lines and columns count from 1.
+    fn error(&self, message: String) -> wave_diagnostics::Error {
+        let before = &self.text[..self.position];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+        diagnostics::InvalidJson(message, line, column).into()
+    }
+
+    fn unexpected(&self) -> wave_diagnostics::Error {
+        match self.peek() {
+            Some(c) => self.error(format!("Unexpected character '{c}'")),
+            None => self.error("Unexpected end of input".into()),
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Primitive<'a>> {
+        match self.peek() {
+            Some('{') => self.parse_nested(Self::parse_object),
+            Some('[') => self.parse_nested(Self::parse_array),
+            Some('"') => self.parse_string().map(Primitive::String),
+            Some('-' | '0'..='9') => self.parse_number(),
+            Some('t') => self.parse_literal("true", Primitive::Boolean(true)),
+            Some('f') => self.parse_literal("false", Primitive::Boolean(false)),
+            Some('n') => self.parse_literal("null", Primitive::Null),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn parse_nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<Primitive<'a>>,
+    ) -> Result<Primitive<'a>> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(format!("Nesting deeper than {MAX_DEPTH} levels")));
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn parse_literal(&mut self, literal: &str, value: Primitive<'a>) -> Result<Primitive<'a>> {
+        for expected in literal.chars() {
+            self.expect(expected)?;
+        }
+        Ok(value)
+    }
+
+    fn parse_object(&mut self) -> Result<Primitive<'a>> {
+        self.expect('{')?;
+        let mut object = Environment::default();
+        self.skip_whitespace();
+        if !self.eat('}') {
+            loop {
+                self.skip_whitespace();
+                if self.peek() != Some('"') {
+                    return Err(self.unexpected());
+                }
+                let key = self.parse_string()?;
+                self.skip_whitespace();
+                self.expect(':')?;
+                self.skip_whitespace();
+                let value = self.parse_value()?;
+                object.define(self.runtime.bind_this(key.into()), value);
+                self.skip_whitespace();
+                if self.eat('}') {
+                    break;
+                }
+                self.expect(',')?;
+            }
+        }
+        Ok(Primitive::Instance(self.runtime.alloc_environment(object)))
+    }
+
+    fn parse_array(&mut self) -> Result<Primitive<'a>> {
+        self.expect('[')?;
+        let mut array = vec![];
+        self.skip_whitespace();
+        if !self.eat(']') {
+            loop {
+                self.skip_whitespace();
+                array.push(self.parse_value()?);
+                self.skip_whitespace();
+                if self.eat(']') {
+                    break;
+                }
+                self.expect(',')?;
+            }
+        }
+        Ok(Primitive::Array(array))
+    }
+
+    fn parse_string(&mut self) -> Result<String> {
+        self.expect('"')?;
+        let mut value = String::new();
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.position += 1;
+                    return Ok(value);
+                }
+                Some('\\') => {
+                    self.position += 1;
+                    value.push(self.parse_escape()?);
+                }
+                Some(c) if c >= ' ' => {
+                    self.position += c.len_utf8();
+                    value.push(c);
+                }
+                Some(_) => return Err(self.error("Control character in string".into())),
+                None => return Err(self.error("Unterminated string".into())),
+            }
+        }
+    }
+
+    fn parse_escape(&mut self) -> Result<char> {
+        let start = self.position;
+        let escaped = match self.next() {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('/') => '/',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{c}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => {
+                let unit = self.parse_code_unit()?;
+                let is_high_surrogate = (0xD800..0xDC00).contains(&unit);
+                if is_high_surrogate && self.text[self.position..].starts_with("\\u") {
+                    let low_start = self.position;
+                    self.position += 2;
+                    let low = self.parse_code_unit()?;
+                    if (0xDC00..0xE000).contains(&low) {
+                        let code = 0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00);
+                        return Ok(char::from_u32(code).expect("surrogate pairs are valid"));
+                    }
+                    self.position = low_start;
+                }
+                // lone surrogates cannot be stored in a string
+                char::from_u32(unit).unwrap_or(char::REPLACEMENT_CHARACTER)
+            }
+            _ => {
+                self.position = start;
+                return Err(self.error("Invalid escape sequence".into()));
+            }
+        };
+        Ok(escaped)
+    }
+
+    fn parse_code_unit(&mut self) -> Result<u32> {
+        let digits = self
+            .text
+            .get(self.position..self.position + 4)
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("Invalid unicode escape".into()))?;
+        self.position += 4;
+        Ok(u32::from_str_radix(digits, 16).expect("validated hex digits"))
+    }
+
+    fn parse_number(&mut self) -> Result<Primitive<'a>> {
+        let start = self.position;
+        self.eat('-');
+        match self.next() {
+            Some('0') => {}
+            Some('1'..='9') => self.skip_digits(),
+            _ => {
+                self.position = start;
+                return Err(self.error("Invalid number".into()));
+            }
+        }
+        if self.eat('.') {
+            self.expect_digits(start)?;
+        }
+        if self.eat('e') || self.eat('E') {
+            if !self.eat('+') {
+                self.eat('-');
+            }
+            self.expect_digits(start)?;
+        }
+        let value = self.text[start..self.position]
+            .parse()
+            .expect("validated number");
+        Ok(Primitive::Number(value))
+    }
+
+    fn skip_digits(&mut self) {
+        while matches!(self.peek(), Some('0'..='9')) {
+            self.position += 1;
+        }
+    }
+
+    fn expect_digits(&mut self, start: usize) -> Result<()> {
+        if !matches!(self.peek(), Some('0'..='9')) {
+            self.position = start;
+            return Err(self.error("Invalid number".into()));
+        }
+        self.skip_digits();
+        Ok(())
+    }
+}
+
+struct JsonWriter<'r, 'a> {
+    runtime: &'r Runtime<'a>,
+    out: Vec<u8>,
+    /// Empty for compact output
+    indent: String,
+    depth: usize,
+    /// The Maps and instances being written, to reject one that contains itself
+    objects: Vec<*const ()>,
+}
+
+impl<'r, 'a> JsonWriter<'r, 'a> {
+    fn write_value(&mut self, value: &Primitive<'a>) -> Result<()> {
+        match value {
+            Primitive::Null => self.out.extend_from_slice(b"null"),
+            Primitive::Boolean(value) => self.out.extend_from_slice(value.to_string().as_bytes()),
+            // like `JSON.stringify`, numbers JSON cannot represent are written as `null`
+            Primitive::Number(value) if !value.is_finite() => self.out.extend_from_slice(b"null"),
+            Primitive::Number(value) => {
+                let mut buffer = ryu_js::Buffer::new();
+                self.out.extend_from_slice(buffer.format(*value).as_bytes());
+            }
+            Primitive::String(value) => self.write_string(value),
+            Primitive::Array(values) => {
+                self.write_nested('[', ']', values.len(), |writer, index| {
+                    writer.write_value(&values[index])
+                })?;
+            }
+            Primitive::Map(map) => {
+                let entries = map
+                    .borrow()
+                    .entries()
+                    .map(|(key, value)| match key {
+                        Primitive::String(key) => Ok((key.clone(), value.clone())),
+                        _ => Err(diagnostics::InvalidJsonValue(
+                            "A Map with non-string keys".into(),
+                        )
+                        .into()),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                self.write_object(Rc::as_ptr(map) as *const (), "A Map", entries)?;
+            }
+            Primitive::Instance(env) | Primitive::This(env) => {
+                let instance = self.runtime.get_receiver(env);
+                let pointer = Rc::as_ptr(&instance) as *const ();
+                let instance = instance.borrow();
+                let mut fields = instance
+                    .values
+                    .iter()
+                    .filter(|(_, value)| !is_function(value))
+                    .filter_map(|(name, value)| {
+                        let name = name.as_str().strip_prefix("this_")?;
+                        (!name.starts_with('#')).then(|| (name.to_string(), value.clone()))
+                    })
+                    .collect::<Vec<_>>();
+                fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+                self.write_object(pointer, "An instance", fields)?;
+            }
+            Primitive::BigInt(_) => {
+                return Err(diagnostics::InvalidJsonValue("A BigInt".into()).into())
+            }
+            Primitive::Set(_) => return Err(diagnostics::InvalidJsonValue("A Set".into()).into()),
+            _ => return Err(diagnostics::InvalidJsonValue("A function".into()).into()),
+        }
+        Ok(())
+    }
+
+    /// Writes the `members` of the object at `pointer`, `kind` names it when it contains itself.
+    fn write_object(
+        &mut self,
+        pointer: *const (),
+        kind: &str,
+        members: Vec<(String, Primitive<'a>)>,
+    ) -> Result<()> {
+        if self.objects.contains(&pointer) {
+            return Err(diagnostics::InvalidJsonValue(format!("{kind} containing itself")).into());
+        }
+        self.objects.push(pointer);
+        self.write_nested('{', '}', members.len(), |writer, index| {
+            let (key, value) = &members[index];
+            writer.write_string(key);
+            writer.out.push(b':');
+            if !writer.indent.is_empty() {
+                writer.out.push(b' ');
+            }
+            writer.write_value(value)
+        })?;
+        self.objects.pop();
+        Ok(())
+    }
+
+    fn write_string(&mut self, value: &str) {
+        serde_json::to_writer(&mut self.out, value).expect("writing to a Vec cannot fail");
+    }
+
+    /// Writes the `len` items of an array or an object, each on its own line when indenting.
+    fn write_nested(
+        &mut self,
+        open: char,
+        close: char,
+        len: usize,
+        mut write_item: impl FnMut(&mut Self, usize) -> Result<()>,
+    ) -> Result<()> {
+        self.out.push(open as u8);
+        if len > 0 {
+            self.depth += 1;
+            for index in 0..len {
+                if index > 0 {
+                    self.out.push(b',');
+                }
+                self.write_newline();
+                write_item(self, index)?;
+            }
+            self.depth -= 1;
+            self.write_newline();
+        }
+        self.out.push(close as u8);
+        Ok(())
+    }
+
+    fn write_newline(&mut self) {
+        if !self.indent.is_empty() {
+            self.out.push(b'\n');
+            for _ in 0..self.depth {
+                self.out.extend_from_slice(self.indent.as_bytes());
+            }
+        }
+    }
+}
+
+fn is_function(value: &Primitive<'_>) -> bool {
+    matches!(
+        value,
+        Primitive::Function(..)
+            | Primitive::GeneratorFunction(..)
+            | Primitive::AsyncFunction(..)
+            | Primitive::NativeFunction(_)
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse, stringify};
+    use crate::evaluator::Primitive;
+    use crate::test_utils::{eval, with_runtime};
+    use crate::Runtime;
+
+    fn string<'a>(value: &str) -> Primitive<'a> {
+        Primitive::String(value.into())
+    }
+
+    fn roundtrip<'a>(runtime: &Runtime<'a>, text: &str, space: Primitive<'a>) -> String {
+        let value = parse(runtime, &[string(text)]).unwrap();
+        match stringify(runtime, &[value, Primitive::Null, space]).unwrap() {
+            Primitive::String(json) => json,
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_and_stringify() {
+        with_runtime("", |runtime| {
+            let text = r#" {"b": [1, 2.5, -0.1e-2, 1E21], "a": {"ok": true, "no": false, "none": null},
+                "s": "tab\t\u00e9\ud83d\ude00 \"quoted\"\/", "b": [0]} "#;
+            assert_eq!(
+                roundtrip(&runtime, text, Primitive::Null),
+                r#"{"a":{"no":false,"none":null,"ok":true},"b":[0],"s":"tab\té😀 \"quoted\"/"}"#
+            );
+            assert_eq!(
+                roundtrip(&runtime, r#"{"list": [1, {}], "empty": []}"#, Primitive::Number(2.0)),
+                "{\n  \"empty\": [],\n  \"list\": [\n    1,\n    {}\n  ]\n}"
+            );
+            assert_eq!(roundtrip(&runtime, "[1]", string("--")), "[\n--1\n]");
+
+            let numbers = vec![
+                Primitive::Number(0.1 + 0.2),
+                Primitive::Number(f64::NAN),
+                Primitive::Number(-0.0),
+                Primitive::Number(1e21),
+            ];
+            assert_eq!(
+                format!("{:?}", stringify(&runtime, &[Primitive::Array(numbers)])?),
+                "[0.30000000000000004,null,0,1e+21]"
+            );
+            assert!(stringify(&runtime, &[Primitive::BigInt(1.into())]).is_err());
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn objects_are_instances() {
+        let source = "let point = JSON.parse(\"{\\\"x\\\": 1, \\\"y\\\": [2]}\");
+            point.x = point.x + 1;
+            [point.x, point.y, JSON.stringify(point)];";
+        assert_eq!(
+            eval(source).unwrap(),
+            "[2, [2], {\"x\":2,\"y\":[2]}]"
+        );
+
+        let class = "class User {
+                #password = \"secret\";
+                name = \"ada\";
+                tags = new Map([[\"admin\", true]]);
+                greet() { return this.name; }
+                json() { return JSON.stringify(this); }
+            }";
+        let run = |code: &str| eval(&format!("{class} {code}"));
+        assert_eq!(
+            run("let u = new User(); u.callback = function () {}; u.json();").unwrap(),
+            "{\"name\":\"ada\",\"tags\":{\"admin\":true}}"
+        );
+        let error = run("let u = new User(); u.self = u; JSON.stringify(u);").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "An instance containing itself cannot be converted to JSON."
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        with_runtime("", |runtime| {
+            let error = |text: &str| parse(&runtime, &[string(text)]).unwrap_err().to_string();
+            assert_eq!(
+                error("{\n  \"a\": 1,\n  \"b\" 2\n}"),
+                "Invalid JSON at line 3, column 7: Unexpected character '2'."
+            );
+            assert_eq!(
+                error("[1, 2"),
+                "Invalid JSON at line 1, column 6: Unexpected end of input."
+            );
+            assert_eq!(
+                error("[01]"),
+                "Invalid JSON at line 1, column 3: Unexpected character '1'."
+            );
+            assert_eq!(
+                error("\"\\x\""),
+                "Invalid JSON at line 1, column 3: Invalid escape sequence."
+            );
+            assert_eq!(
+                error("[1] x"),
+                "Invalid JSON at line 1, column 5: Unexpected character 'x' after the value."
+            );
+            assert!(parse(&runtime, &[string(&"[".repeat(1000))]).is_err());
+            Ok(())
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{
+    diagnostics,
+    evaluator::{function::NativeFunction, Primitive},
+    stdlib::{expect_number, native_function, NativeModule},
+    Runtime,
+};
+use wave_diagnostics::Result;
+
+/// Seed used until a script calls `seed`, so unseeded runs are reproducible too.
+pub(crate) const DEFAULT_SEED: u64 = 0x5741_5645;
+
+pub fn module<'a>() -> NativeModule<'a> {
+    NativeModule {
+        name: "math",
+        exports: vec![
+            ("PI".into(), Primitive::Number(std::f64::consts::PI)),
+            ("E".into(), Primitive::Number(std::f64::consts::E)),
+            native_function("floor", floor),
+            native_function("ceil", ceil),
+            native_function("round", round),
+            native_function("trunc", trunc),
+            native_function("sqrt", sqrt),
+            native_function("abs", abs),
+            native_function("sign", sign),
+            native_function("exp", exp),
+            native_function("log", log),
+            native_function("sin", sin),
+            native_function("cos", cos),
+            native_function("tan", tan),
+            native_function("asin", asin),
+            native_function("acos", acos),
+            native_function("atan", atan),
+            native_function("atan2", atan2),
+            native_function("pow", pow),
+            native_function("min", min),
+            native_function("max", max),
+            native_function("isNaN", is_nan),
+            (
+                "random".into(),
+                Primitive::NativeFunction(NativeFunction::new("random", |runtime, _, _| {
+                    Ok(random(runtime))
+                })),
+            ),
+            (
+                "seed".into(),
+                Primitive::NativeFunction(NativeFunction::new("seed", |runtime, arguments, _| {
+                    seed(runtime, &arguments)
+                })),
+            ),
+        ],
+    }
+}
+
+macro_rules! unary_math_function {
+    ($($name:ident => $wave_name:literal),* $(,)?) => {
+        $(
+            fn $name<'a>(arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+                let value = expect_number($wave_name, arg, 0)?;
+                Ok(Primitive::Number(value.$name()))
+            }
+        )*
+    };
+}
+
+unary_math_function! {
+    floor => "floor",
+    ceil => "ceil",
+    trunc => "trunc",
+    sqrt => "sqrt",
+    abs => "abs",
+    exp => "exp",
+    sin => "sin",
+    cos => "cos",
+    tan => "tan",
+    asin => "asin",
+    acos => "acos",
+    atan => "atan",
+}
+
+/// Rounds half-way cases towards positive infinity, `round(-2.5)` is `-2`.
+fn round<'a>(arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    let value = expect_number("round", arg, 0)?;
+    // adding 0.5 first would round up 0.49999999999999994 and large odd numbers
+    let floor = value.floor();
+    let rounded = if value - floor >= 0.5 {
+        floor + 1.0
+    } else {
+        floor
+    };
+    Ok(Primitive::Number(rounded))
+}
+
+fn sign<'a>(arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    let value = expect_number("sign", arg, 0)?;
+    let sign = if value.is_nan() || value == 0.0 {
+        value
+    } else {
+        value.signum()
+    };
+    Ok(Primitive::Number(sign))
+}
+
+/// Natural logarithm.
+fn log<'a>(arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    let value = expect_number("log", arg, 0)?;
+    Ok(Primitive::Number(value.ln()))
+}
+
+fn atan2<'a>(arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    let y = expect_number("atan2", arg, 0)?;
+    let x = expect_number("atan2", arg, 1)?;
+    Ok(Primitive::Number(y.atan2(x)))
+}
+
+fn pow<'a>(arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    let base = expect_number("pow", arg, 0)?;
+    let exponent = expect_number("pow", arg, 1)?;
+    Ok(Primitive::Number(base.powf(exponent)))
+}
+
+fn min<'a>(arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    fold_numbers("min", arg, f64::INFINITY, f64::min)
+}
+
+fn max<'a>(arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    fold_numbers("max", arg, f64::NEG_INFINITY, f64::max)
+}
+
+/// `min` and `max` are variadic and propagate `NaN` like the arithmetic operators do.
+fn fold_numbers<'a>(
+    name: &'static str,
+    arg: &[Primitive<'a>],
+    init: f64,
+    fold: fn(f64, f64) -> f64,
+) -> Result<Primitive<'a>> {
+    let mut result = init;
+    for index in 0..arg.len() {
+        let value = expect_number(name, arg, index)?;
+        if value.is_nan() {
+            return Ok(Primitive::Number(f64::NAN));
+        }
+        result = fold(result, value);
+    }
+    Ok(Primitive::Number(result))
+}
+
+fn is_nan<'a>(arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    match arg.first() {
+        Some(Primitive::Number(value)) => Ok(Primitive::Boolean(value.is_nan())),
+        Some(_) => Ok(Primitive::Boolean(false)),
+        None => Err(diagnostics::InvalidNativeArgument("isNaN", 1, "number").into()),
+    }
+}
+
+/// Returns a number in `[0, 1)` from the splitmix64 generator of the runtime.
+fn random<'a>(runtime: &Runtime<'a>) -> Primitive<'a> {
+    let mut z = runtime
+        .random_state
+        .get()
+        .wrapping_add(0x9E37_79B9_7F4A_7C15);
+    runtime.random_state.set(z);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    // keep the top 53 bits, the precision of an f64 mantissa
+    Primitive::Number((z >> 11) as f64 / (1u64 << 53) as f64)
+}
+
+fn seed<'a>(runtime: &Runtime<'a>, arg: &[Primitive<'a>]) -> Result<Primitive<'a>> {
+    let seed = expect_number("seed", arg, 0)?;
+    runtime.random_state.set(seed.to_bits());
+    Ok(Primitive::Null)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{max, min, random, round};
+    use crate::evaluator::Primitive;
+    use crate::test_utils::{eval, with_runtime};
+    use wave_diagnostics::Result;
+
+    fn number(result: Result<Primitive>) -> f64 {
+        match result.unwrap() {
+            Primitive::Number(value) => value,
+            other => panic!("{other:?}"),
+        }
+    }
+
+    fn numbers(values: &[f64]) -> Vec<Primitive<'static>> {
+        values
+            .iter()
+            .map(|value| Primitive::Number(*value))
+            .collect()
+    }
+
+    #[test]
+    fn seeded_random_is_reproducible() {
+        let source = "import { random, seed } from \"std:math\";
+            seed(42);
+            let first = [random(), random()];
+            seed(42);
+            let second = [random(), random()];
+            [first[0] - second[0], first[1] - second[1]];";
+        assert_eq!(eval(source).unwrap(), "[0, 0]");
+
+        // every runtime starts its own sequence from the default seed
+        let unseeded = "import { random } from \"std:math\"; [random(), random()];";
+        assert_eq!(eval(unseeded).unwrap(), eval(unseeded).unwrap());
+
+        with_runtime("", |runtime| {
+            for _ in 0..5 {
+                assert!((0.0..1.0).contains(&number(Ok(random(&runtime)))));
+            }
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn variadic_min_max() {
+        assert_eq!(number(min(&numbers(&[3.0, -1.0, 2.0]))), -1.0);
+        assert_eq!(number(max(&numbers(&[3.0, -1.0, 2.0]))), 3.0);
+        assert_eq!(number(max(&[])), f64::NEG_INFINITY);
+        assert!(min(&[Primitive::Boolean(true)]).is_err());
+    }
+
+    #[test]
+    fn round_half_up() {
+        assert_eq!(number(round(&numbers(&[2.5]))), 3.0);
+        assert_eq!(number(round(&numbers(&[-2.5]))), -2.0);
+        assert_eq!(number(round(&numbers(&[0.49999999999999994]))), 0.0);
+        assert_eq!(
+            number(round(&numbers(&[4503599627370497.0]))),
+            4503599627370497.0
+        );
+    }
+}
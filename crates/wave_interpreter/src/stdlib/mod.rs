@@ -0,0 +1,67 @@
+pub mod io;
+pub mod json;
+pub mod math;
+
+use crate::{
+    diagnostics,
+    evaluator::{function::NativeFunction, Primitive},
+};
+use wave_diagnostics::Result;
+use wave_span::Atom;
+
+/// Native modules importable with `import { .. } from "std:<name>"`.
+pub const NATIVE_MODULES: &str = "io, json, math";
+
+pub struct NativeModule<'a> {
+    pub name: &'static str,
+    pub exports: Vec<(Atom, Primitive<'a>)>,
+}
+
+impl<'a> NativeModule<'a> {
+    pub fn get(name: &str) -> Option<Self> {
+        match name {
+            "io" => Some(io::module()),
+            "json" => Some(json::module()),
+            "math" => Some(math::module()),
+            _ => None,
+        }
+    }
+
+    pub fn export(&self, name: &str) -> Option<Primitive<'a>> {
+        self.exports
+            .iter()
+            .find(|(export, _)| *export == name)
+            .map(|(_, value)| value.clone())
+    }
+}
+
+/// An export implemented by a Rust function of its arguments.
+pub(crate) fn native_function<'a>(
+    name: &str,
+    function: fn(&[Primitive<'a>]) -> Result<Primitive<'a>>,
+) -> (Atom, Primitive<'a>) {
+    let function = NativeFunction::new(name, move |_, arguments, _| function(&arguments));
+    (name.into(), Primitive::NativeFunction(function))
+}
+
+pub(crate) fn expect_string<'b>(
+    function: &'static str,
+    arguments: &'b [Primitive],
+    index: usize,
+) -> Result<&'b str> {
+    match arguments.get(index) {
+        Some(Primitive::String(value)) => Ok(value),
+        _ => Err(diagnostics::InvalidNativeArgument(function, index + 1, "string").into()),
+    }
+}
+
+pub(crate) fn expect_number(
+    function: &'static str,
+    arguments: &[Primitive],
+    index: usize,
+) -> Result<f64> {
+    match arguments.get(index) {
+        Some(Primitive::Number(value)) => Ok(*value),
+        _ => Err(diagnostics::InvalidNativeArgument(function, index + 1, "number").into()),
+    }
+}
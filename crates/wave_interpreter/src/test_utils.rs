@@ -0,0 +1,20 @@
+use crate::Runtime;
+use wave_allocator::Allocator;
+use wave_diagnostics::Result;
+use wave_parser::Parser;
+
+/// Evaluates `source` and formats the value of its last statement.
+pub(crate) fn eval(source: &str) -> Result<String> {
+    with_runtime(source, |runtime| Ok(format!("{:?}", runtime.eval()?)))
+}
+
+/// Parses `source`, which must be free of syntax errors, and hands a runtime for it to `f`.
+pub(crate) fn with_runtime<T>(
+    source: &str,
+    f: impl for<'a> FnOnce(Runtime<'a>) -> Result<T>,
+) -> Result<T> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source).parse();
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    f(Runtime::new(ret.program))
+}
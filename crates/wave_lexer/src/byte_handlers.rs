@@ -1,4 +1,5 @@
-use crate::{diagnostics, string_builder::AutoCow, Kind, Lexer};
+use crate::{diagnostics, Kind, Lexer};
+use wave_syntax::identifier::is_identifier_start_all;
 
 /// Lookup table mapping any incoming byte to a handler function defined below.
 /// <https://github.com/ratel-rust/ratel-core/blob/master/ratel/src/lexer/mod.rs>
@@ -10,12 +11,12 @@ pub static BYTE_HANDLERS: [ByteHandler; 128] = [
 //  0    1    2    3    4    5    6    7    8    9    A    B    C    D    E    F    //
     ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, SPS, LIN, SPS, SPS, LIN, ERR, ERR, // 0
     ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, ERR, // 1
-    SPS, EXL, QOT, IDT, IDT, PRC, AMP, QOT, PNO, PNC, ATR, PLS, COM, MIN, PRD, SLH, // 2
-    ZER, DIG, DIG, DIG, DIG, DIG, DIG, DIG, DIG, DIG, IDT, SEM, LSS, EQL, GTR, IDT, // 3
+    SPS, EXL, QOT, HAS, IDT, PRC, AMP, QOT, PNO, PNC, ATR, PLS, COM, MIN, PRD, SLH, // 2
+    ZER, DIG, DIG, DIG, DIG, DIG, DIG, DIG, DIG, DIG, COL, SEM, LSS, EQL, GTR, QST, // 3
     IDT, IDT, IDT, IDT, IDT, IDT, IDT, IDT, IDT, IDT, IDT, IDT, IDT, IDT, IDT, IDT, // 4
     IDT, IDT, IDT, IDT, IDT, IDT, IDT, IDT, IDT, IDT, IDT, BTO, IDT, BTC, CRT, IDT, // 5
-    IDT, IDT, L_B, L_C, IDT, L_E, L_F, IDT, IDT, L_I, IDT, IDT, L_L, IDT, L_N, IDT, // 6
-    IDT, IDT, L_R, L_S, L_T, IDT, IDT, L_W, IDT, IDT, IDT, BEO, PIP, BEC, IDT, ERR, // 7
+    TPL, L_A, L_B, L_C, L_D, L_E, L_F, IDT, IDT, L_I, IDT, IDT, L_L, IDT, L_N, IDT, // 6
+    IDT, IDT, L_R, L_S, L_T, IDT, IDT, L_W, IDT, L_Y, IDT, BEO, PIP, BEC, IDT, ERR, // 7
 ];
 
 const ERR: ByteHandler = |lexer| {
@@ -43,20 +44,59 @@ const IDT: ByteHandler = |lexer| {
     Kind::Ident
 };
 
+// #
+const HAS: ByteHandler = |lexer| {
+    lexer.consume_char();
+    if lexer.peek().is_some_and(is_identifier_start_all) {
+        lexer.identifier_name_handler();
+        return Kind::PrivateIdentifier;
+    }
+    lexer.error(diagnostics::InvalidCharacter(
+        '#',
+        lexer.unterminated_range(),
+    ));
+    Kind::Undetermined
+};
+
 // 0
 const ZER: ByteHandler = |lexer| {
-    let mut builder = AutoCow::new(lexer);
-    let c = lexer.consume_char();
-    builder.push_matching(c);
-    lexer.read_zero(&mut builder)
+    lexer.consume_char();
+    lexer.read_zero()
 };
 
 // 1 to 9
 const DIG: ByteHandler = |lexer| {
-    let mut builder = AutoCow::new(lexer);
-    let c = lexer.consume_char();
-    builder.push_matching(c);
-    lexer.decimal_literal_after_first_digit(&mut builder)
+    lexer.consume_char();
+    lexer.decimal_literal_after_first_digit()
+};
+
+// :
+const COL: ByteHandler = |lexer| {
+    lexer.consume_char();
+    Kind::Colon
+};
+
+// ?
+const QST: ByteHandler = |lexer| {
+    lexer.consume_char();
+    if lexer.next_eq('?') {
+        if lexer.next_eq('=') {
+            Kind::Question2Eq
+        } else {
+            Kind::Question2
+        }
+    } else if lexer.peek() == Some('.') {
+        // `a ?.5 : 1` is a conditional, not an optional chain
+        let after_dot = lexer.remaining()[1..].chars().next();
+        if after_dot.is_some_and(|c| c.is_ascii_digit()) {
+            Kind::Question
+        } else {
+            lexer.consume_char();
+            Kind::QuestionDot
+        }
+    } else {
+        Kind::Question
+    }
 };
 
 // <
@@ -95,6 +135,8 @@ const EQL: ByteHandler = |lexer| {
     lexer.consume_char();
     if lexer.next_eq('=') {
         Kind::Eq2
+    } else if lexer.next_eq('>') {
+        Kind::Arrow
     } else {
         Kind::Eq
     }
@@ -112,6 +154,12 @@ const QOT: ByteHandler = |lexer| {
     lexer.read_string_literal(c)
 };
 
+// `
+const TPL: ByteHandler = |lexer| {
+    lexer.consume_char();
+    lexer.read_template_literal(Kind::TemplateHead, Kind::NoSubstitutionTemplate)
+};
+
 // +
 const PLS: ByteHandler = |lexer| {
     lexer.consume_char();
@@ -267,9 +315,22 @@ const EXL: ByteHandler = |lexer| {
 // .
 const PRD: ByteHandler = |lexer| {
     lexer.consume_char();
+    if lexer.peek().is_some_and(|c| c.is_ascii_digit()) {
+        return lexer.decimal_literal_after_dot();
+    }
+    if lexer.remaining().starts_with("..") {
+        lexer.consume_char();
+        lexer.consume_char();
+        return Kind::Dot3;
+    }
     Kind::Dot
 };
 
+const L_A: ByteHandler = |lexer| match &lexer.identifier_name_handler()[1..] {
+    "wait" => Kind::Await,
+    _ => Kind::Ident,
+};
+
 const L_B: ByteHandler = |lexer| match &lexer.identifier_name_handler()[1..] {
     "reak" => Kind::Break,
     _ => Kind::Ident,
@@ -279,6 +340,12 @@ const L_C: ByteHandler = |lexer| match &lexer.identifier_name_handler()[1..] {
     "onst" => Kind::Const,
     "lass" => Kind::Class,
     "ontinue" => Kind::Continue,
+    "ase" => Kind::Case,
+    _ => Kind::Ident,
+};
+
+const L_D: ByteHandler = |lexer| match &lexer.identifier_name_handler()[1..] {
+    "efault" => Kind::Default,
     _ => Kind::Ident,
 };
 
@@ -292,12 +359,14 @@ const L_F: ByteHandler = |lexer| match &lexer.identifier_name_handler()[1..] {
     "unction" => Kind::Function,
     "alse" => Kind::False,
     "rom" => Kind::From,
+    "or" => Kind::For,
     _ => Kind::Ident,
 };
 
 const L_I: ByteHandler = |lexer| match &lexer.identifier_name_handler()[1..] {
     "f" => Kind::If,
     "mport" => Kind::Import,
+    "nstanceof" => Kind::Instanceof,
     _ => Kind::Ident,
 };
 
@@ -315,6 +384,7 @@ const L_N: ByteHandler = |lexer| match &lexer.identifier_name_handler()[1..] {
 const L_T: ByteHandler = |lexer| match &lexer.identifier_name_handler()[1..] {
     "his" => Kind::This,
     "rue" => Kind::True,
+    "ypeof" => Kind::Typeof,
     _ => Kind::Ident,
 };
 
@@ -325,6 +395,7 @@ const L_R: ByteHandler = |lexer| match &lexer.identifier_name_handler()[1..] {
 
 const L_S: ByteHandler = |lexer| match &lexer.identifier_name_handler()[1..] {
     "uper" => Kind::Super,
+    "witch" => Kind::Switch,
     _ => Kind::Ident,
 };
 
@@ -332,3 +403,8 @@ const L_W: ByteHandler = |lexer| match &lexer.identifier_name_handler()[1..] {
     "hile" => Kind::While,
     _ => Kind::Ident,
 };
+
+const L_Y: ByteHandler = |lexer| match &lexer.identifier_name_handler()[1..] {
+    "ield" => Kind::Yield,
+    _ => Kind::Ident,
+};
@@ -8,6 +8,18 @@ use wave_span::Span;
 #[error("Unterminated string")]
 pub struct UnterminatedString(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unterminated template")]
+pub struct UnterminatedTemplate(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid escape sequence")]
+pub struct InvalidEscapeSequence(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid Unicode escape sequence")]
+pub struct InvalidUnicodeEscapeSequence(#[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Invalid Character `{0}`")]
 pub struct InvalidCharacter(pub char, #[label] pub Span);
@@ -16,6 +28,22 @@ pub struct InvalidCharacter(pub char, #[label] pub Span);
 #[error("Invalid characters after number")]
 pub struct InvalidNumberEnd(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Expected {0} digits")]
+pub struct ExpectedDigits(pub &'static str, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid digit `{0}` in {1} literal")]
+pub struct InvalidDigit(pub char, pub &'static str, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Only one underscore is allowed as numeric separator")]
+pub struct ConsecutiveNumericSeparators(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Numeric separators are only allowed between digits")]
+pub struct InvalidNumericSeparator(#[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Unterminated multiline comment")]
 pub struct UnterminatedMultiLineComment(#[label] pub Span);
@@ -7,8 +7,15 @@ pub enum Kind {
     #[default]
     Eof,
     Ident,
+    /// `#name`, only valid inside a class body
+    PrivateIdentifier,
     NewLine,
     Decimal,
+    Float,
+    Binary,
+    Octal,
+    Hex,
+    BigInt,
     WhiteSpace,
     Comment,
     MultiLineComment,
@@ -28,6 +35,10 @@ pub enum Kind {
     True,
     False,
     Str,
+    NoSubstitutionTemplate,
+    TemplateHead,
+    TemplateMiddle,
+    TemplateTail,
     If,
     Else,
     Function,
@@ -66,13 +77,28 @@ pub enum Kind {
     Break,
     Continue,
     Dot,
+    Dot3,
+    Question,
+    Question2,
+    Question2Eq,
+    QuestionDot,
+    Colon,
     Class,
     This,
     Extends,
     Super,
     New,
     Import,
+    Instanceof,
+    Typeof,
+    For,
+    Yield,
+    Await,
     From,
+    Switch,
+    Case,
+    Default,
+    Arrow,
 }
 
 use self::Kind::*;
@@ -87,7 +113,7 @@ impl Kind {
     }
 
     pub fn is_number(self) -> bool {
-        matches!(self, Decimal)
+        matches!(self, Decimal | Float | Binary | Octal | Hex | BigInt)
     }
 
     pub fn to_str(self) -> &'static str {
@@ -95,8 +121,14 @@ impl Kind {
             Undetermined => "Unknown",
             Eof => "EOF",
             Decimal => "Decimal",
+            Float => "Float",
+            Binary => "Binary",
+            Octal => "Octal",
+            Hex => "Hex",
+            BigInt => "BigInt",
             NewLine => "\n",
             Ident => "Identifier",
+            PrivateIdentifier => "#identifier",
             WhiteSpace => " ",
             Comment => "\\",
             MultiLineComment => "/** */",
@@ -108,6 +140,7 @@ impl Kind {
             True => "true",
             False => "false",
             Str => "String",
+            NoSubstitutionTemplate | TemplateHead | TemplateMiddle | TemplateTail => "Template",
             Const => "const",
             Plus => "+",
             Minus => "-",
@@ -154,13 +187,28 @@ impl Kind {
             Break => "break",
             Continue => "continue",
             Dot => ".",
+            Dot3 => "...",
+            Question => "?",
+            Question2 => "??",
+            Question2Eq => "??=",
+            QuestionDot => "?.",
+            Colon => ":",
             Class => "class",
             This => "this",
             Extends => "extends",
             Super => "super",
             New => "new",
             Import => "import",
+            Instanceof => "instanceof",
+            Typeof => "typeof",
+            For => "for",
+            Yield => "yield",
+            Await => "await",
             From => "from",
+            Switch => "switch",
+            Case => "case",
+            Default => "default",
+            Arrow => "=>",
         }
     }
 
@@ -176,8 +224,17 @@ impl Kind {
         matches!(self, Ident)
     }
 
+    /// Reserved words are still valid property names, as in `Array.from`.
+    #[rustfmt::skip]
+    pub fn is_keyword(self) -> bool {
+        matches!(self, Let      | Const  | Null    | True   | False   | If      | Else
+                     | Function | Return | While   | Break  | Continue | Class  | This
+                     | Extends  | Super  | New     | Import | From    | Switch  | Case
+                     | Default  | Instanceof | Typeof | For | Yield | Await)
+    }
+
     pub fn is_unary_operator(self) -> bool {
-        matches!(self, Minus | Plus | Bang)
+        matches!(self, Minus | Plus | Bang | Typeof)
     }
 
     pub fn is_update_operator(self) -> bool {
@@ -185,11 +242,11 @@ impl Kind {
     }
 
     pub fn is_logical_operator(self) -> bool {
-        matches!(self, Pipe2 | Amp2)
+        matches!(self, Pipe2 | Amp2 | Question2)
     }
 
     pub fn is_class_element_name_start(self) -> bool {
-        self.is_literal_property_name() || matches!(self, LBrack)
+        self.is_literal_property_name() || matches!(self, LBrack | PrivateIdentifier)
     }
 
     pub fn is_literal_property_name(self) -> bool {
@@ -200,14 +257,14 @@ impl Kind {
     pub fn is_binary_operator(self) -> bool {
         matches!(self, Eq2   | Neq    | LAngle | LtEq  | RAngle  | GtEq |
                        Plus  | Minus  | Star   | Slash | Percent
-                                      | Star2)
+                       | Pipe  | Caret  | Amp    | Star2 | Instanceof)
     }
 
     #[rustfmt::skip]
     pub fn is_assignment_operator(self) -> bool {
         matches!(self, Eq  | PlusEq   | MinusEq | StarEq  | SlashEq | PercentEq
                            | Pipe2Eq  | Amp2Eq  | CaretEq | AmpEq   | PipeEq
-                           | Star2Eq)
+                           | Star2Eq  | Question2Eq)
     }
 }
 
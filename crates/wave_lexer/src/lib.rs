@@ -1,11 +1,13 @@
 mod byte_handlers;
 mod diagnostics;
 mod kind;
+mod number;
 mod string_builder;
 mod token;
 mod trivia_builder;
 
 use crate::string_builder::AutoCow;
+use rustc_hash::FxHashMap;
 use std::{collections::VecDeque, str::Chars};
 use wave_allocator::Allocator;
 use wave_diagnostics::Error;
@@ -19,7 +21,11 @@ use wave_syntax::{
 };
 
 use self::trivia_builder::TriviaBuilder;
-pub use self::{kind::Kind, token::Token};
+pub use self::{
+    kind::Kind,
+    number::{parse_big_int, parse_number},
+    token::Token,
+};
 
 #[derive(Debug, Clone)]
 pub struct LexerCheckpoint<'a> {
@@ -35,6 +41,8 @@ pub struct Lexer<'a> {
     pub errors: Vec<Error>,
     lookahead: VecDeque<LexerCheckpoint<'a>>,
     pub trivia_builder: TriviaBuilder,
+    /// Decoded values of strings and templates containing escapes, keyed by token start
+    escaped_strings: FxHashMap<u32, &'a str>,
 }
 
 impl<'a> Lexer<'a> {
@@ -57,6 +65,7 @@ impl<'a> Lexer<'a> {
             errors: vec![],
             lookahead: VecDeque::with_capacity(4),
             trivia_builder: TriviaBuilder::default(),
+            escaped_strings: FxHashMap::default(),
         }
     }
 
@@ -250,8 +259,27 @@ impl<'a> Lexer<'a> {
         self.errors.push(error.into());
     }
 
+    /// Source text of the token, strings and templates are returned without their
+    /// delimiters and with escapes decoded.
     pub fn get_string(&self, token: Token) -> &'a str {
-        &self.source[token.start as usize..token.end as usize]
+        if token.escaped {
+            return self.escaped_strings[&token.start];
+        }
+        let raw = &self.source[token.start as usize..token.end as usize];
+        match token.kind {
+            Kind::Str | Kind::NoSubstitutionTemplate | Kind::TemplateTail => &raw[1..raw.len() - 1],
+            Kind::TemplateHead | Kind::TemplateMiddle => &raw[1..raw.len() - 2],
+            _ => raw,
+        }
+    }
+
+    /// Re-lex the `}` closing a template substitution as a `TemplateMiddle` or `TemplateTail`,
+    /// the parser calls this because only it knows the `}` belongs to a template.
+    pub fn next_template_substitution_tail(&mut self) -> Token {
+        self.current.token.start = self.offset() - 1;
+        let kind = self.read_template_literal(Kind::TemplateMiddle, Kind::TemplateTail);
+        self.lookahead.clear();
+        self.finish_next(kind)
     }
 
     fn skip_irregular_whitespace(&mut self) -> Kind {
@@ -259,8 +287,57 @@ impl<'a> Lexer<'a> {
     }
 
     /// 12.9.3 Numeric Literals with `0` prefix
-    fn read_zero(&mut self, _builder: &mut AutoCow<'a>) -> Kind {
-        self.check_after_numeric_literal(Kind::Decimal)
+    fn read_zero(&mut self) -> Kind {
+        match self.peek() {
+            Some('x' | 'X') => self.read_non_decimal(Kind::Hex),
+            Some('o' | 'O') => self.read_non_decimal(Kind::Octal),
+            Some('b' | 'B') => self.read_non_decimal(Kind::Binary),
+            _ => self.decimal_literal_after_integer(),
+        }
+    }
+
+    /// `0x`, `0o` and `0b` literals, the prefix letter is the next char
+    fn read_non_decimal(&mut self, kind: Kind) -> Kind {
+        self.consume_char();
+        let (name, is_digit): (_, fn(char) -> bool) = match kind {
+            Kind::Binary => ("binary", |c| matches!(c, '0' | '1')),
+            Kind::Octal => ("octal", |c| matches!(c, '0'..='7')),
+            _ => ("hexadecimal", |c| c.is_ascii_hexdigit()),
+        };
+
+        let has_digits = self.read_digits(is_digit, false);
+
+        // `0b2` and `0o8`, point at the offending digit rather than the whole literal
+        if let Some(c) = self.peek().filter(char::is_ascii_digit) {
+            let start = self.offset();
+            self.consume_char();
+            self.error(diagnostics::InvalidDigit(
+                c,
+                name,
+                Span::new(start, self.offset()),
+            ));
+            while self.peek().is_some_and(is_identifier_part) {
+                self.current.chars.next();
+            }
+            return Kind::Undetermined;
+        }
+
+        if !has_digits {
+            self.error(diagnostics::ExpectedDigits(name, self.unterminated_range()));
+            return Kind::Undetermined;
+        }
+
+        let kind = self.read_bigint_suffix(kind);
+        self.check_after_numeric_literal(kind)
+    }
+
+    /// `n` suffix turns an integer literal into a `BigInt` literal
+    fn read_bigint_suffix(&mut self, kind: Kind) -> Kind {
+        if self.next_eq('n') {
+            Kind::BigInt
+        } else {
+            kind
+        }
     }
 
     fn check_after_numeric_literal(&mut self, kind: Kind) -> Kind {
@@ -285,21 +362,82 @@ impl<'a> Lexer<'a> {
         Kind::Undetermined
     }
 
-    fn read_decimal_digits_after_first_digit(&mut self, builder: &mut AutoCow<'a>) {
+    /// Reads digits accepted by `is_digit` along with `_` separators between them,
+    /// returns whether any digit was read.
+    fn read_digits(&mut self, is_digit: fn(char) -> bool, mut after_digit: bool) -> bool {
+        let mut has_digits = false;
         while let Some(c) = self.peek() {
-            match c {
-                c @ '0'..='9' => {
-                    self.current.chars.next();
-                    builder.push_matching(c);
-                }
-                _ => break,
+            if is_digit(c) {
+                self.current.chars.next();
+                has_digits = true;
+                after_digit = true;
+                continue;
             }
+            if c != '_' {
+                break;
+            }
+
+            let start = self.offset();
+            self.current.chars.next();
+            if self.peek() == Some('_') {
+                while self.next_eq('_') {}
+                self.error(diagnostics::ConsecutiveNumericSeparators(Span::new(
+                    start,
+                    self.offset(),
+                )));
+            } else if !after_digit || !self.peek().is_some_and(is_digit) {
+                self.error(diagnostics::InvalidNumericSeparator(Span::new(
+                    start,
+                    self.offset(),
+                )));
+            }
+            after_digit = false;
         }
+        has_digits
+    }
+
+    fn decimal_literal_after_first_digit(&mut self) -> Kind {
+        self.read_digits(|c| c.is_ascii_digit(), true);
+        self.decimal_literal_after_integer()
+    }
+
+    fn decimal_literal_after_integer(&mut self) -> Kind {
+        if self.next_eq('n') {
+            return self.check_after_numeric_literal(Kind::BigInt);
+        }
+        let kind = if self.next_eq('.') {
+            self.read_digits(|c| c.is_ascii_digit(), false);
+            Kind::Float
+        } else {
+            Kind::Decimal
+        };
+        let kind = self.read_exponent(kind);
+        self.check_after_numeric_literal(kind)
+    }
+
+    /// `.5`, the dot has been consumed and the next char is a digit
+    fn decimal_literal_after_dot(&mut self) -> Kind {
+        self.read_digits(|c| c.is_ascii_digit(), false);
+        let kind = self.read_exponent(Kind::Float);
+        self.check_after_numeric_literal(kind)
     }
 
-    fn decimal_literal_after_first_digit(&mut self, builder: &mut AutoCow<'a>) -> Kind {
-        self.read_decimal_digits_after_first_digit(builder);
-        self.check_after_numeric_literal(Kind::Decimal)
+    fn read_exponent(&mut self, kind: Kind) -> Kind {
+        if !matches!(self.peek(), Some('e' | 'E')) {
+            return kind;
+        }
+        self.current.chars.next();
+        if !self.next_eq('+') {
+            self.next_eq('-');
+        }
+        if !self.read_digits(|c| c.is_ascii_digit(), false) {
+            self.error(diagnostics::ExpectedDigits(
+                "exponent",
+                self.unterminated_range(),
+            ));
+            return Kind::Undetermined;
+        }
+        Kind::Float
     }
 
     fn read_string_literal(&mut self, delimiter: char) -> Kind {
@@ -312,18 +450,173 @@ impl<'a> Lexer<'a> {
                 }
                 Some(c @ ('"' | '\'')) => {
                     if c == delimiter {
-                        builder.finish_without_push(self);
+                        self.save_string(
+                            builder.value.is_some(),
+                            builder.finish_without_push(self),
+                        );
                         return Kind::Str;
                     }
                     builder.push_matching(c);
                 }
+                Some('\\') => {
+                    builder.force_allocation_without_current_ascii_char(self);
+                    self.read_escape_sequence(&mut builder);
+                }
+                Some(c) => {
+                    builder.push_matching(c);
+                }
+            }
+        }
+    }
+
+    /// 12.8.6 Template Literal Lexical Components
+    /// Reads up to the closing backtick or the next `${`,
+    /// the opening backtick or `}` has been consumed.
+    fn read_template_literal(&mut self, substitute: Kind, tail: Kind) -> Kind {
+        let mut builder = AutoCow::new(self);
+        loop {
+            match self.current.chars.next() {
+                None => {
+                    self.error(diagnostics::UnterminatedTemplate(self.unterminated_range()));
+                    return Kind::Undetermined;
+                }
+                Some('`') => {
+                    self.save_string(builder.value.is_some(), builder.finish_without_push(self));
+                    return tail;
+                }
+                Some('$') if self.peek() == Some('{') => {
+                    self.save_string(builder.value.is_some(), builder.finish_without_push(self));
+                    self.current.chars.next();
+                    return substitute;
+                }
+                Some('\\') => {
+                    builder.force_allocation_without_current_ascii_char(self);
+                    self.read_escape_sequence(&mut builder);
+                }
                 Some(c) => {
+                    if is_line_terminator(c) {
+                        self.current.token.is_on_new_line = true;
+                    }
                     builder.push_matching(c);
                 }
             }
         }
     }
 
+    fn save_string(&mut self, escaped: bool, value: &'a str) {
+        if escaped {
+            self.escaped_strings.insert(self.current.token.start, value);
+            self.current.token.escaped = true;
+        }
+    }
+
+    /// 12.9.4 String Literals, EscapeSequence
+    /// The backslash has been consumed, the decoded char is pushed to `builder`.
+    fn read_escape_sequence(&mut self, builder: &mut AutoCow<'a>) {
+        let start = self.offset() - 1;
+        let Some(c) = self.current.chars.next() else {
+            return;
+        };
+        let value = match c {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'v' => '\u{b}',
+            '0' if !self.peek().is_some_and(|c| c.is_ascii_digit()) => '\0',
+            // LineContinuation
+            '\r' => {
+                self.next_eq('\n');
+                return;
+            }
+            c if is_line_terminator(c) => return,
+            // legacy octal escapes are not supported
+            '0'..='9' => {
+                self.error(diagnostics::InvalidEscapeSequence(Span::new(
+                    start,
+                    self.offset(),
+                )));
+                return;
+            }
+            'x' => match self.read_hex_digits(2).and_then(char::from_u32) {
+                Some(value) => value,
+                None => {
+                    self.error(diagnostics::InvalidEscapeSequence(Span::new(
+                        start,
+                        self.offset(),
+                    )));
+                    return;
+                }
+            },
+            'u' => match self.read_unicode_escape() {
+                Some(value) => value,
+                None => {
+                    self.error(diagnostics::InvalidUnicodeEscapeSequence(Span::new(
+                        start,
+                        self.offset(),
+                    )));
+                    return;
+                }
+            },
+            c @ ('\'' | '"' | '\\' | '`' | '$') => c,
+            _ => {
+                self.error(diagnostics::InvalidEscapeSequence(Span::new(
+                    start,
+                    self.offset(),
+                )));
+                return;
+            }
+        };
+        builder.push_matching(value);
+    }
+
+    /// `\uXXXX` or `\u{X..}`, the `\u` has been consumed.
+    /// Surrogate pairs written as two escapes are combined, a lone surrogate is an error.
+    fn read_unicode_escape(&mut self) -> Option<char> {
+        let code_point = self.read_code_point()?;
+        if (0xD800..=0xDBFF).contains(&code_point) {
+            let checkpoint = self.current.chars.clone();
+            if self.next_eq('\\') && self.next_eq('u') {
+                if let Some(low @ 0xDC00..=0xDFFF) = self.read_code_point() {
+                    return char::from_u32(
+                        0x10000 + ((code_point - 0xD800) << 10) + (low - 0xDC00),
+                    );
+                }
+            }
+            self.current.chars = checkpoint;
+            return None;
+        }
+        char::from_u32(code_point)
+    }
+
+    fn read_code_point(&mut self) -> Option<u32> {
+        if !self.next_eq('{') {
+            return self.read_hex_digits(4);
+        }
+        let mut value = 0u32;
+        let mut digits = 0;
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(16)) {
+            self.current.chars.next();
+            value = value.checked_mul(16)?.checked_add(digit)?;
+            digits += 1;
+        }
+        if digits == 0 || !self.next_eq('}') || value > 0x10FFFF {
+            return None;
+        }
+        Some(value)
+    }
+
+    fn read_hex_digits(&mut self, count: usize) -> Option<u32> {
+        let mut value = 0;
+        for _ in 0..count {
+            let digit = self.peek().and_then(|c| c.to_digit(16))?;
+            self.current.chars.next();
+            value = value * 16 + digit;
+        }
+        Some(value)
+    }
+
     fn skip_single_line_comment(&mut self) -> Kind {
         let start = self.current.token.start;
         while let Some(c) = self.current.chars.next() {
@@ -357,3 +650,46 @@ impl<'a> Lexer<'a> {
         Kind::Eof
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{Kind, Lexer};
+    use wave_allocator::Allocator;
+
+    fn tokens(source: &str) -> (Vec<(Kind, String)>, usize) {
+        let allocator = Allocator::default();
+        let mut lexer = Lexer::new(&allocator, source);
+        let mut tokens = vec![];
+        loop {
+            let token = lexer.next_token();
+            if token.kind == Kind::Eof {
+                break;
+            }
+            tokens.push((token.kind, lexer.get_string(token).to_string()));
+        }
+        (tokens, lexer.errors.len())
+    }
+
+    #[test]
+    fn string_escapes() {
+        let (tokens, errors) = tokens(r#""a\n\t\\\"" '\x41B\u{1F600}😀'"#);
+        assert_eq!(errors, 0);
+        assert_eq!(tokens[0], (Kind::Str, "a\n\t\\\"".to_string()));
+        assert_eq!(tokens[1], (Kind::Str, "AB😀😀".to_string()));
+
+        assert_eq!(self::tokens(r#""\q""#).1, 1);
+        assert_eq!(self::tokens(r#""\1""#).1, 1);
+        assert_eq!(self::tokens(r#""\x4""#).1, 1);
+        assert_eq!(self::tokens(r#""\u{110000}""#).1, 1);
+        assert_eq!(self::tokens(r#""\uD83D""#).1, 1);
+    }
+
+    #[test]
+    fn template_tokens() {
+        let (tokens, errors) = tokens("`a\\u0041` `b${");
+        assert_eq!(errors, 0);
+        assert_eq!(tokens[0], (Kind::NoSubstitutionTemplate, "aA".to_string()));
+        assert_eq!(tokens[1], (Kind::TemplateHead, "b".to_string()));
+        assert_eq!(self::tokens("`abc").1, 1);
+    }
+}
@@ -0,0 +1,134 @@
+use std::borrow::Cow;
+
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+use crate::Kind;
+
+/// Value of a numeric literal token, `src` is the raw source text including any prefix.
+pub fn parse_number(src: &str, kind: Kind) -> Result<f64, &'static str> {
+    let src = strip_separators(src);
+    match kind {
+        Kind::Decimal | Kind::Float => src.parse::<f64>().map_err(|_| "Invalid Float"),
+        Kind::Binary | Kind::Octal | Kind::Hex => {
+            // go through BigInt so literals above 2^53 round like `parse::<f64>` does
+            BigInt::parse_bytes(src[2..].as_bytes(), radix(kind))
+                .and_then(|value| value.to_f64())
+                .ok_or("Invalid Number")
+        }
+        _ => Err("Invalid Number"),
+    }
+}
+
+/// Value of a `BigInt` literal token, the `n` suffix is optional.
+pub fn parse_big_int(src: &str) -> Result<BigInt, &'static str> {
+    let src = strip_separators(src.trim_end_matches('n'));
+    let (digits, radix) = match src.get(..2) {
+        Some("0x" | "0X") => (&src[2..], 16),
+        Some("0o" | "0O") => (&src[2..], 8),
+        Some("0b" | "0B") => (&src[2..], 2),
+        _ => (&src[..], 10),
+    };
+    BigInt::parse_bytes(digits.as_bytes(), radix).ok_or("Invalid BigInt")
+}
+
+fn radix(kind: Kind) -> u32 {
+    match kind {
+        Kind::Binary => 2,
+        Kind::Octal => 8,
+        Kind::Hex => 16,
+        _ => 10,
+    }
+}
+
+fn strip_separators(src: &str) -> Cow<'_, str> {
+    if src.contains('_') {
+        Cow::Owned(src.replace('_', ""))
+    } else {
+        Cow::Borrowed(src)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_big_int, parse_number};
+    use crate::{Kind, Lexer};
+    use std::ops::Range;
+    use wave_allocator::Allocator;
+
+    fn lex(source: &str) -> (Kind, usize) {
+        let allocator = Allocator::default();
+        let mut lexer = Lexer::new(&allocator, source);
+        let token = lexer.next_token();
+        (token.kind, lexer.errors.len())
+    }
+
+    #[test]
+    fn numeric_literal_values() {
+        assert_eq!(parse_number("0x1F", Kind::Hex), Ok(31.0));
+        assert_eq!(parse_number("0o17", Kind::Octal), Ok(15.0));
+        assert_eq!(parse_number("0b1010", Kind::Binary), Ok(10.0));
+        assert_eq!(parse_number("1e-9", Kind::Float), Ok(1e-9));
+        assert_eq!(parse_number(".5", Kind::Float), Ok(0.5));
+        assert_eq!(parse_number("1_000_000", Kind::Decimal), Ok(1_000_000.0));
+        assert_eq!(parse_big_int("0xFFn").unwrap(), 255.into());
+    }
+
+    #[test]
+    fn numeric_literal_kinds() {
+        assert_eq!(lex("0x1F"), (Kind::Hex, 0));
+        assert_eq!(lex("0o17"), (Kind::Octal, 0));
+        assert_eq!(lex("0b1010"), (Kind::Binary, 0));
+        assert_eq!(lex("1e-9"), (Kind::Float, 0));
+        assert_eq!(lex(".5"), (Kind::Float, 0));
+        assert_eq!(lex("2.75"), (Kind::Float, 0));
+        assert_eq!(lex("1_000_000"), (Kind::Decimal, 0));
+        assert_eq!(lex("0b11n"), (Kind::BigInt, 0));
+    }
+
+    /// The message and the labeled range of every diagnostic for the first token.
+    fn errors(source: &str) -> Vec<(String, Range<usize>)> {
+        let allocator = Allocator::default();
+        let mut lexer = Lexer::new(&allocator, source);
+        lexer.next_token();
+        lexer
+            .errors
+            .iter()
+            .map(|error| {
+                let label = error.labels().and_then(|mut labels| labels.next());
+                let range =
+                    label.map_or(0..0, |label| label.offset()..label.offset() + label.len());
+                (error.to_string(), range)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn malformed_numeric_literals() {
+        let error = |message: &str, range: Range<usize>| vec![(message.to_string(), range)];
+        assert_eq!(errors("0x"), error("Expected hexadecimal digits", 0..2));
+        assert_eq!(errors("1e"), error("Expected exponent digits", 0..2));
+        assert_eq!(
+            errors("0b2"),
+            error("Invalid digit `2` in binary literal", 2..3)
+        );
+        assert_eq!(
+            errors("0o78"),
+            error("Invalid digit `8` in octal literal", 3..4)
+        );
+        assert_eq!(
+            errors("12ab"),
+            error("Invalid characters after number", 2..4)
+        );
+        assert_eq!(
+            errors("1__0"),
+            error("Only one underscore is allowed as numeric separator", 1..3)
+        );
+        let misplaced = "Numeric separators are only allowed between digits";
+        assert_eq!(errors("1_"), error(misplaced, 1..2));
+        assert_eq!(errors("0x_1"), error(misplaced, 2..3));
+        assert_eq!(errors("1_e5"), error(misplaced, 1..2));
+        assert_eq!(lex("0x"), (Kind::Undetermined, 1));
+        assert_eq!(lex("1__0"), (Kind::Decimal, 1));
+    }
+}
@@ -7,6 +7,9 @@ pub struct Token {
     pub start: u32,
     pub end: u32,
     pub is_on_new_line: bool,
+    /// The cooked value of a string or template differs from its source text,
+    /// see `Lexer::get_string`.
+    pub escaped: bool,
 }
 
 impl Token {
@@ -1,11 +1,22 @@
-use wave_ast::ast::{BindingPattern, BindingPatternKind};
+use wave_allocator::Box;
+use wave_ast::ast::{BindingPattern, BindingPatternKind, RestElement};
 use wave_diagnostics::Result;
+use wave_lexer::Kind;
+use wave_span::Span;
 
-use crate::Parser;
+use crate::{
+    list::{ArrayPatternList, ObjectPatternProperties, SeparatedList},
+    Parser,
+};
 
 impl<'a> Parser<'a> {
+    /// Section 14.3.3 Destructuring Binding Patterns
     pub(crate) fn parse_binding(&mut self) -> Result<(BindingPattern<'a>, bool)> {
-        let kind = self.parse_binding_pattern_identifier()?;
+        let kind = match self.cur_kind() {
+            Kind::LCurly => self.parse_object_binding_pattern()?,
+            Kind::LBrack => self.parse_array_binding_pattern()?,
+            _ => self.parse_binding_pattern_identifier()?,
+        };
         Ok((self.ast.binding_pattern(kind), false))
     }
 
@@ -19,4 +30,50 @@ impl<'a> Parser<'a> {
         let pattern = self.parse_binding()?.0;
         Ok(pattern)
     }
+
+    /// A binding pattern followed by an optional initializer, `[a = 1]`
+    pub(crate) fn parse_binding_element(&mut self) -> Result<BindingPattern<'a>> {
+        let span = self.start_span();
+        let pattern = self.parse_binding_pattern()?;
+        self.parse_initializer(span, pattern)
+    }
+
+    pub(crate) fn parse_initializer(
+        &mut self,
+        span: Span,
+        left: BindingPattern<'a>,
+    ) -> Result<BindingPattern<'a>> {
+        if self.eat(Kind::Eq) {
+            let right = self.parse_assignment_expression_base()?;
+            Ok(self
+                .ast
+                .assignment_pattern(self.end_span(span), left, right))
+        } else {
+            Ok(left)
+        }
+    }
+
+    /// `...` BindingPattern
+    pub(crate) fn parse_rest_element(&mut self) -> Result<Box<'a, RestElement<'a>>> {
+        let span = self.start_span();
+        self.expect(Kind::Dot3)?;
+        let argument = self.parse_binding_pattern()?;
+        Ok(self.ast.rest_element(self.end_span(span), argument))
+    }
+
+    fn parse_object_binding_pattern(&mut self) -> Result<BindingPatternKind<'a>> {
+        let span = self.start_span();
+        let list = ObjectPatternProperties::parse(self)?;
+        Ok(self
+            .ast
+            .object_pattern(self.end_span(span), list.elements, list.rest))
+    }
+
+    fn parse_array_binding_pattern(&mut self) -> Result<BindingPatternKind<'a>> {
+        let span = self.start_span();
+        let list = ArrayPatternList::parse(self)?;
+        Ok(self
+            .ast
+            .array_pattern(self.end_span(span), list.elements, list.rest))
+    }
 }
@@ -1,11 +1,11 @@
 use wave_allocator::{Box, Vec};
 use wave_ast::ast::{
     Class, ClassBody, ClassElement, ClassType, Expression, MethodDefinition, MethodDefinitionKind,
-    PropertyDefinition, PropertyKey, Statement,
+    PropertyKey, Statement,
 };
 use wave_diagnostics::Result;
 use wave_lexer::Kind;
-use wave_span::Span;
+use wave_span::{GetSpan, Span};
 
 use crate::{
     context::StatementContext,
@@ -118,9 +118,22 @@ impl<'a> Parser<'a> {
     pub(crate) fn parse_class_element(&mut self) -> Result<ClassElement<'a>> {
         let span = self.start_span();
 
-        let kind = MethodDefinitionKind::Method;
+        let r#static = self.eat_class_element_modifier("static");
 
-        let _ = self.peek_kind().is_class_element_name_start();
+        let kind = if self.eat_class_element_modifier("get") {
+            MethodDefinitionKind::Get
+        } else if self.eat_class_element_modifier("set") {
+            MethodDefinitionKind::Set
+        } else {
+            MethodDefinitionKind::Method
+        };
+        let r#async = kind.is_method() && self.eat_class_element_modifier("async");
+        let star = self.cur_token().span();
+        let generator = kind.is_method() && self.eat(Kind::Star);
+        if r#async && generator {
+            self.error(diagnostics::AsyncGenerator(star));
+        }
+        let generator = generator && !r#async;
 
         let key = match self.cur_kind() {
             kind if kind.is_class_element_name_start() => self.parse_class_element_name()?,
@@ -128,13 +141,14 @@ impl<'a> Parser<'a> {
         };
 
         if self.at(Kind::LParen) {
-            let definition = self.parse_class_method_definition(span, kind, key)?;
+            let definition =
+                self.parse_class_method_definition(span, kind, key, r#static, r#async, generator)?;
             Ok(definition)
         } else {
-            if !kind.is_method() {
+            if !kind.is_method() || r#async || generator {
                 return Err(self.unexpected());
             }
-            let definition = self.parse_class_property_definition(span, key)?;
+            let definition = self.parse_class_property_definition(span, key, r#static)?;
             if let Some((name, span)) = definition.prop_name() {
                 if name == "constructor" {
                     self.error(diagnostics::FieldConstructor(span));
@@ -144,10 +158,25 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// `static`, `get` and `set` are only modifiers when a class element name follows,
+    /// otherwise they name the element itself, as in `get() {}`.
+    fn eat_class_element_modifier(&mut self, modifier: &str) -> bool {
+        if self.at(Kind::Ident)
+            && self.cur_src() == modifier
+            && (self.peek_kind().is_class_element_name_start()
+                || matches!(modifier, "static" | "async") && self.peek_at(Kind::Star))
+        {
+            self.bump_any();
+            return true;
+        }
+        false
+    }
+
     fn parse_class_property_definition(
         &mut self,
         span: Span,
         key: PropertyKey<'a>,
+        r#static: bool,
     ) -> Result<ClassElement<'a>> {
         let value = if self.eat(Kind::Eq) {
             // let current_flags = self.scope.current_flags();
@@ -160,15 +189,9 @@ impl<'a> Parser<'a> {
         };
         self.asi()?;
 
-        let property_definition = PropertyDefinition {
-            span: self.end_span(span),
-            key,
-            value,
-        };
-
-        Ok(ClassElement::PropertyDefinition(
-            self.ast.alloc(property_definition),
-        ))
+        Ok(self
+            .ast
+            .class_property(self.end_span(span), key, value, r#static))
     }
 
     fn parse_class_method_definition(
@@ -176,23 +199,50 @@ impl<'a> Parser<'a> {
         span: Span,
         kind: MethodDefinitionKind,
         key: PropertyKey<'a>,
+        r#static: bool,
+        r#async: bool,
+        generator: bool,
     ) -> Result<ClassElement<'a>> {
-        let kind = if key
-            .prop_name()
-            .map_or(false, |(name, _)| name == "constructor")
-        {
-            MethodDefinitionKind::Constructor
-        } else {
-            kind
+        let is_constructor = !r#static
+            && key
+                .prop_name()
+                .map_or(false, |(name, _)| name == "constructor");
+
+        let kind = match kind {
+            MethodDefinitionKind::Method if is_constructor => MethodDefinitionKind::Constructor,
+            MethodDefinitionKind::Get | MethodDefinitionKind::Set if is_constructor => {
+                self.error(diagnostics::ConstructorAccessor(key.span()));
+                kind
+            }
+            _ => kind,
         };
 
-        let value = self.parse_method()?;
+        if is_constructor && generator {
+            self.error(diagnostics::ConstructorGenerator(key.span()));
+        }
+        if is_constructor && r#async {
+            self.error(diagnostics::ConstructorAsync(key.span()));
+        }
+
+        let value = self.parse_method(r#async, generator)?;
+
+        let params = &value.params;
+        match kind {
+            MethodDefinitionKind::Get if !params.items.is_empty() || params.rest.is_some() => {
+                self.error(diagnostics::GetterParameters(params.span));
+            }
+            MethodDefinitionKind::Set if params.items.len() != 1 || params.rest.is_some() => {
+                self.error(diagnostics::SetterParameters(params.span));
+            }
+            _ => {}
+        }
 
         let method_definition = MethodDefinition {
             span: self.end_span(span),
             key,
             value,
             kind,
+            r#static,
         };
 
         Ok(ClassElement::MethodDefinition(
@@ -201,6 +251,13 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_class_element_name(&mut self) -> Result<PropertyKey<'a>> {
+        if self.at(Kind::PrivateIdentifier) {
+            let ident = self.parse_private_identifier();
+            if ident.name == "constructor" {
+                self.error(diagnostics::PrivateNameConstructor(ident.span));
+            }
+            return Ok(PropertyKey::PrivateIdentifier(self.ast.alloc(ident)));
+        }
         self.parse_property_name()
     }
 }
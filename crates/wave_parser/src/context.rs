@@ -1,10 +1,19 @@
 use bitflags::bitflags;
+use wave_span::Atom;
 
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct Context: u8 {
         const In = 1<< 0;
         const Return = 1<< 1;
+        /// Inside the body of a loop, `break` and `continue` are allowed
+        const Iteration = 1<< 2;
+        /// Inside the cases of a `switch`, `break` is allowed
+        const Switch = 1<< 3;
+        /// Inside the body of a generator, `yield` is allowed
+        const Yield = 1<< 4;
+        /// Inside the body of an async function, `await` is allowed
+        const Await = 1<< 5;
     }
 }
 
@@ -19,12 +28,34 @@ impl Context {
     pub(crate) fn has_return(self) -> bool {
         self.contains(Self::Return)
     }
+
+    #[inline]
+    pub(crate) fn has_iteration(self) -> bool {
+        self.contains(Self::Iteration)
+    }
+
+    #[inline]
+    pub(crate) fn has_yield(self) -> bool {
+        self.contains(Self::Yield)
+    }
+
+    #[inline]
+    pub(crate) fn has_await(self) -> bool {
+        self.contains(Self::Await)
+    }
+
+    #[inline]
+    pub(crate) fn has_break(self) -> bool {
+        self.intersects(Self::Iteration | Self::Switch)
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum StatementContext {
     If,
+    Label,
     While,
+    For,
     StatementList,
 }
 
@@ -33,3 +64,15 @@ impl StatementContext {
         self != Self::StatementList
     }
 }
+
+/// A label that is in scope for `break` and `continue`.
+#[derive(Debug)]
+pub(crate) struct Label {
+    pub name: Atom,
+    /// Start of the labeled statement
+    pub start: u32,
+    /// Start of the labeled statement's body
+    pub body_start: u32,
+    /// `continue` may only target labels of iteration statements
+    pub is_loop: bool,
+}
@@ -3,7 +3,6 @@ use wave_diagnostics::Result;
 use wave_lexer::{Kind, LexerCheckpoint, Token};
 use wave_span::Span;
 
-#[allow(dead_code)]
 pub struct ParserCheckpoint<'a> {
     lexer: LexerCheckpoint<'a>,
     cur_token: Token,
@@ -77,6 +76,36 @@ impl<'a> Parser<'a> {
         self.cur_kind() == kind
     }
 
+    pub(crate) fn checkpoint(&self) -> ParserCheckpoint<'a> {
+        ParserCheckpoint {
+            lexer: self.lexer.checkpoint(),
+            cur_token: self.token,
+            prev_span_end: self.prev_token_end,
+            errors_pos: self.errors.len(),
+        }
+    }
+
+    pub(crate) fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            errors_pos,
+        } = checkpoint;
+        self.lexer.rewind(lexer);
+        self.token = cur_token;
+        self.prev_token_end = prev_span_end;
+        self.errors.truncate(errors_pos);
+    }
+
+    /// Runs `f` to look further ahead than the next token, then rewinds to the current token
+    pub(crate) fn lookahead<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        let checkpoint = self.checkpoint();
+        let result = f(self);
+        self.rewind(checkpoint);
+        result
+    }
+
     /// Move to the next token
     /// Checks if the current token is escaped if it is a keyword
     fn advance(&mut self, _kind: Kind) {
@@ -84,6 +113,13 @@ impl<'a> Parser<'a> {
         self.token = self.lexer.next_token();
     }
 
+    /// Tell the lexer the current `}` closes a template substitution
+    pub(crate) fn re_lex_template_substitution_tail(&mut self) {
+        if self.at(Kind::RCurly) {
+            self.token = self.lexer.next_template_substitution_tail();
+        }
+    }
+
     /// Advance any token
     pub(crate) fn bump_any(&mut self) {
         self.advance(self.cur_kind());
@@ -142,4 +178,18 @@ impl<'a> Parser<'a> {
         }
         cb(self)
     }
+
+    pub(crate) fn without_context<F, T>(&mut self, flags: Context, cb: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let context_flags_to_clear = flags & self.ctx;
+        if !context_flags_to_clear.is_empty() {
+            self.ctx &= !context_flags_to_clear;
+            let result = cb(self);
+            self.ctx |= context_flags_to_clear;
+            return result;
+        }
+        cb(self)
+    }
 }
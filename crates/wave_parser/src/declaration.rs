@@ -1,4 +1,4 @@
-use crate::Parser;
+use crate::{diagnostics, Parser};
 
 use wave_allocator::Box;
 use wave_ast::ast::{VariableDeclaration, VariableDeclarationKind, VariableDeclarator};
@@ -9,6 +9,8 @@ use wave_span::Span;
 #[derive(Clone, Debug, Copy, Eq, PartialEq)]
 pub enum VariableDeclarationParent {
     Statement,
+    /// The head of a `for...of` statement, where the iterated values initialize the binding
+    For,
 }
 #[derive(Clone, Debug, Copy, Eq, PartialEq)]
 pub struct VariableDeclarationContext {
@@ -50,7 +52,7 @@ impl<'a> Parser<'a> {
 
     fn parse_variable_declarator(
         &mut self,
-        _decl_ctx: VariableDeclarationContext,
+        decl_ctx: VariableDeclarationContext,
         kind: VariableDeclarationKind,
     ) -> Result<VariableDeclarator<'a>> {
         let span = self.start_span();
@@ -62,6 +64,15 @@ impl<'a> Parser<'a> {
             .then(|| self.parse_assignment_expression_base())
             .transpose()?;
 
+        let in_for = decl_ctx.parent == VariableDeclarationParent::For;
+        if in_for && init.is_some() {
+            self.error(diagnostics::ForOfLoopInitializer(self.end_span(span)));
+        } else if init.is_none() && id.is_destructuring_pattern() && !in_for {
+            self.error(diagnostics::InvalidDestructuringDeclaration(
+                self.end_span(span),
+            ));
+        }
+
         Ok(self
             .ast
             .variable_declarator(self.end_span(span), kind, id, init, definite))
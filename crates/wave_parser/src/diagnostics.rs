@@ -34,6 +34,11 @@ pub struct UnexpectedToken(#[label] pub Span);
 #[diagnostic(help("replace with `super()` or `super.prop` or `super[prop]`"))]
 pub struct UnexpectedSuper(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid optional chain from new expression")]
+#[diagnostic(help("Wrap the optional chain in parentheses"))]
+pub struct NewOptionalChain(#[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Cannot assign to this expression")]
 #[diagnostic()]
@@ -59,16 +64,122 @@ pub struct ExpectToken(
 pub struct ExpectFunctionName(#[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("TS1108: A 'return' statement can only be used within a function body")]
-#[diagnostic()]
+#[error("Illegal return statement")]
+#[diagnostic(help("A `return` statement can only be used within a function body"))]
 pub struct ReturnStatementOnlyInFunctionBody(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Illegal break statement")]
+#[diagnostic(help(
+    "A `break` statement can only be used within an enclosing loop or switch statement"
+))]
+pub struct InvalidBreak(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Illegal continue statement")]
+#[diagnostic(help("A `continue` statement can only be used within an enclosing loop"))]
+pub struct InvalidContinue(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Use of undefined label `{0}`")]
+#[diagnostic()]
+pub struct UndefinedLabel(pub String, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Label `{0}` has already been declared")]
+#[diagnostic()]
+pub struct LabelRedeclaration(
+    pub String,
+    #[label("`{0}` has already been declared here")] pub Span,
+    #[label("It can not be redeclared here")] pub Span,
+);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("A `continue` statement can only jump to a label of an enclosing loop")]
+#[diagnostic(help("Use `break` to leave a labeled statement that is not a loop"))]
+pub struct InvalidLabelNonIteration(pub String, #[label("`{0}` does not label a loop")] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Empty parenthesized expression")]
 #[diagnostic()]
 pub struct EmptyParenthesizedExpression(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("A class constructor can't be a getter or setter")]
+#[diagnostic()]
+pub struct ConstructorAccessor(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("A getter must not have any parameters")]
+#[diagnostic()]
+pub struct GetterParameters(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("A setter must have exactly one parameter")]
+#[diagnostic(help("Rest parameters are not allowed in setters"))]
+pub struct SetterParameters(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Classes can't have an element named '#constructor'")]
+#[diagnostic()]
+pub struct PrivateNameConstructor(#[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Classes can't have a field named 'constructor'")]
 #[diagnostic()]
 pub struct FieldConstructor(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("A rest element must be last in a destructuring pattern")]
+#[diagnostic()]
+pub struct BindingRestElementLast(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Missing initializer in destructuring declaration")]
+#[diagnostic()]
+pub struct InvalidDestructuringDeclaration(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("A rest parameter must be last in a parameter list")]
+#[diagnostic()]
+pub struct RestParameterLast(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("A 'default' clause cannot appear more than once in a 'switch' statement")]
+#[diagnostic()]
+pub struct MultipleDefaultCases(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The variable declaration of a 'for...of' statement cannot have an initializer")]
+#[diagnostic()]
+pub struct ForOfLoopInitializer(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("A 'yield' expression is only allowed in a generator body")]
+#[diagnostic()]
+pub struct YieldOutsideGenerator(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("A 'yield' expression cannot be the operand of an operator")]
+#[diagnostic(help("Wrap it in parentheses, e.g. `1 + (yield value)`"))]
+pub struct UnsupportedYieldPosition(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Class constructor may not be a generator")]
+#[diagnostic()]
+pub struct ConstructorGenerator(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("An 'await' expression is only allowed in an async function")]
+#[diagnostic()]
+pub struct AwaitOutsideAsync(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Class constructor may not be async")]
+#[diagnostic()]
+pub struct ConstructorAsync(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Async generators are not supported")]
+#[diagnostic()]
+pub struct AsyncGenerator(#[label] pub Span);
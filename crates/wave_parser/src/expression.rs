@@ -1,18 +1,22 @@
 use std::cell::Cell;
 
+use wave_allocator::Box;
+
 use wave_ast::{
     ast::{
-        AssignmentTarget, BindingIdentifier, Expression, IdentifierName, IdentifierReference,
-        SimpleAssignmentTarget,
+        AssignmentTarget, BindingIdentifier, ChainElement, Expression, IdentifierName,
+        IdentifierReference, PrivateIdentifier, SimpleAssignmentTarget, SpreadElement,
+        TemplateElement, TemplateElementValue,
     },
-    literal::{BooleanLiteral, NullLiteral, NumberLiteral, StringLiteral},
+    literal::{BigIntLiteral, BooleanLiteral, NullLiteral, NumberLiteral, StringLiteral},
 };
 use wave_diagnostics::Result;
-use wave_lexer::Kind;
+use wave_lexer::{parse_big_int, parse_number, Kind};
 use wave_span::{Atom, Span};
 use wave_syntax::precedence::Precedence;
 
 use crate::{
+    context::Context,
     diagnostics,
     grammar::CoverGrammar,
     list::{ArrayExpressionList, CallArguments, SeparatedList, SequenceExpressionList},
@@ -53,15 +57,28 @@ impl<'a> Parser<'a> {
     }
 
     pub(crate) fn parse_assignment_expression(&mut self) -> Result<Expression<'a>> {
+        if self.at(Kind::Yield) && self.ctx.has_yield() {
+            return self.parse_yield_expression();
+        }
         let span = self.start_span();
         let lhs = self.parse_conditional_expression()?;
         self.parse_assignment_expression_recursive(span, lhs)
     }
 
+    /// Section 13.14 Conditional Expression
+    /// `ShortCircuitExpression` ? `AssignmentExpression` : `AssignmentExpression`
     fn parse_conditional_expression(&mut self) -> Result<Expression<'a>> {
-        let _span = self.start_span();
+        let span = self.start_span();
         let lhs = self.parse_binary_or_logical_expression_base(Precedence::lowest())?;
-        Ok(lhs)
+        if !self.eat(Kind::Question) {
+            return Ok(lhs);
+        }
+        let consequent = self.with_context(Context::In, Self::parse_assignment_expression_base)?;
+        self.expect(Kind::Colon)?;
+        let alternate = self.parse_assignment_expression_base()?;
+        Ok(self
+            .ast
+            .conditional_expression(self.end_span(span), lhs, consequent, alternate))
     }
 
     fn parse_binary_or_logical_expression_base(
@@ -144,6 +161,10 @@ impl<'a> Parser<'a> {
             return self.parse_unary_expression();
         }
 
+        if self.at(Kind::Await) && self.ctx.has_await() {
+            return self.parse_await_expression();
+        }
+
         self.parse_update_expression()
     }
 
@@ -175,27 +196,64 @@ impl<'a> Parser<'a> {
 
     pub(crate) fn parse_lhs_expression(&mut self) -> Result<Expression<'a>> {
         let span = self.start_span();
-        let lhs = self.parse_member_expression_base()?;
-        let lhs = self.parse_call_expression(span, lhs)?;
+        let mut in_optional_chain = false;
+        let lhs = self.parse_member_expression_base(&mut in_optional_chain)?;
+        let lhs = self.parse_call_expression(span, lhs, &mut in_optional_chain)?;
+        if in_optional_chain {
+            let span = self.end_span(span);
+            return Ok(self.map_to_chain_expression(span, lhs));
+        }
         Ok(lhs)
     }
 
-    fn parse_member_expression_base(&mut self) -> Result<Expression<'a>> {
+    fn map_to_chain_expression(
+        &mut self,
+        span: Span,
+        expression: Expression<'a>,
+    ) -> Expression<'a> {
+        match expression {
+            Expression::MemberExpression(expr) => self
+                .ast
+                .chain_expression(span, ChainElement::MemberExpression(expr)),
+            Expression::CallExpression(expr) => self
+                .ast
+                .chain_expression(span, ChainElement::CallExpression(expr)),
+            expr => expr,
+        }
+    }
+
+    fn parse_member_expression_base(
+        &mut self,
+        in_optional_chain: &mut bool,
+    ) -> Result<Expression<'a>> {
         let span = self.start_span();
         self.parse_primary_expression()
-            .and_then(|lhs| self.parse_member_expression_rhs(span, lhs))
+            .and_then(|lhs| self.parse_member_expression_rhs(span, lhs, in_optional_chain))
     }
 
     fn parse_member_expression_rhs(
         &mut self,
         lhs_span: Span,
         lhs: Expression<'a>,
+        in_optional_chain: &mut bool,
     ) -> Result<Expression<'a>> {
         let mut lhs = lhs;
         loop {
             lhs = match self.cur_kind() {
-                Kind::Dot => self.parse_static_member_expression(lhs_span, lhs)?,
-                Kind::LBrack => self.parse_computed_member_expression(lhs_span, lhs)?,
+                Kind::Dot => self.parse_static_member_expression(lhs_span, lhs, false)?,
+                Kind::LBrack => self.parse_computed_member_expression(lhs_span, lhs, false)?,
+                Kind::QuestionDot => {
+                    *in_optional_chain = true;
+                    match self.peek_kind() {
+                        Kind::LBrack => {
+                            self.bump_any(); // bump `?.`
+                            self.parse_computed_member_expression(lhs_span, lhs, true)?
+                        }
+                        // `?.(` is an optional call, handled by `parse_call_expression`
+                        Kind::LParen => break,
+                        _ => self.parse_static_member_expression(lhs_span, lhs, true)?,
+                    }
+                }
                 _ => break,
             };
         }
@@ -206,44 +264,73 @@ impl<'a> Parser<'a> {
         &mut self,
         lhs_span: Span,
         lhs: Expression<'a>,
+        optional: bool,
     ) -> Result<Expression<'a>> {
         self.bump_any(); // advance `[`
         let property = self.parse_expression()?;
         self.expect(Kind::RBrack)?;
         Ok(self
             .ast
-            .computed_member_expression(self.end_span(lhs_span), lhs, property))
+            .computed_member_expression(self.end_span(lhs_span), lhs, property, optional))
     }
 
     fn parse_static_member_expression(
         &mut self,
         lhs_span: Span,
         lhs: Expression<'a>,
+        optional: bool,
     ) -> Result<Expression<'a>> {
         self.bump_any(); // advance `.` or `?.`
+        if self.at(Kind::PrivateIdentifier) {
+            let field = self.parse_private_identifier();
+            return Ok(self.ast.private_field_expression(
+                self.end_span(lhs_span),
+                lhs,
+                field,
+                optional,
+            ));
+        }
         let ident = self.parse_identifier_name()?;
         Ok(self
             .ast
-            .static_member_expression(self.end_span(lhs_span), lhs, ident))
+            .static_member_expression(self.end_span(lhs_span), lhs, ident, optional))
+    }
+
+    pub(crate) fn parse_private_identifier(&mut self) -> PrivateIdentifier {
+        let span = self.start_span();
+        let name = Atom::from(&self.cur_src()[1..]);
+        self.bump_any();
+        PrivateIdentifier {
+            span: self.end_span(span),
+            name,
+        }
     }
 
     pub(crate) fn parse_identifier_name(&mut self) -> Result<IdentifierName> {
-        if !self.cur_kind().is_identifier_name() {
+        if !self.cur_kind().is_identifier_name() && !self.cur_kind().is_keyword() {
             return Err(self.unexpected());
         }
         let (span, name) = self.parse_identifier_kind(Kind::Ident);
         Ok(IdentifierName { span, name })
     }
 
+    /// Calls and the member accesses following them, `a.b()[c]?.(d).e`
     fn parse_call_expression(
         &mut self,
         lhs_span: Span,
         lhs: Expression<'a>,
+        in_optional_chain: &mut bool,
     ) -> Result<Expression<'a>> {
         let mut lhs = lhs;
         loop {
+            lhs = self.parse_member_expression_rhs(lhs_span, lhs, in_optional_chain)?;
+            if self.at(Kind::QuestionDot) && self.peek_at(Kind::LParen) {
+                self.bump_any(); // bump `?.`
+                lhs = self.parse_call_arguments(lhs_span, lhs, true)?;
+                continue;
+            }
             if self.at(Kind::LParen) {
-                lhs = self.parse_call_arguments(lhs_span, lhs)?;
+                lhs = self.parse_call_arguments(lhs_span, lhs, false)?;
                 continue;
             }
             break;
@@ -256,11 +343,15 @@ impl<'a> Parser<'a> {
         &mut self,
         lhs_span: Span,
         lhs: Expression<'a>,
+        optional: bool,
     ) -> Result<Expression<'a>> {
         let call_arguments = CallArguments::parse(self)?;
-        Ok(self
-            .ast
-            .call_expression(self.end_span(lhs_span), lhs, call_arguments.elements))
+        Ok(self.ast.call_expression(
+            self.end_span(lhs_span),
+            lhs,
+            call_arguments.elements,
+            optional,
+        ))
     }
 
     fn parse_primary_expression(&mut self) -> Result<Expression<'a>> {
@@ -271,22 +362,35 @@ impl<'a> Parser<'a> {
         }
 
         match &self.cur_kind() {
+            Kind::Ident if self.at_match_expression() => self.parse_match_expression(),
             Kind::Ident => self.parse_identifier_expression(), // fast path, keywords are checked at the end
             Kind::LBrack => self.parse_array_expression(),
             Kind::LParen => self.parse_parenthesized_expression(span),
             Kind::New => self.parse_new_expression(),
             Kind::This => Ok(self.parse_this_expression()),
             Kind::Super => Ok(self.parse_super()),
+            Kind::Yield if self.ctx.has_yield() => {
+                Err(diagnostics::UnsupportedYieldPosition(self.cur_token().span()).into())
+            }
+            Kind::Yield => Err(diagnostics::YieldOutsideGenerator(self.cur_token().span()).into()),
+            Kind::Await => Err(diagnostics::AwaitOutsideAsync(self.cur_token().span()).into()),
+
+            Kind::NoSubstitutionTemplate | Kind::TemplateHead => self.parse_template_literal(),
 
             kind if kind.is_literal() => self.parse_literal_expression(),
-            _ => self.parse_identifier_expression(),
+            kind if kind.is_identifier() => self.parse_identifier_expression(),
+            _ => Err(self.unexpected()),
         }
     }
 
     fn parse_new_expression(&mut self) -> Result<Expression<'a>> {
         let span = self.start_span();
         let _ = self.parse_keyword_identifier(Kind::New);
-        let callee = self.parse_member_expression_base()?;
+        let mut in_optional_chain = false;
+        let callee = self.parse_member_expression_base(&mut in_optional_chain)?;
+        if in_optional_chain {
+            self.error(diagnostics::NewOptionalChain(self.end_span(span)));
+        }
         let arguments = if self.at(Kind::LParen) {
             CallArguments::parse(self)?.elements
         } else {
@@ -312,6 +416,14 @@ impl<'a> Parser<'a> {
         self.ast.super_(span)
     }
 
+    /// `...` AssignmentExpression
+    pub(crate) fn parse_spread_element(&mut self) -> Result<Box<'a, SpreadElement<'a>>> {
+        let span = self.start_span();
+        self.expect(Kind::Dot3)?;
+        let argument = self.parse_assignment_expression_base()?;
+        Ok(self.ast.spread_element(self.end_span(span), argument))
+    }
+
     pub(crate) fn parse_array_expression(&mut self) -> Result<Expression<'a>> {
         let span = self.start_span();
         let list = ArrayExpressionList::parse(self)?;
@@ -358,6 +470,9 @@ impl<'a> Parser<'a> {
                 let literal = self.parse_literal_null();
                 Ok(self.ast.literal_null_expression(literal))
             }
+            Kind::BigInt => self
+                .parse_literal_bigint()
+                .map(|literal| self.ast.literal_bigint_expression(literal)),
             kind if kind.is_number() => self
                 .parse_literal_number()
                 .map(|literal| self.ast.literal_number_expression(literal)),
@@ -383,14 +498,24 @@ impl<'a> Parser<'a> {
         let span = self.start_span();
         let token = self.cur_token();
         let src = self.cur_src();
-        let value = src
-            .parse::<f64>()
-            .map_err(|_| diagnostics::InvalidNumber("Invalid Float", token.span()))?;
+        let value = parse_number(src, token.kind)
+            .map_err(|message| diagnostics::InvalidNumber(message, token.span()))?;
 
         self.bump_any();
         Ok(NumberLiteral::new(self.end_span(span), value, src))
     }
 
+    pub(crate) fn parse_literal_bigint(&mut self) -> Result<BigIntLiteral<'a>> {
+        let span = self.start_span();
+        let token = self.cur_token();
+        let src = self.cur_src();
+        let value = parse_big_int(src)
+            .map_err(|message| diagnostics::InvalidNumber(message, token.span()))?;
+
+        self.bump_any();
+        Ok(BigIntLiteral::new(self.end_span(span), value, src))
+    }
+
     pub(crate) fn parse_literal_null(&mut self) -> NullLiteral {
         let span = self.start_span();
         self.bump_any(); // bump `null`
@@ -412,6 +537,54 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// 13.2.8 Template Literals
+    fn parse_template_literal(&mut self) -> Result<Expression<'a>> {
+        let span = self.start_span();
+        let mut quasis = self.ast.new_vec();
+        let mut expressions = self.ast.new_vec();
+
+        if self.at(Kind::NoSubstitutionTemplate) {
+            quasis.push(self.parse_template_element(true));
+        } else {
+            quasis.push(self.parse_template_element(false));
+            loop {
+                expressions.push(self.parse_expression()?);
+                self.expect_without_advance(Kind::RCurly)?;
+                self.re_lex_template_substitution_tail();
+                match self.cur_kind() {
+                    Kind::TemplateMiddle => quasis.push(self.parse_template_element(false)),
+                    Kind::TemplateTail => {
+                        quasis.push(self.parse_template_element(true));
+                        break;
+                    }
+                    _ => return Err(self.unexpected()),
+                }
+            }
+        }
+
+        Ok(self
+            .ast
+            .template_literal(self.end_span(span), quasis, expressions))
+    }
+
+    fn parse_template_element(&mut self, tail: bool) -> TemplateElement {
+        let token = self.cur_token();
+        let cooked = self.cur_string();
+        let raw = self.cur_src();
+        // drop the leading backtick or `}`, and the trailing backtick or `${`
+        let end_offset = if tail { 1 } else { 2 };
+        let raw = &raw[1..raw.len() - end_offset];
+        self.bump_any();
+        TemplateElement {
+            span: Span::new(token.start + 1, token.end - end_offset as u32),
+            tail,
+            value: TemplateElementValue {
+                raw: raw.into(),
+                cooked: cooked.into(),
+            },
+        }
+    }
+
     fn parse_assignment_expression_recursive(
         &mut self,
         span: Span,
@@ -35,7 +35,32 @@ impl FunctionKind {
 
 impl<'a> Parser<'a> {
     pub(crate) fn at_function(&mut self) -> bool {
-        self.at(Kind::Function)
+        self.at(Kind::Function) || self.at_async_function()
+    }
+
+    /// `async` is contextual, it only starts a function when `function` follows on the same line.
+    fn at_async_function(&mut self) -> bool {
+        self.at(Kind::Ident)
+            && self.cur_src() == "async"
+            && self.peek_at(Kind::Function)
+            && !self.peek_token().is_on_new_line
+    }
+
+    /// Eats `async function` and an optional `*`, returning whether the function is async and
+    /// whether it is a generator.
+    fn parse_function_keyword(&mut self) -> Result<(bool, bool)> {
+        let r#async = self.at_async_function();
+        if r#async {
+            self.bump_any(); // bump `async`
+        }
+        self.expect(Kind::Function)?;
+        let star = self.cur_token().span();
+        let generator = self.eat(Kind::Star);
+        if r#async && generator {
+            self.error(diagnostics::AsyncGenerator(star));
+            return Ok((true, false));
+        }
+        Ok((r#async, generator))
     }
 
     pub(crate) fn parse_function(
@@ -43,11 +68,13 @@ impl<'a> Parser<'a> {
         span: Span,
         id: Option<BindingIdentifier>,
         func_kind: FunctionKind,
+        r#async: bool,
+        generator: bool,
     ) -> Result<Box<'a, Function<'a>>> {
         let params = self.parse_formal_parameters(FormalParameterKind::FormalParameter)?;
 
         let body = if self.at(Kind::LCurly) {
-            Some(self.parse_function_body()?)
+            Some(self.parse_function_body(r#async, generator)?)
         } else {
             None
         };
@@ -57,25 +84,45 @@ impl<'a> Parser<'a> {
             FunctionKind::Expression { .. } => FunctionType::FunctionExpression,
         };
 
-        Ok(self
-            .ast
-            .function(function_type, self.end_span(span), id, params, body))
+        Ok(self.ast.function(
+            function_type,
+            self.end_span(span),
+            id,
+            generator,
+            r#async,
+            params,
+            body,
+        ))
     }
 
-    pub(crate) fn parse_function_body(&mut self) -> Result<Box<'a, FunctionBody<'a>>> {
+    pub(crate) fn parse_function_body(
+        &mut self,
+        r#async: bool,
+        generator: bool,
+    ) -> Result<Box<'a, FunctionBody<'a>>> {
         let span = self.start_span();
         self.expect(Kind::LCurly)?;
-        let statements = self.with_context(Context::Return, |p| p.parse_statements())?;
+        // Labels are not visible across function boundaries
+        let labels = std::mem::take(&mut self.labels);
+        let mut body_context = Context::Return;
+        body_context.set(Context::Yield, generator);
+        body_context.set(Context::Await, r#async);
+        let outer_context = Context::Iteration | Context::Switch | Context::Yield | Context::Await;
+        let statements = self.without_context(outer_context, |p| {
+            p.with_context(body_context, |p| p.parse_statements())
+        });
+        self.labels = labels;
+        let statements = statements?;
         self.expect(Kind::RCurly)?;
         Ok(self.ast.function_body(self.end_span(span), statements))
     }
 
     pub(crate) fn parse_function_expression(&mut self, span: Span) -> Result<Expression<'a>> {
         let func_kind = FunctionKind::Expression;
-        self.expect(Kind::Function)?;
+        let (r#async, generator) = self.parse_function_keyword()?;
 
         let id = self.parse_function_id(func_kind);
-        let function = self.parse_function(span, id, func_kind)?;
+        let function = self.parse_function(span, id, func_kind, r#async, generator)?;
 
         Ok(self.ast.function_expression(function))
     }
@@ -88,7 +135,7 @@ impl<'a> Parser<'a> {
         let list: FormalParameterList<'_> = FormalParameterList::parse(self)?;
         let formal_parameters =
             self.ast
-                .formal_parameters(self.end_span(span), params_kind, list.elements);
+                .formal_parameters(self.end_span(span), params_kind, list.elements, list.rest);
         Ok(formal_parameters)
     }
 
@@ -110,9 +157,9 @@ impl<'a> Parser<'a> {
         func_kind: FunctionKind,
     ) -> Result<Box<'a, Function<'a>>> {
         let span = self.start_span();
-        self.expect(Kind::Function)?;
+        let (r#async, generator) = self.parse_function_keyword()?;
         let id = self.parse_function_id(func_kind);
-        self.parse_function(span, id, func_kind)
+        self.parse_function(span, id, func_kind, r#async, generator)
     }
 
     pub(crate) fn parse_function_id(&mut self, kind: FunctionKind) -> Option<BindingIdentifier> {
@@ -132,8 +179,40 @@ impl<'a> Parser<'a> {
         id
     }
 
-    pub(crate) fn parse_method(&mut self) -> Result<Box<'a, Function<'a>>> {
+    pub(crate) fn parse_method(
+        &mut self,
+        r#async: bool,
+        generator: bool,
+    ) -> Result<Box<'a, Function<'a>>> {
+        let span = self.start_span();
+        self.parse_function(span, None, FunctionKind::Expression, r#async, generator)
+    }
+
+    /// Like in JS, `yield` binds looser than any operator, as an operand it must be
+    /// parenthesized: `1 + (yield value)`.
+    pub(crate) fn parse_yield_expression(&mut self) -> Result<Expression<'a>> {
+        let span = self.start_span();
+        self.bump_any(); // bump `yield`
+        let delegate = self.eat(Kind::Star);
+        let ends = matches!(
+            self.cur_kind(),
+            Kind::RParen | Kind::RBrack | Kind::Comma | Kind::Colon
+        );
+        let argument = if !delegate && (ends || self.can_insert_semicolon()) {
+            None
+        } else {
+            Some(self.parse_assignment_expression_base()?)
+        };
+        Ok(self
+            .ast
+            .yield_expression(self.end_span(span), delegate, argument))
+    }
+
+    /// Like in JS, `await` is a unary operator: `await f() + 1` adds to the awaited value.
+    pub(crate) fn parse_await_expression(&mut self) -> Result<Expression<'a>> {
         let span = self.start_span();
-        self.parse_function(span, None, FunctionKind::Expression)
+        self.bump_any(); // bump `await`
+        let argument = self.parse_unary_expression_base(self.start_span())?;
+        Ok(self.ast.await_expression(self.end_span(span), argument))
     }
 }
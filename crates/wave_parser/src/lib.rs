@@ -8,6 +8,7 @@ mod expression;
 mod function;
 mod grammar;
 mod list;
+mod match_expression;
 mod module;
 mod object;
 mod operator;
@@ -15,7 +16,7 @@ mod statement;
 
 mod syntax_directed_operations;
 
-use context::Context;
+use context::{Context, Label};
 use wave_allocator::Allocator;
 use wave_ast::{ast::Program, ast_builder::AstBuilder, Trivias};
 use wave_diagnostics::{Error, Result};
@@ -35,6 +36,7 @@ pub struct Parser<'a> {
     errors: Vec<Error>,
     token: Token,
     ctx: Context,
+    labels: Vec<Label>,
     prev_token_end: u32,
     ast: AstBuilder<'a>,
     preserve_parens: bool,
@@ -49,6 +51,7 @@ impl<'a> Parser<'a> {
             token: Token::default(),
             prev_token_end: 0,
             ctx: Context::default(),
+            labels: vec![],
             ast: AstBuilder::new(allocator),
             preserve_parens: false,
         }
@@ -82,7 +85,15 @@ impl<'a> Parser<'a> {
     fn parse_program(&mut self) -> Result<Program<'a>> {
         self.bump_any();
 
-        let statements = self.parse_statements()?;
+        let mut statements = self.parse_statements()?;
+        // `parse_statements` stops at a `}`, which has nothing to close at the top level
+        while self.at(Kind::RCurly) {
+            let span = self.cur_token().span();
+            self.error(diagnostics::UnexpectedToken(span));
+            self.bump_any();
+            statements.push(self.ast.invalid_statement(span));
+            statements.extend(self.parse_statements()?);
+        }
 
         let span = Span::new(0, self.source_text.len() as u32);
         Ok(self.ast.program(span, statements))
@@ -97,3 +108,110 @@ impl<'a> Parser<'a> {
         diagnostics::UnexpectedToken(self.cur_token().span()).into()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::Parser;
+    use wave_allocator::Allocator;
+    use wave_ast::ast::Statement;
+
+    fn errors(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source).parse();
+        ret.errors.iter().map(|e| e.to_string()).collect()
+    }
+
+    #[test]
+    fn contextual_match() {
+        assert!(errors("let match = 3; match + 1; match(match);").is_empty());
+        assert!(errors("let x = match (1) { _ => 2 };").is_empty());
+        assert!(!errors("let x = match (1) { 1 2 };").is_empty());
+    }
+
+    #[test]
+    fn recovers_from_statement_errors() {
+        let allocator = Allocator::default();
+        let source =
+            "let a = ;\nlet b = 2;\nfunction f() { let c = = 1; return c; }\n}\nlet d = b +;";
+        let ret = Parser::new(&allocator, source).parse();
+
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 4);
+        let kinds = ret
+            .program
+            .body
+            .iter()
+            .map(|stmt| matches!(stmt, Statement::InvalidStatement(_)))
+            .collect::<Vec<_>>();
+        assert_eq!(kinds, [true, false, false, true, true]);
+    }
+
+    #[test]
+    fn invalid_labels() {
+        assert_eq!(
+            errors("while (true) { break missing; }"),
+            ["Use of undefined label `missing`"]
+        );
+        assert_eq!(
+            errors("a: { continue a; }"),
+            ["A `continue` statement can only jump to a label of an enclosing loop"]
+        );
+        assert!(errors("a: b: while (true) { continue a; }").is_empty());
+    }
+
+    #[test]
+    fn invalid_jumps() {
+        assert_eq!(errors("break;"), ["Illegal break statement"]);
+        assert_eq!(
+            errors("switch (1) { case 1: continue; }"),
+            ["Illegal continue statement"]
+        );
+        assert_eq!(
+            errors("while (true) { function f() { break; } }"),
+            ["Illegal break statement"]
+        );
+        assert_eq!(errors("return 1;"), ["Illegal return statement"]);
+        assert!(errors("while (true) { switch (1) { case 1: continue; } }").is_empty());
+    }
+
+    #[test]
+    fn yield_positions() {
+        let generator = |body: &str| errors(&format!("function* g(f, a) {{ {body} }}"));
+        for body in [
+            "a = yield 1;",
+            "return yield a;",
+            "f(yield a, yield);",
+            "let b = [yield, (yield a) + 1];",
+            "if (yield) {}",
+            "let c = yield* f();",
+        ] {
+            assert!(generator(body).is_empty(), "{body}");
+        }
+        assert_eq!(
+            generator("let b = 1 + yield a;"),
+            ["A 'yield' expression cannot be the operand of an operator"]
+        );
+        assert_eq!(
+            errors("function f() { yield 1; }"),
+            ["A 'yield' expression is only allowed in a generator body"]
+        );
+    }
+
+    #[test]
+    fn await_positions() {
+        let function = |body: &str| errors(&format!("async function f(g, a) {{ {body} }}"));
+        for body in [
+            "return await a;",
+            "g(await a, 1);",
+            "if (!await a) {}",
+            "let b = (await g()) + 1;",
+            "let c = await g() + 1;",
+        ] {
+            assert!(function(body).is_empty(), "{body}");
+        }
+        assert_eq!(
+            errors("function f(a) { let b = await a; }"),
+            ["An 'await' expression is only allowed in an async function"]
+        );
+    }
+}
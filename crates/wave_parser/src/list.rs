@@ -1,13 +1,15 @@
+use wave_allocator::Box;
 use wave_allocator::Vec;
 use wave_ast::ast::{
-    Argument, ArrayExpressionElement, ClassElement, Expression, FormalParameter,
-    ImportDeclarationSpecifier,
+    Argument, ArrayExpressionElement, BindingIdentifier, BindingPattern, BindingProperty,
+    ClassElement, Expression, FormalParameter, ImportDeclarationSpecifier, MatchArm, MatchPattern,
+    MatchProperty, PropertyKey, RestElement, SwitchCase,
 };
 use wave_diagnostics::Result;
 use wave_lexer::Kind;
 use wave_span::Span;
 
-use crate::Parser;
+use crate::{diagnostics, Parser};
 
 pub trait NormalList<'a> {
     /// Open element, e.g.. `{` `[` `(`
@@ -77,12 +79,14 @@ pub trait SeparatedList<'a>: Sized {
 
 pub struct FormalParameterList<'a> {
     pub elements: Vec<'a, FormalParameter<'a>>,
+    pub rest: Option<Box<'a, RestElement<'a>>>,
 }
 
 impl<'a> SeparatedList<'a> for FormalParameterList<'a> {
     fn new(p: &Parser<'a>) -> Self {
         Self {
             elements: p.ast.new_vec(),
+            rest: None,
         }
     }
 
@@ -95,9 +99,18 @@ impl<'a> SeparatedList<'a> for FormalParameterList<'a> {
     }
 
     fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
+        if p.at(Kind::Dot3) {
+            let rest = p.parse_rest_element()?;
+            if !p.at(self.close()) {
+                p.error(diagnostics::RestParameterLast(rest.span));
+            }
+            self.rest.replace(rest);
+            return Ok(());
+        }
+
         let span = p.start_span();
 
-        let pattern = p.parse_binding_pattern()?;
+        let pattern = p.parse_binding_element()?;
         let formal_parameter = p.ast.formal_parameter(p.end_span(span), pattern);
         self.elements.push(formal_parameter);
 
@@ -105,6 +118,102 @@ impl<'a> SeparatedList<'a> for FormalParameterList<'a> {
     }
 }
 
+pub struct ObjectPatternProperties<'a> {
+    pub elements: Vec<'a, BindingProperty<'a>>,
+    pub rest: Option<Box<'a, RestElement<'a>>>,
+}
+
+impl<'a> SeparatedList<'a> for ObjectPatternProperties<'a> {
+    fn new(p: &Parser<'a>) -> Self {
+        Self {
+            elements: p.ast.new_vec(),
+            rest: None,
+        }
+    }
+
+    fn open(&self) -> Kind {
+        Kind::LCurly
+    }
+
+    fn close(&self) -> Kind {
+        Kind::RCurly
+    }
+
+    fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
+        if p.at(Kind::Dot3) {
+            let rest = p.parse_rest_element()?;
+            if !p.at(self.close()) {
+                p.error(diagnostics::BindingRestElementLast(rest.span));
+            }
+            self.rest.replace(rest);
+            return Ok(());
+        }
+
+        let span = p.start_span();
+        let key = p.parse_property_name()?;
+
+        let (value, shorthand) = if p.eat(Kind::Colon) {
+            (p.parse_binding_element()?, false)
+        } else {
+            // `{ a }` and `{ a = 1 }` bind the key itself
+            let PropertyKey::Identifier(ident) = &key else {
+                return Err(p.unexpected());
+            };
+            let identifier = BindingIdentifier::new(ident.span, ident.name.clone());
+            let left = p
+                .ast
+                .binding_pattern(p.ast.binding_pattern_identifier(identifier));
+            (p.parse_initializer(span, left)?, true)
+        };
+
+        let property = p
+            .ast
+            .binding_property(p.end_span(span), key, value, shorthand);
+        self.elements.push(property);
+        Ok(())
+    }
+}
+
+pub struct ArrayPatternList<'a> {
+    pub elements: Vec<'a, Option<BindingPattern<'a>>>,
+    pub rest: Option<Box<'a, RestElement<'a>>>,
+}
+
+impl<'a> SeparatedList<'a> for ArrayPatternList<'a> {
+    fn new(p: &Parser<'a>) -> Self {
+        Self {
+            elements: p.ast.new_vec(),
+            rest: None,
+        }
+    }
+
+    fn open(&self) -> Kind {
+        Kind::LBrack
+    }
+
+    fn close(&self) -> Kind {
+        Kind::RBrack
+    }
+
+    fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
+        match p.cur_kind() {
+            Kind::Comma => self.elements.push(None),
+            Kind::Dot3 => {
+                let rest = p.parse_rest_element()?;
+                if !p.at(self.close()) {
+                    p.error(diagnostics::BindingRestElementLast(rest.span));
+                }
+                self.rest.replace(rest);
+            }
+            _ => {
+                let element = p.parse_binding_element()?;
+                self.elements.push(Some(element));
+            }
+        }
+        Ok(())
+    }
+}
+
 pub struct SequenceExpressionList<'a> {
     pub elements: Vec<'a, Expression<'a>>,
 }
@@ -155,9 +264,13 @@ impl<'a> SeparatedList<'a> for ArrayExpressionList<'a> {
     }
 
     fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
-        let element = p
-            .parse_assignment_expression_base()
-            .map(ArrayExpressionElement::Expression);
+        let element = if p.at(Kind::Dot3) {
+            p.parse_spread_element()
+                .map(ArrayExpressionElement::SpreadElement)
+        } else {
+            p.parse_assignment_expression_base()
+                .map(ArrayExpressionElement::Expression)
+        };
 
         if p.at(Kind::Comma) && p.peek_at(self.close()) {
             self.trailing_comma = Some(p.end_span(p.start_span()));
@@ -190,9 +303,12 @@ impl<'a> SeparatedList<'a> for CallArguments<'a> {
     }
 
     fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
-        let element = p
-            .parse_assignment_expression_base()
-            .map(Argument::Expression);
+        let element = if p.at(Kind::Dot3) {
+            p.parse_spread_element().map(Argument::SpreadElement)
+        } else {
+            p.parse_assignment_expression_base()
+                .map(Argument::Expression)
+        };
         self.elements.push(element?);
         Ok(())
     }
@@ -262,3 +378,122 @@ impl<'a> SeparatedList<'a> for ImportSpecifierList<'a> {
         Ok(())
     }
 }
+
+pub struct SwitchCases<'a> {
+    pub elements: Vec<'a, SwitchCase<'a>>,
+}
+
+impl<'a> SwitchCases<'a> {
+    pub(crate) fn new(p: &Parser<'a>) -> Self {
+        Self {
+            elements: p.ast.new_vec(),
+        }
+    }
+}
+
+impl<'a> NormalList<'a> for SwitchCases<'a> {
+    fn open(&self) -> Kind {
+        Kind::LCurly
+    }
+
+    fn close(&self) -> Kind {
+        Kind::RCurly
+    }
+
+    fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
+        let element = p.parse_switch_case()?;
+        self.elements.push(element);
+        Ok(())
+    }
+}
+
+pub struct MatchArms<'a> {
+    pub elements: Vec<'a, MatchArm<'a>>,
+}
+
+impl<'a> SeparatedList<'a> for MatchArms<'a> {
+    fn new(p: &Parser<'a>) -> Self {
+        Self {
+            elements: p.ast.new_vec(),
+        }
+    }
+
+    fn open(&self) -> Kind {
+        Kind::LCurly
+    }
+
+    fn close(&self) -> Kind {
+        Kind::RCurly
+    }
+
+    fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
+        let arm = p.parse_match_arm()?;
+        self.elements.push(arm);
+        Ok(())
+    }
+}
+
+pub struct MatchArrayPatternList<'a> {
+    pub elements: Vec<'a, MatchPattern<'a>>,
+    pub rest: Option<Box<'a, BindingIdentifier>>,
+}
+
+impl<'a> SeparatedList<'a> for MatchArrayPatternList<'a> {
+    fn new(p: &Parser<'a>) -> Self {
+        Self {
+            elements: p.ast.new_vec(),
+            rest: None,
+        }
+    }
+
+    fn open(&self) -> Kind {
+        Kind::LBrack
+    }
+
+    fn close(&self) -> Kind {
+        Kind::RBrack
+    }
+
+    fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
+        if p.at(Kind::Dot3) {
+            let span = p.start_span();
+            p.bump_any();
+            let rest = p.parse_binding_identifier()?;
+            if !p.at(self.close()) {
+                p.error(diagnostics::BindingRestElementLast(p.end_span(span)));
+            }
+            self.rest.replace(p.ast.alloc(rest));
+            return Ok(());
+        }
+
+        let pattern = p.parse_match_pattern()?;
+        self.elements.push(pattern);
+        Ok(())
+    }
+}
+
+pub struct MatchPropertyList<'a> {
+    pub elements: Vec<'a, MatchProperty<'a>>,
+}
+
+impl<'a> SeparatedList<'a> for MatchPropertyList<'a> {
+    fn new(p: &Parser<'a>) -> Self {
+        Self {
+            elements: p.ast.new_vec(),
+        }
+    }
+
+    fn open(&self) -> Kind {
+        Kind::LCurly
+    }
+
+    fn close(&self) -> Kind {
+        Kind::RCurly
+    }
+
+    fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
+        let property = p.parse_match_property()?;
+        self.elements.push(property);
+        Ok(())
+    }
+}
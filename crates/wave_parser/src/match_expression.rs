@@ -0,0 +1,100 @@
+use wave_ast::ast::{BindingIdentifier, Expression, MatchArm, MatchPattern, MatchProperty};
+use wave_diagnostics::Result;
+use wave_lexer::Kind;
+
+use crate::{
+    list::{MatchArms, MatchArrayPatternList, MatchPropertyList, SeparatedList},
+    Parser,
+};
+
+impl<'a> Parser<'a> {
+    /// `match` is contextual, it only starts an expression as `match (Expression) {`, so it
+    /// still names bindings like `let match = 3;` and can be called as `match(x)`.
+    pub(crate) fn at_match_expression(&mut self) -> bool {
+        if !(self.cur_src() == "match" && self.peek_at(Kind::LParen)) {
+            return false;
+        }
+        self.lookahead(|parser| {
+            parser.bump_any();
+            parser.parse_paren_expression().is_ok() && parser.at(Kind::LCurly)
+        })
+    }
+
+    /// `match` ( Expression ) { MatchArm , ... }
+    pub(crate) fn parse_match_expression(&mut self) -> Result<Expression<'a>> {
+        let span = self.start_span();
+        self.bump_any(); // bump `match`
+        let discriminant = self.parse_paren_expression()?;
+        let list = MatchArms::parse(self)?;
+        Ok(self
+            .ast
+            .match_expression(self.end_span(span), discriminant, list.elements))
+    }
+
+    /// MatchPattern [`if` Expression] `=>` Expression
+    pub(crate) fn parse_match_arm(&mut self) -> Result<MatchArm<'a>> {
+        let span = self.start_span();
+        let pattern = self.parse_match_pattern()?;
+        let guard = self
+            .eat(Kind::If)
+            .then(|| self.parse_assignment_expression_base())
+            .transpose()?;
+        self.expect(Kind::Arrow)?;
+        let body = self.parse_assignment_expression_base()?;
+        Ok(MatchArm {
+            span: self.end_span(span),
+            pattern,
+            guard,
+            body,
+        })
+    }
+
+    pub(crate) fn parse_match_pattern(&mut self) -> Result<MatchPattern<'a>> {
+        match self.cur_kind() {
+            Kind::LBrack => {
+                let span = self.start_span();
+                let list = MatchArrayPatternList::parse(self)?;
+                Ok(self
+                    .ast
+                    .match_array_pattern(self.end_span(span), list.elements, list.rest))
+            }
+            Kind::Minus if self.peek_kind().is_number() => {
+                let span = self.start_span();
+                self.parse_unary_expression_base(span)
+                    .map(MatchPattern::Literal)
+            }
+            kind if kind.is_literal() => self.parse_literal_expression().map(MatchPattern::Literal),
+            Kind::Ident if self.peek_at(Kind::LCurly) => {
+                let span = self.start_span();
+                let class = self.parse_identifier_reference()?;
+                let list = MatchPropertyList::parse(self)?;
+                Ok(self
+                    .ast
+                    .match_instance_pattern(self.end_span(span), class, list.elements))
+            }
+            Kind::Ident => {
+                let identifier = self.parse_binding_identifier()?;
+                Ok(MatchPattern::Identifier(self.ast.alloc(identifier)))
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    /// `x` or `x: MatchPattern` inside an instance pattern
+    pub(crate) fn parse_match_property(&mut self) -> Result<MatchProperty<'a>> {
+        let span = self.start_span();
+        let key = self.parse_identifier_name()?;
+        let (value, shorthand) = if self.eat(Kind::Colon) {
+            (self.parse_match_pattern()?, false)
+        } else {
+            let identifier = BindingIdentifier::new(key.span, key.name.clone());
+            (MatchPattern::Identifier(self.ast.alloc(identifier)), true)
+        };
+        Ok(MatchProperty {
+            span: self.end_span(span),
+            key,
+            value,
+            shorthand,
+        })
+    }
+}
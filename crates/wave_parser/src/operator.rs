@@ -8,6 +8,7 @@ use wave_syntax::{
 
 pub fn kind_to_precedence(kind: Kind) -> Option<Precedence> {
     match kind {
+        Kind::Question2 => Some(Precedence::Coalesce),
         Kind::Pipe2 => Some(Precedence::LogicalOr),
         Kind::Amp2 => Some(Precedence::LogicalAnd),
         Kind::Pipe => Some(Precedence::BitwiseOr),
@@ -16,7 +17,9 @@ pub fn kind_to_precedence(kind: Kind) -> Option<Precedence> {
         Kind::Eq2 | Kind::Neq => Some(Precedence::Equality),
         Kind::Plus | Kind::Minus => Some(Precedence::Add),
         Kind::Star | Kind::Slash | Kind::Percent => Some(Precedence::Multiply),
-        Kind::LAngle | Kind::RAngle | Kind::LtEq | Kind::GtEq => Some(Precedence::Relational),
+        Kind::LAngle | Kind::RAngle | Kind::LtEq | Kind::GtEq | Kind::Instanceof => {
+            Some(Precedence::Relational)
+        }
         Kind::Star2 => Some(Precedence::Exponential),
         _ => None,
     }
@@ -32,6 +35,7 @@ pub fn map_assignment_operator(kind: Kind) -> AssignmentOperator {
         Kind::PercentEq => AssignmentOperator::Remainder,
         Kind::Amp2Eq => AssignmentOperator::LogicalAnd,
         Kind::Pipe2Eq => AssignmentOperator::LogicalOr,
+        Kind::Question2Eq => AssignmentOperator::LogicalNullish,
         Kind::Star2Eq => AssignmentOperator::Exponential,
         Kind::PipeEq => AssignmentOperator::BitwiseOR,
         Kind::CaretEq => AssignmentOperator::BitwiseXOR,
@@ -57,6 +61,7 @@ pub fn map_binary_operator(kind: Kind) -> BinaryOperator {
         Kind::Pipe => BinaryOperator::BitwiseOR,
         Kind::Caret => BinaryOperator::BitwiseXOR,
         Kind::Amp => BinaryOperator::BitwiseAnd,
+        Kind::Instanceof => BinaryOperator::Instanceof,
         _ => unreachable!("Binary Operator: {kind:?}"),
     }
 }
@@ -66,6 +71,7 @@ pub fn map_unary_operator(kind: Kind) -> UnaryOperator {
         Kind::Minus => UnaryOperator::UnaryNegation,
         Kind::Plus => UnaryOperator::UnaryPlus,
         Kind::Bang => UnaryOperator::LogicalNot,
+        Kind::Typeof => UnaryOperator::Typeof,
         _ => unreachable!("Unary Operator: {kind:?}"),
     }
 }
@@ -74,6 +80,7 @@ pub fn map_logical_operator(kind: Kind) -> LogicalOperator {
     match kind {
         Kind::Pipe2 => LogicalOperator::Or,
         Kind::Amp2 => LogicalOperator::And,
+        Kind::Question2 => LogicalOperator::Coalesce,
         _ => unreachable!("Logical Operator: {kind:?}"),
     }
 }
@@ -1,13 +1,17 @@
 use wave_allocator::Box;
-use wave_ast::ast::{BlockStatement, Declaration, Expression, Statement};
+use wave_ast::ast::{
+    BlockStatement, Declaration, Expression, LabelIdentifier, Statement, SwitchCase,
+};
 use wave_diagnostics::Result;
 use wave_lexer::Kind;
 use wave_span::Span;
 
 use crate::{
-    context::StatementContext,
+    context::{Context, Label, StatementContext},
     declaration::{VariableDeclarationContext, VariableDeclarationParent},
-    diagnostics, Parser,
+    diagnostics,
+    list::{NormalList, SwitchCases},
+    Parser,
 };
 
 impl<'a> Parser<'a> {
@@ -18,7 +22,7 @@ impl<'a> Parser<'a> {
             match self.cur_kind() {
                 Kind::RCurly => break,
                 _ => {
-                    let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+                    let stmt = self.parse_statement_or_recover(StatementContext::StatementList);
 
                     if let Statement::ExpressionStatement(expr) = &stmt {
                         if let Expression::StringLiteral(string) = &expr.expression {
@@ -37,6 +41,62 @@ impl<'a> Parser<'a> {
         Ok(statements)
     }
 
+    /// Parses a statement, and on a syntax error records it and skips ahead to the
+    /// next statement so parsing can continue.
+    pub(crate) fn parse_statement_or_recover(
+        &mut self,
+        stmt_ctx: StatementContext,
+    ) -> Statement<'a> {
+        let span = self.start_span();
+        match self.parse_statement_list_item(stmt_ctx) {
+            Ok(stmt) => stmt,
+            Err(error) => {
+                self.error(error);
+                self.synchronize(span.start);
+                self.ast.invalid_statement(self.end_span(span))
+            }
+        }
+    }
+
+    /// Skips tokens until a `;`, the `}` closing the current block, or the start of a statement.
+    fn synchronize(&mut self, start: u32) {
+        // Always make progress, the failed statement may not have consumed any token
+        if self.cur_token().start == start && !self.at(Kind::Eof) {
+            self.bump_any();
+        }
+        let mut depth = 0u32;
+        loop {
+            match self.cur_kind() {
+                Kind::Eof => return,
+                Kind::Semicolon if depth == 0 => {
+                    self.bump_any();
+                    return;
+                }
+                Kind::RCurly if depth == 0 => return,
+                Kind::RCurly => depth -= 1,
+                Kind::LCurly => depth += 1,
+                Kind::Let
+                | Kind::Const
+                | Kind::If
+                | Kind::While
+                | Kind::For
+                | Kind::Switch
+                | Kind::Break
+                | Kind::Continue
+                | Kind::Return
+                | Kind::Class
+                | Kind::Function
+                | Kind::Import
+                    if depth == 0 =>
+                {
+                    return
+                }
+                _ => {}
+            }
+            self.bump_any();
+        }
+    }
+
     pub(crate) fn parse_statement_list_item(
         &mut self,
         stmt_ctx: StatementContext,
@@ -47,6 +107,8 @@ impl<'a> Parser<'a> {
             Kind::LCurly => self.parse_block_statement(),
             Kind::If => self.parse_if_statement(),
             Kind::While => self.parse_while_statement(),
+            Kind::For => self.parse_for_statement(),
+            Kind::Switch => self.parse_switch_statement(),
             Kind::Break | Kind::Continue => self.parse_break_or_continue_statement(),
             Kind::Const => self.parse_variable_statement(stmt_ctx),
             Kind::Let => self.parse_variable_statement(stmt_ctx),
@@ -63,10 +125,57 @@ impl<'a> Parser<'a> {
 
     fn parse_expression_or_labeled_statement(&mut self) -> Result<Statement<'a>> {
         let span = self.start_span();
+        if self.at(Kind::Ident) && self.peek_at(Kind::Colon) {
+            return self.parse_labeled_statement(span);
+        }
         let expr = self.parse_expression()?;
         self.parse_expression_statement(span, expr)
     }
 
+    fn parse_labeled_statement(&mut self, span: Span) -> Result<Statement<'a>> {
+        let label = self.parse_label_identifier();
+        self.expect(Kind::Colon)?;
+
+        if let Some(declared) = self.labels.iter().find(|l| l.name == label.name) {
+            let declared = Span::new(declared.start, declared.start + label.name.len() as u32);
+            self.error(diagnostics::LabelRedeclaration(
+                label.name.to_string(),
+                declared,
+                label.span,
+            ));
+        }
+
+        let is_loop = matches!(self.cur_kind(), Kind::While | Kind::For);
+        if is_loop {
+            // In `a: b: while (...)` both `a` and `b` label the loop
+            let mut start = span.start;
+            for outer in self.labels.iter_mut().rev() {
+                if outer.body_start != start {
+                    break;
+                }
+                outer.is_loop = true;
+                start = outer.start;
+            }
+        }
+        self.labels.push(Label {
+            name: label.name.clone(),
+            start: span.start,
+            body_start: self.cur_token().start,
+            is_loop,
+        });
+        let body = self.parse_statement_list_item(StatementContext::Label);
+        self.labels.pop();
+
+        Ok(self
+            .ast
+            .labeled_statement(self.end_span(span), label, body?))
+    }
+
+    fn parse_label_identifier(&mut self) -> LabelIdentifier {
+        let (span, name) = self.parse_identifier_kind(Kind::Ident);
+        LabelIdentifier { span, name }
+    }
+
     pub(crate) fn parse_expression_statement(
         &mut self,
         span: Span,
@@ -122,7 +231,7 @@ impl<'a> Parser<'a> {
         self.expect(Kind::LCurly)?;
         let mut body = self.ast.new_vec();
         while !self.at(Kind::RCurly) && !self.at(Kind::Eof) {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+            let stmt = self.parse_statement_or_recover(StatementContext::StatementList);
             body.push(stmt);
         }
         self.expect(Kind::RCurly)?;
@@ -153,20 +262,123 @@ impl<'a> Parser<'a> {
         let span = self.start_span();
         self.bump_any(); // bump `while`
         let test = self.parse_paren_expression()?;
-        let body = self.parse_statement_list_item(StatementContext::While)?;
+        let body = self.with_context(Context::Iteration, |p| {
+            p.parse_statement_list_item(StatementContext::While)
+        })?;
         Ok(self.ast.while_statement(self.end_span(span), test, body))
     }
 
+    fn parse_for_statement(&mut self) -> Result<Statement<'a>> {
+        let span = self.start_span();
+        self.bump_any(); // bump `for`
+        self.expect(Kind::LParen)?;
+        let left = self.parse_variable_declaration(
+            self.start_span(),
+            VariableDeclarationContext::new(VariableDeclarationParent::For),
+        )?;
+        // `of` is a contextual keyword
+        if !(self.at(Kind::Ident) && self.cur_src() == "of") {
+            return Err(self.unexpected());
+        }
+        self.bump_any();
+        let right = self.parse_assignment_expression_base()?;
+        self.expect(Kind::RParen)?;
+        let body = self.with_context(Context::Iteration, |p| {
+            p.parse_statement_list_item(StatementContext::For)
+        })?;
+        Ok(self
+            .ast
+            .for_of_statement(self.end_span(span), left, right, body))
+    }
+
     fn parse_break_or_continue_statement(&mut self) -> Result<Statement<'a>> {
         let span = self.start_span();
         let kind = self.cur_kind();
         self.bump_any();
+
+        let label = if self.at(Kind::Ident) && !self.cur_token().is_on_new_line {
+            let label = self.parse_label_identifier();
+            match self.labels.iter().find(|l| l.name == label.name) {
+                None => self.error(diagnostics::UndefinedLabel(
+                    label.name.to_string(),
+                    label.span,
+                )),
+                Some(target) if kind == Kind::Continue && !target.is_loop => {
+                    self.error(diagnostics::InvalidLabelNonIteration(
+                        label.name.to_string(),
+                        label.span,
+                    ));
+                }
+                Some(_) => {}
+            }
+            Some(label)
+        } else {
+            let keyword = Span::new(span.start, self.prev_token_end);
+            match kind {
+                Kind::Break if !self.ctx.has_break() => {
+                    self.error(diagnostics::InvalidBreak(keyword));
+                }
+                Kind::Continue if !self.ctx.has_iteration() => {
+                    self.error(diagnostics::InvalidContinue(keyword));
+                }
+                _ => {}
+            }
+            None
+        };
+
         self.asi()?;
         let end_span = self.end_span(span);
         match kind {
-            Kind::Break => Ok(self.ast.break_statement(end_span)),
-            Kind::Continue => Ok(self.ast.continue_statement(end_span)),
+            Kind::Break => Ok(self.ast.break_statement(end_span, label)),
+            Kind::Continue => Ok(self.ast.continue_statement(end_span, label)),
             _ => unreachable!(),
         }
     }
+
+    fn parse_switch_statement(&mut self) -> Result<Statement<'a>> {
+        let span = self.start_span();
+        self.bump_any(); // bump `switch`
+        let discriminant = self.parse_paren_expression()?;
+        let mut cases = SwitchCases::new(self);
+        self.with_context(Context::Switch, |p| cases.parse(p))?;
+
+        if let Some(duplicate) = cases
+            .elements
+            .iter()
+            .filter(|case| case.is_default_case())
+            .nth(1)
+        {
+            self.error(diagnostics::MultipleDefaultCases(duplicate.span));
+        }
+
+        Ok(self
+            .ast
+            .switch_statement(self.end_span(span), discriminant, cases.elements))
+    }
+
+    pub(crate) fn parse_switch_case(&mut self) -> Result<SwitchCase<'a>> {
+        let span = self.start_span();
+        let test = match self.cur_kind() {
+            Kind::Default => {
+                self.bump_any();
+                None
+            }
+            Kind::Case => {
+                self.bump_any();
+                Some(self.parse_expression()?)
+            }
+            _ => return Err(self.unexpected()),
+        };
+        self.expect(Kind::Colon)?;
+
+        let mut consequent = self.ast.new_vec();
+        while !matches!(
+            self.cur_kind(),
+            Kind::Case | Kind::Default | Kind::RCurly | Kind::Eof
+        ) {
+            let stmt = self.parse_statement_or_recover(StatementContext::StatementList);
+            consequent.push(stmt);
+        }
+        Ok(self.ast.switch_case(self.end_span(span), test, consequent))
+    }
 }
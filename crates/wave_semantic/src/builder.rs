@@ -0,0 +1,379 @@
+use wave_ast::ast::{
+    Argument, ArrayExpressionElement, AssignmentTarget, BindingPattern, BindingPatternKind,
+    ChainElement, ClassElement, Declaration, Expression, FormalParameters, Function,
+    MatchExpression, MatchPattern, MemberExpression, Program, SimpleAssignmentTarget, Statement,
+};
+use wave_diagnostics::Error;
+use wave_span::Atom;
+use wave_syntax::operator::UnaryOperator;
+
+use crate::diagnostics;
+
+pub struct SemanticBuilderReturn {
+    /// Errors and warnings, a program with only warnings can still be evaluated.
+    pub errors: Vec<Error>,
+}
+
+#[derive(Default)]
+pub struct SemanticBuilder {
+    errors: Vec<Error>,
+    /// Private names declared by each enclosing class, innermost last
+    private_names: Vec<Vec<Atom>>,
+}
+
+impl SemanticBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(mut self, program: &Program<'_>) -> SemanticBuilderReturn {
+        for statement in &program.body {
+            self.visit_statement(statement);
+        }
+        SemanticBuilderReturn {
+            errors: self.errors,
+        }
+    }
+
+    fn visit_statement(&mut self, statement: &Statement<'_>) {
+        match statement {
+            Statement::ExpressionStatement(stmt) => self.visit_expression(&stmt.expression),
+            Statement::IfStatement(stmt) => {
+                self.visit_expression(&stmt.test);
+                self.visit_statement(&stmt.consequent);
+                if let Some(alternate) = &stmt.alternate {
+                    self.visit_statement(alternate);
+                }
+            }
+            Statement::BlockStatement(stmt) => {
+                for statement in &stmt.body {
+                    self.visit_statement(statement);
+                }
+            }
+            Statement::ReturnStatement(stmt) => {
+                if let Some(argument) = &stmt.argument {
+                    self.visit_expression(argument);
+                }
+            }
+            Statement::WhileStatement(stmt) => {
+                self.visit_expression(&stmt.test);
+                self.visit_statement(&stmt.body);
+            }
+            Statement::ForOfStatement(stmt) => {
+                for declarator in &stmt.left.declarations {
+                    self.visit_binding_pattern(&declarator.id);
+                }
+                self.visit_expression(&stmt.right);
+                self.visit_statement(&stmt.body);
+            }
+            Statement::SwitchStatement(stmt) => {
+                self.visit_expression(&stmt.discriminant);
+                for case in &stmt.cases {
+                    if let Some(test) = &case.test {
+                        self.visit_expression(test);
+                    }
+                    for statement in &case.consequent {
+                        self.visit_statement(statement);
+                    }
+                }
+            }
+            Statement::LabeledStatement(stmt) => self.visit_statement(&stmt.body),
+            Statement::Declaration(decl) => self.visit_declaration(decl),
+            Statement::BreakStatement(_)
+            | Statement::ContinueStatement(_)
+            | Statement::ModuleDeclaration(_)
+            | Statement::InvalidStatement(_) => {}
+        }
+    }
+
+    fn visit_declaration(&mut self, declaration: &Declaration<'_>) {
+        match declaration {
+            Declaration::VariableDeclaration(decl) => {
+                for declarator in &decl.declarations {
+                    self.visit_binding_pattern(&declarator.id);
+                    if let Some(init) = &declarator.init {
+                        self.visit_expression(init);
+                    }
+                }
+            }
+            Declaration::FunctionDeclaration(function) => self.visit_function(function),
+            Declaration::ClassDeclaration(class) => {
+                if let Some(super_class) = &class.super_class {
+                    self.visit_expression(super_class);
+                }
+                let private_names = class
+                    .body
+                    .body
+                    .iter()
+                    .filter_map(|element| match element {
+                        ClassElement::MethodDefinition(method) => method.key.private_name(),
+                        ClassElement::PropertyDefinition(property) => property.key.private_name(),
+                    })
+                    .cloned()
+                    .collect();
+                self.private_names.push(private_names);
+                for element in &class.body.body {
+                    match element {
+                        ClassElement::MethodDefinition(method) => {
+                            self.visit_function(&method.value)
+                        }
+                        ClassElement::PropertyDefinition(property) => {
+                            if let Some(value) = &property.value {
+                                self.visit_expression(value);
+                            }
+                        }
+                    }
+                }
+                self.private_names.pop();
+            }
+        }
+    }
+
+    fn visit_function(&mut self, function: &Function<'_>) {
+        self.visit_formal_parameters(&function.params);
+        if let Some(body) = &function.body {
+            for statement in &body.statements {
+                self.visit_statement(statement);
+            }
+        }
+    }
+
+    fn visit_formal_parameters(&mut self, params: &FormalParameters<'_>) {
+        for param in &params.items {
+            self.visit_binding_pattern(&param.pattern);
+        }
+        if let Some(rest) = &params.rest {
+            self.visit_binding_pattern(&rest.argument);
+        }
+    }
+
+    /// Default values in patterns are expressions too.
+    fn visit_binding_pattern(&mut self, pattern: &BindingPattern<'_>) {
+        match &pattern.kind {
+            BindingPatternKind::BindingIdentifier(_) => {}
+            BindingPatternKind::AssignmentPattern(pattern) => {
+                self.visit_binding_pattern(&pattern.left);
+                self.visit_expression(&pattern.right);
+            }
+            BindingPatternKind::ArrayPattern(pattern) => {
+                for element in pattern.elements.iter().flatten() {
+                    self.visit_binding_pattern(element);
+                }
+                if let Some(rest) = &pattern.rest {
+                    self.visit_binding_pattern(&rest.argument);
+                }
+            }
+            BindingPatternKind::ObjectPattern(pattern) => {
+                for property in &pattern.properties {
+                    self.visit_binding_pattern(&property.value);
+                }
+                if let Some(rest) = &pattern.rest {
+                    self.visit_binding_pattern(&rest.argument);
+                }
+            }
+        }
+    }
+
+    fn visit_arguments(&mut self, arguments: &[Argument<'_>]) {
+        for argument in arguments {
+            match argument {
+                Argument::Expression(expr) => self.visit_expression(expr),
+                Argument::SpreadElement(spread) => self.visit_expression(&spread.argument),
+            }
+        }
+    }
+
+    fn visit_member_expression(&mut self, expr: &MemberExpression<'_>) {
+        self.visit_expression(expr.object());
+        match expr {
+            MemberExpression::ComputedMemberExpression(expr) => {
+                self.visit_expression(&expr.expression);
+            }
+            MemberExpression::PrivateFieldExpression(expr) => {
+                let name = &expr.field.name;
+                if !self
+                    .private_names
+                    .iter()
+                    .flatten()
+                    .any(|declared| declared == name)
+                {
+                    self.errors.push(
+                        diagnostics::UndeclaredPrivateName(name.to_string(), expr.field.span)
+                            .into(),
+                    );
+                }
+            }
+            MemberExpression::StaticMemberExpression(_) => {}
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'_>) {
+        match expr {
+            Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumberLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::Identifier(_)
+            | Expression::ThisExpression(_)
+            | Expression::Super(_) => {}
+            Expression::AwaitExpression(expr) => self.visit_expression(&expr.argument),
+            Expression::YieldExpression(expr) => {
+                if let Some(argument) = &expr.argument {
+                    self.visit_expression(argument);
+                }
+            }
+            Expression::TemplateLiteral(expr) => {
+                for expression in &expr.expressions {
+                    self.visit_expression(expression);
+                }
+            }
+            Expression::AssignmentExpression(expr) => {
+                let AssignmentTarget::SimpleAssignmentTarget(target) = &expr.left;
+                if let SimpleAssignmentTarget::MemberAssignmentTarget(member) = target {
+                    self.visit_member_expression(member);
+                }
+                self.visit_expression(&expr.right);
+            }
+            Expression::BinaryExpression(expr) => {
+                self.visit_expression(&expr.left);
+                self.visit_expression(&expr.right);
+            }
+            Expression::LogicalExpression(expr) => {
+                self.visit_expression(&expr.left);
+                self.visit_expression(&expr.right);
+            }
+            Expression::SequenceExpression(expr) => {
+                for expression in &expr.expressions {
+                    self.visit_expression(expression);
+                }
+            }
+            Expression::ParenthesizedExpression(expr) => self.visit_expression(&expr.expression),
+            Expression::ArrayExpression(expr) => {
+                for element in &expr.elements {
+                    match element {
+                        ArrayExpressionElement::Expression(expr) => self.visit_expression(expr),
+                        ArrayExpressionElement::SpreadElement(spread) => {
+                            self.visit_expression(&spread.argument);
+                        }
+                    }
+                }
+            }
+            Expression::CallExpression(expr) => {
+                self.visit_expression(&expr.callee);
+                self.visit_arguments(&expr.arguments);
+            }
+            Expression::NewExpression(expr) => {
+                self.visit_expression(&expr.callee);
+                self.visit_arguments(&expr.arguments);
+            }
+            Expression::UnaryExpression(expr) => self.visit_expression(&expr.argument),
+            Expression::UpdateExpression(expr) => {
+                if let SimpleAssignmentTarget::MemberAssignmentTarget(member) = &expr.argument {
+                    self.visit_member_expression(member);
+                }
+            }
+            Expression::ConditionalExpression(expr) => {
+                self.visit_expression(&expr.test);
+                self.visit_expression(&expr.consequent);
+                self.visit_expression(&expr.alternate);
+            }
+            Expression::FunctionExpression(function) => self.visit_function(function),
+            Expression::MemberExpression(expr) => self.visit_member_expression(expr),
+            Expression::ChainExpression(expr) => match &expr.expression {
+                ChainElement::CallExpression(call) => {
+                    self.visit_expression(&call.callee);
+                    self.visit_arguments(&call.arguments);
+                }
+                ChainElement::MemberExpression(member) => self.visit_member_expression(member),
+            },
+            Expression::MatchExpression(expr) => self.visit_match_expression(expr),
+        }
+    }
+
+    fn visit_match_expression(&mut self, expr: &MatchExpression<'_>) {
+        self.visit_expression(&expr.discriminant);
+
+        let mut catch_all = false;
+        let mut seen_literals = vec![];
+        for arm in &expr.arms {
+            if catch_all {
+                self.errors
+                    .push(diagnostics::UnreachableMatchArm(arm.span).into());
+            } else if let Some(literal) = literal_key(&arm.pattern) {
+                if seen_literals.contains(&literal) {
+                    self.errors
+                        .push(diagnostics::DuplicateMatchArm(literal, arm.span).into());
+                } else if arm.guard.is_none() {
+                    seen_literals.push(literal);
+                }
+            }
+            catch_all |= arm.guard.is_none() && arm.pattern.is_irrefutable();
+
+            if let Some(guard) = &arm.guard {
+                self.visit_expression(guard);
+            }
+            self.visit_expression(&arm.body);
+        }
+    }
+}
+
+/// A normalized form of a literal pattern, so `1` and `1.0` compare equal.
+fn literal_key(pattern: &MatchPattern<'_>) -> Option<String> {
+    let MatchPattern::Literal(literal) = pattern else {
+        return None;
+    };
+    match literal {
+        Expression::NumberLiteral(lit) => Some(lit.value.to_string()),
+        Expression::UnaryExpression(expr) if expr.operator == UnaryOperator::UnaryNegation => {
+            match &expr.argument {
+                Expression::NumberLiteral(lit) => Some((-lit.value).to_string()),
+                Expression::BigIntLiteral(lit) => Some(format!("-{}n", lit.value)),
+                _ => None,
+            }
+        }
+        Expression::BigIntLiteral(lit) => Some(format!("{}n", lit.value)),
+        Expression::StringLiteral(lit) => Some(format!("{:?}", lit.value.as_str())),
+        Expression::BooleanLiteral(lit) => Some(lit.value.to_string()),
+        Expression::NullLiteral(_) => Some("null".to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SemanticBuilder;
+    use wave_allocator::Allocator;
+    use wave_parser::Parser;
+
+    fn warnings(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source).parse();
+        assert!(ret.errors.is_empty());
+        let ret = SemanticBuilder::new().build(&ret.program);
+        ret.errors.iter().map(|error| error.to_string()).collect()
+    }
+
+    #[test]
+    fn unreachable_match_arms() {
+        assert_eq!(
+            warnings("match (1) { x => x, 1 => 1 };"),
+            ["Unreachable match arm"]
+        );
+        assert_eq!(
+            warnings("function f(v) { return match (v) { 1 => 1, 1.0 => 2, x => 3 }; }").len(),
+            1
+        );
+        assert!(warnings("match (1) { x if x > 1 => x, 1 => 1, y => y };").is_empty());
+    }
+
+    #[test]
+    fn private_names_must_be_declared() {
+        let class = "class A { #x = 1; get() { return this.#x; } }";
+        assert!(warnings(class).is_empty());
+        assert_eq!(
+            warnings(&format!("{class} new A().#x;")),
+            ["Private field '#x' must be declared in an enclosing class"]
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use wave_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+
+use wave_span::Span;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unreachable match arm")]
+#[diagnostic(
+    severity(Warning),
+    help("An earlier arm without a guard matches every value")
+)]
+pub struct UnreachableMatchArm(#[label("this arm is never reached")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unreachable match arm")]
+#[diagnostic(
+    severity(Warning),
+    help("An earlier arm without a guard matches `{0}`")
+)]
+pub struct DuplicateMatchArm(pub String, #[label("this arm is never reached")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Private field '#{0}' must be declared in an enclosing class")]
+#[diagnostic()]
+pub struct UndeclaredPrivateName(pub String, #[label] pub Span);
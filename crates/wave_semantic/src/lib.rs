@@ -0,0 +1,6 @@
+//! Semantic checks that run on a parsed program before it is evaluated.
+
+mod builder;
+mod diagnostics;
+
+pub use builder::{SemanticBuilder, SemanticBuilderReturn};
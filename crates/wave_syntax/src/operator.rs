@@ -22,6 +22,8 @@ pub enum AssignmentOperator {
     LogicalAnd,
     #[cfg_attr(feature = "serde", serde(rename = "||="))]
     LogicalOr,
+    #[cfg_attr(feature = "serde", serde(rename = "??="))]
+    LogicalNullish,
     #[cfg_attr(feature = "serde", serde(rename = "**="))]
     Exponential,
     #[cfg_attr(feature = "serde", serde(rename = "|="))]
@@ -43,12 +45,29 @@ impl AssignmentOperator {
             Self::Remainder => "%=",
             Self::LogicalAnd => "&&=",
             Self::LogicalOr => "||=",
+            Self::LogicalNullish => "??=",
             Self::Exponential => "**=",
             Self::BitwiseOR => "|=",
             Self::BitwiseXOR => "^=",
             Self::BitwiseAnd => "&=",
         }
     }
+
+    /// The binary operator applied by a compound assignment, `None` for `=` and the logical ones.
+    pub fn to_binary_operator(self) -> Option<BinaryOperator> {
+        match self {
+            Self::Addition => Some(BinaryOperator::Addition),
+            Self::Subtraction => Some(BinaryOperator::Subtraction),
+            Self::Multiplication => Some(BinaryOperator::Multiplication),
+            Self::Division => Some(BinaryOperator::Division),
+            Self::Remainder => Some(BinaryOperator::Remainder),
+            Self::Exponential => Some(BinaryOperator::Exponential),
+            Self::BitwiseOR => Some(BinaryOperator::BitwiseOR),
+            Self::BitwiseXOR => Some(BinaryOperator::BitwiseXOR),
+            Self::BitwiseAnd => Some(BinaryOperator::BitwiseAnd),
+            Self::Assign | Self::LogicalAnd | Self::LogicalOr | Self::LogicalNullish => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -84,6 +103,8 @@ pub enum BinaryOperator {
     BitwiseXOR,
     #[cfg_attr(feature = "serde", serde(rename = "&"))]
     BitwiseAnd,
+    #[cfg_attr(feature = "serde", serde(rename = "instanceof"))]
+    Instanceof,
 }
 
 impl BinaryOperator {
@@ -104,6 +125,7 @@ impl BinaryOperator {
             Self::BitwiseOR => "|",
             Self::BitwiseXOR => "^",
             Self::BitwiseAnd => "&",
+            Self::Instanceof => "instanceof",
         }
     }
 }
@@ -117,6 +139,8 @@ pub enum UnaryOperator {
     UnaryPlus,
     #[cfg_attr(feature = "serde", serde(rename = "!"))]
     LogicalNot,
+    #[cfg_attr(feature = "serde", serde(rename = "typeof"))]
+    Typeof,
 }
 
 impl UnaryOperator {
@@ -129,6 +153,7 @@ impl UnaryOperator {
             Self::UnaryNegation => "-",
             Self::UnaryPlus => "+",
             Self::LogicalNot => "!",
+            Self::Typeof => "typeof",
         }
     }
 }
@@ -158,8 +183,8 @@ pub enum LogicalOperator {
     Or,
     #[cfg_attr(feature = "serde", serde(rename = "&&"))]
     And,
-    // #[cfg_attr(feature = "serde", serde(rename = "??"))]
-    // Coalesce,
+    #[cfg_attr(feature = "serde", serde(rename = "??"))]
+    Coalesce,
 }
 
 impl LogicalOperator {
@@ -167,7 +192,7 @@ impl LogicalOperator {
         match self {
             Self::Or => "||",
             Self::And => "&&",
-            // Self::Coalesce => "??",
+            Self::Coalesce => "??",
         }
     }
 }
@@ -177,7 +202,7 @@ impl GetPrecedence for LogicalOperator {
         match self {
             Self::Or => Precedence::LogicalOr,
             Self::And => Precedence::LogicalAnd,
-            // Self::Coalesce => Precedence::Coalesce,
+            Self::Coalesce => Precedence::Coalesce,
         }
     }
 }